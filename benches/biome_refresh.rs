@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Benchmark of incremental biome refresh against full reclassification
+// ABOUTME: Measures the per-cell input scan cost when nothing, or only a small patch, has changed
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use kosmarium::engine::{
+    Simulation,
+    agents::BiomeClassifier,
+    core::{DetailLevel, WorldScale},
+    physics::{DiamondSquareConfig, DiamondSquareGenerator, TerrainGenerator},
+};
+use std::hint::black_box;
+
+fn biome_refresh(c: &mut Criterion) {
+    let (width, height) = (256, 128);
+    let heightmap =
+        DiamondSquareGenerator::new(42).generate(width, height, &DiamondSquareConfig::default());
+    let scale = WorldScale::new(1000.0, (width as u32, height as u32), DetailLevel::Standard);
+    let mut sim = Simulation::_new_with_scale(heightmap, scale);
+    for _ in 0..6 {
+        sim.tick();
+    }
+    let classifier = BiomeClassifier::new_for_scale(&sim._world_scale);
+    let history = Some(sim.get_precipitation_history());
+    let (map, tracker) = classifier.generate_biome_map_tracked(
+        &sim.heightmap,
+        &sim.temperature_layer,
+        &sim.water,
+        &sim.climate_system,
+        &sim.drainage_network,
+        history,
+    );
+
+    c.bench_function("biome_full_reclassification", |b| {
+        b.iter(|| {
+            black_box(classifier.generate_biome_map_tracked(
+                &sim.heightmap,
+                &sim.temperature_layer,
+                &sim.water,
+                &sim.climate_system,
+                &sim.drainage_network,
+                history,
+            ))
+        })
+    });
+
+    c.bench_function("biome_update_unchanged", |b| {
+        b.iter_batched(
+            || (map.clone(), tracker.clone()),
+            |(mut map, mut tracker)| {
+                classifier.update_biome_map(
+                    &mut map,
+                    &mut tracker,
+                    &sim.heightmap,
+                    &sim.temperature_layer,
+                    &sim.water,
+                    &sim.climate_system,
+                    &sim.drainage_network,
+                    history,
+                )
+            },
+            BatchSize::LargeInput,
+        )
+    });
+
+    // Flood a 16x16 patch so a few hundred cells cross the water-depth tolerance
+    let mut flooded = sim.water.clone();
+    for y in 40..56 {
+        for x in 100..116 {
+            flooded.depth.set(x, y, 0.5);
+        }
+    }
+    c.bench_function("biome_update_local_change", |b| {
+        b.iter_batched(
+            || (map.clone(), tracker.clone()),
+            |(mut map, mut tracker)| {
+                classifier.update_biome_map(
+                    &mut map,
+                    &mut tracker,
+                    &sim.heightmap,
+                    &sim.temperature_layer,
+                    &flooded,
+                    &sim.climate_system,
+                    &sim.drainage_network,
+                    history,
+                )
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, biome_refresh);
+criterion_main!(benches);
@@ -4,8 +4,12 @@
 // ABOUTME: Application implementations - different ways to use the simulation engine
 // ABOUTME: Demonstrates engine flexibility through specialized application instances
 
+pub mod batch_run;
 pub mod terrain_explorer;
 pub mod weather_demo;
+pub mod workspace_run;
 
 // Re-export application entry points
+pub use batch_run::{parse_run_args, run_batch};
 pub use weather_demo::run_weather_demo;
+pub use workspace_run::{parse_workspace_args, run_workspace};
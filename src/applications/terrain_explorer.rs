@@ -15,7 +15,9 @@ use crate::engine::{
         DiamondSquareConfig, DiamondSquareGenerator, TectonicConfig, TectonicGenerator,
         TerrainGenerator,
     },
-    rendering::{GraphicsRenderer, ascii_render, ascii_render_biomes, run_tui},
+    rendering::{
+        GraphicsRenderer, ascii_render, ascii_render_biome_legend, ascii_render_biomes, run_tui,
+    },
 };
 
 #[derive(Parser)]
@@ -121,7 +123,8 @@ pub fn run_terrain_explorer() -> Result<(), Box<dyn std::error::Error>> {
     // Step 3: Run simulation setup
     println!("Creating simulation...");
     let start_time = std::time::Instant::now();
-    let mut sim = Simulation::new(heightmap);
+    let mut sim = Simulation::try_new(heightmap)
+        .map_err(|problems| format!("invalid simulation inputs: {}", problems.join("; ")))?;
     println!("Simulation created in {:.2?}", start_time.elapsed());
 
     // Choose between graphics, TUI, and ASCII rendering
@@ -147,6 +150,7 @@ pub fn run_terrain_explorer() -> Result<(), Box<dyn std::error::Error>> {
             let biome_map = sim.generate_biome_map();
             ascii_render_biomes(&biome_map);
             println!("\nBiome classification using Whittaker model");
+            ascii_render_biome_legend(&biome_map);
         } else {
             // Show elevation data (default)
             ascii_render(&sim);
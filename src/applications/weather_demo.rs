@@ -6,11 +6,13 @@
 
 use clap::Parser;
 use macroquad::prelude::*;
+use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 // Import engine components
 use kosmarium::engine::{
     Simulation, WorkspaceConfig,
+    config::LayerSettings,
     core::{
         DetailLevel, TemporalMode, TemporalPerformanceMonitor, TemporalScale, TemporalScalingConfig,
         TemporalScalingService, WorldScale,
@@ -18,6 +20,7 @@ use kosmarium::engine::{
     physics::{DiamondSquareConfig, DiamondSquareGenerator, TerrainGenerator},
     rendering::{
         AsciiFramebuffer, FramebufferConfig, GraphicsRenderer, VisualizationLayer, ascii_render,
+        ascii_render_wind,
         multi_viewport::{MovementDirection, MultiViewportApp},
         run_tui,
     },
@@ -51,6 +54,10 @@ pub struct WeatherDemoArgs {
     #[arg(long)]
     pub ascii: bool,
 
+    /// With --ascii, draw wind direction arrows instead of elevation
+    #[arg(long)]
+    pub ascii_wind: bool,
+
     /// Use graphics mode (macroquad) instead of TUI
     #[arg(long)]
     pub graphics: bool,
@@ -75,7 +82,7 @@ pub struct WeatherDemoArgs {
     #[arg(long)]
     pub ascii_frames: bool,
 
-    /// Layers to display (comma-separated: elevation,water,biomes,temperature,pressure,wind,flow,sediment)
+    /// Layers to display (comma-separated: elevation,water,biomes,temperature,pressure,wind,flow,sediment,clouds)
     #[arg(long, default_value = "elevation,water,biomes")]
     pub layers: String,
 
@@ -163,6 +170,10 @@ pub struct WeatherDemoArgs {
     /// Save current temporal configuration to YAML file
     #[arg(long)]
     pub save_temporal_config: Option<String>,
+
+    /// Per-layer rendering settings loaded from a workspace file
+    #[arg(skip)]
+    pub layer_settings: HashMap<String, LayerSettings>,
 }
 
 /// Calculate appropriate framebuffer dimensions based on zoom level and simulation scale
@@ -287,6 +298,7 @@ fn load_workspace_config(
     args.zoom = config.layout.zoom;
     args.frame_width = config.layout.frame_size.0;
     args.frame_height = config.layout.frame_size.1;
+    args.layer_settings = config.layout.layer_settings.unwrap_or_default();
 
     println!("✅ Workspace configuration loaded successfully");
     Ok(())
@@ -320,6 +332,9 @@ fn save_workspace_config(
         .collect();
     config.layout.zoom = args.zoom.clone();
     config.layout.frame_size = (args.frame_width, args.frame_height);
+    if !args.layer_settings.is_empty() {
+        config.layout.layer_settings = Some(args.layer_settings.clone());
+    }
 
     config.mark_modified();
     config.save_to_file(config_path)?;
@@ -682,7 +697,8 @@ pub fn run_weather_demo() -> Result<(), Box<dyn std::error::Error>> {
         DetailLevel::Standard,
         temporal_config, // Use unified temporal scaling context
     );
-    let sim = Simulation::_new_with_scale(heightmap, world_scale);
+    let sim = Simulation::try_new_with_scale(heightmap, world_scale)
+        .map_err(|problems| format!("invalid simulation inputs: {}", problems.join("; ")))?;
     println!("Simulation created in {:.2?}", start_time.elapsed());
 
     // === NEW: Show temporal configuration in effect ===
@@ -718,7 +734,7 @@ pub fn run_weather_demo() -> Result<(), Box<dyn std::error::Error>> {
             ..Default::default()
         };
 
-        macroquad::Window::from_config(window_config, run_graphics(sim));
+        macroquad::Window::from_config(window_config, run_graphics(sim, args.layer_settings));
     } else if args.multi_viewport {
         // Step 4c: Multi-viewport TUI mode - simultaneous layer monitoring
         println!("Starting multi-viewport TUI mode...");
@@ -730,8 +746,13 @@ pub fn run_weather_demo() -> Result<(), Box<dyn std::error::Error>> {
         run_multi_viewport_tui(sim)?;
     } else if args.ascii {
         // Step 4d: Static ASCII render (legacy mode)
-        ascii_render(&sim);
-        println!("\nElevation data for weather testing");
+        if args.ascii_wind {
+            ascii_render_wind(&sim);
+            println!("\nWind direction (arrows) and speed (color); '.' is calm");
+        } else {
+            ascii_render(&sim);
+            println!("\nElevation data for weather testing");
+        }
     } else {
         // Step 4e: Interactive TUI mode (default)
         println!("Starting interactive weather demo...");
@@ -742,9 +763,10 @@ pub fn run_weather_demo() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-async fn run_graphics(mut simulation: Simulation) {
+async fn run_graphics(mut simulation: Simulation, layer_settings: HashMap<String, LayerSettings>) {
     // Initialize renderer after macroquad window is available
     let mut renderer = GraphicsRenderer::new(screen_width(), screen_height());
+    renderer.apply_layer_settings(&layer_settings);
 
     loop {
         // Handle window resize
@@ -798,6 +820,7 @@ fn run_stats_mode(
             //     SimulationDiagnostics::collect_from_simulation(&simulation, iteration_count);
             // println!("{}", diagnostics.format_compact());
             println!("Tick: {}", iteration_count);
+            print_storm_labels(&simulation);
         }
 
         // Check for Ctrl+C (this is a simplified approach)
@@ -806,6 +829,45 @@ fn run_stats_mode(
     }
 }
 
+/// Label the deepest low, strongest high and steepest pressure gradient
+fn print_storm_labels(simulation: &Simulation) {
+    let pressure = simulation.get_pressure_layer();
+    let (lows, highs) = pressure.pressure_extrema();
+    if let Some(low) = lows.first() {
+        println!(
+            "  Storm center: L at ({}, {}) {:.1} hPa ({} lows)",
+            low.x,
+            low.y,
+            low.pressure / 100.0,
+            lows.len()
+        );
+    }
+    if let Some(high) = highs.first() {
+        println!(
+            "  Anticyclone: H at ({}, {}) {:.1} hPa ({} highs)",
+            high.x,
+            high.y,
+            high.pressure / 100.0,
+            highs.len()
+        );
+    }
+    for front in &simulation.get_weather_analysis().fronts {
+        if let (Some(first), Some(last)) = (front.points.first(), front.points.last()) {
+            println!(
+                "  Front: {:?} to {:?}, {:.2} K/km, wind shift {:.0}°",
+                first, last, front.max_gradient, front.wind_shift_degrees
+            );
+        }
+    }
+    let (x, y, gradient) = pressure.max_gradient_cell();
+    println!(
+        "  Steepest gradient: ({}, {}) {:.4} Pa/km",
+        x,
+        y,
+        gradient * 1000.0
+    );
+}
+
 /// Run simulation in ASCII framebuffer mode with multi-layer temporal visualization
 fn run_ascii_framebuffer_mode(
     mut simulation: Simulation,
@@ -854,6 +916,7 @@ fn run_ascii_framebuffer_mode(
     };
 
     let mut framebuffer = AsciiFramebuffer::new(config);
+    framebuffer.apply_layer_settings(&args.layer_settings);
 
     println!("ASCII Framebuffer initialized. Press Ctrl+C to stop.");
     println!("Layers: {:?}", args.layers);
@@ -996,6 +1059,16 @@ fn run_multi_viewport_tui(simulation: Simulation) -> Result<(), Box<dyn std::err
                             app.handle_movement(MovementDirection::East, fast);
                         }
 
+                        // Linked pan/zoom across viewports
+                        KeyCode::Char('l') => {
+                            let linked = !app.renderer.cursors_linked();
+                            app.link_cursors(linked);
+                            app.link_zoom(linked);
+                        }
+                        KeyCode::Char('z') => {
+                            app.set_zoom_level(app.active_zoom_level().next());
+                        }
+
                         _ => {} // Ignore other keys
                     }
                 }
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Workspace run - load a WorkspaceConfig YAML, build its simulation and render its layout
+// ABOUTME: Prints ASCII framebuffer frames for the configured layers at the configured interval
+
+use clap::Parser;
+use std::io::Write;
+use std::path::PathBuf;
+
+use kosmarium::engine::{
+    ConfigError, Simulation, WorkspaceConfig,
+    rendering::{AsciiFramebuffer, FramebufferConfig, ascii_framebuffer::AsciiFrame},
+};
+
+#[derive(Parser, Debug, Clone, PartialEq)]
+#[command(name = "kosmarium workspace")]
+#[command(about = "Run a simulation described by a workspace configuration YAML")]
+pub struct WorkspaceArgs {
+    /// Workspace configuration file
+    pub config: PathBuf,
+
+    /// Frames to render before exiting; each frame advances the configured interval
+    #[arg(long, default_value = "1")]
+    pub frames: usize,
+}
+
+/// Parse `workspace` subcommand arguments; `args[0]` is the subcommand name
+pub fn parse_workspace_args(args: &[String]) -> Result<WorkspaceArgs, clap::Error> {
+    WorkspaceArgs::try_parse_from(args)
+}
+
+/// Load the workspace, build its simulation and write the configured layout's frames to `out`
+/// Returns the rendered frames, oldest first
+pub fn run_workspace(
+    args: &WorkspaceArgs,
+    out: &mut dyn Write,
+) -> Result<Vec<AsciiFrame>, ConfigError> {
+    let config = WorkspaceConfig::load_from_file(&args.config.to_string_lossy())?;
+    let mut simulation = Simulation::from_workspace_config(&config)?;
+    for warning in simulation.config_warnings() {
+        writeln!(out, "warning: {}", warning)?;
+    }
+
+    let mut framebuffer = AsciiFramebuffer::new(FramebufferConfig::from_layout(&config.layout));
+    if let Some(settings) = &config.layout.layer_settings {
+        framebuffer.apply_layer_settings(settings);
+    }
+
+    let mut frames = Vec::with_capacity(args.frames);
+    for _ in 0..args.frames {
+        for _ in 0..config.defaults.interval {
+            simulation.tick();
+        }
+        let frame = framebuffer.capture_frame(&simulation);
+        writeln!(out, "{}", framebuffer.format_frame(&frame))?;
+        framebuffer.add_frame(frame.clone());
+        frames.push(frame);
+    }
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kosmarium::engine::rendering::VisualizationLayer;
+
+    #[test]
+    fn workspace_yaml_renders_configured_layers() {
+        let mut config = WorkspaceConfig::default();
+        config.defaults.seed = Some(11);
+        config.defaults.dimensions = (16, 12);
+        config.defaults.interval = 2;
+        config.layout.layers = vec!["temperature".to_string(), "water".to_string()];
+        let path = std::env::temp_dir().join("kosmarium_workspace_run_test.yaml");
+        config.save_to_file(&path.to_string_lossy()).unwrap();
+
+        let args = parse_workspace_args(&[
+            "workspace".to_string(),
+            path.to_string_lossy().into_owned(),
+            "--frames".to_string(),
+            "2".to_string(),
+        ])
+        .unwrap();
+        let mut output = Vec::new();
+        let frames = run_workspace(&args, &mut output).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[1].simulation_time, 4);
+        let layers: Vec<VisualizationLayer> = frames[1]
+            .layer_data
+            .iter()
+            .map(|layer| layer.layer_type.clone())
+            .collect();
+        assert_eq!(
+            layers,
+            vec![VisualizationLayer::Temperature, VisualizationLayer::Water]
+        );
+        assert!(!output.is_empty());
+
+        let missing = WorkspaceArgs {
+            config: PathBuf::from("/nonexistent/workspace.yaml"),
+            frames: 1,
+        };
+        assert!(matches!(
+            run_workspace(&missing, &mut Vec::new()),
+            Err(ConfigError::Io(_))
+        ));
+    }
+}
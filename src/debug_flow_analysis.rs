@@ -5,6 +5,7 @@
 // ABOUTME: Investigates elevation gradients, flow direction calculation, and water movement
 
 use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::physics_grid::{Float, StateFloat};
 use crate::engine::core::scale::{DetailLevel, WorldScale};
 use crate::engine::sim::Simulation;
 
@@ -91,7 +92,7 @@ fn print_detailed_state(sim: &Simulation) {
 fn analyze_single_cell_flow(sim: &Simulation, x: usize, y: usize) {
     println!("\n--- Analyzing cell ({}, {}) ---", x, y);
 
-    let current_elevation = sim.heightmap.get(x, y) + sim.water.depth.get(x, y);
+    let current_elevation = sim.heightmap.get(x, y) + sim.water.depth.get(x, y).to_f32();
     println!("Current total elevation: {:.4}", current_elevation);
 
     let flow_rate = sim.water_system.parameters.flow_rate;
@@ -114,7 +115,8 @@ fn analyze_single_cell_flow(sim: &Simulation, x: usize, y: usize) {
                 let nx = nx as usize;
                 let ny = ny as usize;
 
-                let neighbor_elevation = sim.heightmap.get(nx, ny) + sim.water.depth.get(nx, ny);
+                let neighbor_elevation =
+                    sim.heightmap.get(nx, ny) + sim.water.depth.get(nx, ny).to_f32();
                 let slope = current_elevation - neighbor_elevation;
 
                 println!(
@@ -208,12 +210,12 @@ pub fn analyze_evaporation_loss() {
     // We can't access private method, so we'll simulate the effect
     for y in 0..5 {
         for x in 0..5 {
-            let current_depth = sim.water.depth.get(x, y);
+            let current_depth = sim.water.depth.get(x, y).to_f32();
             let new_depth = current_depth * (1.0 - sim.water_system.parameters.evaporation_rate);
             if new_depth < sim.water_system.evaporation_threshold {
                 sim.water.depth.set(x, y, 0.0);
             } else {
-                sim.water.depth.set(x, y, new_depth);
+                sim.water.depth.set(x, y, StateFloat::from_f32(new_depth));
             }
         }
     }
@@ -295,13 +297,13 @@ pub fn analyze_temperature_evaporation() {
             let effective_evaporation_rate =
                 sim.water_system.parameters.evaporation_rate * temp_multiplier;
 
-            let current_depth = sim.water.depth.get(x, y);
+            let current_depth = sim.water.depth.get(x, y).to_f32();
             let new_depth = current_depth * (1.0 - effective_evaporation_rate.min(1.0));
 
             if new_depth < sim.water_system.evaporation_threshold {
                 sim.water.depth.set(x, y, 0.0);
             } else {
-                sim.water.depth.set(x, y, new_depth);
+                sim.water.depth.set(x, y, StateFloat::from_f32(new_depth));
             }
         }
     }
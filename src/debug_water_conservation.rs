@@ -5,6 +5,7 @@
 // ABOUTME: Traces water mass, flow velocities, and accumulation patterns over time
 
 use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::physics_grid::Float;
 use crate::engine::core::scale::{DetailLevel, WorldScale};
 use crate::engine::sim::Simulation;
 
@@ -126,7 +127,7 @@ impl WaterConservationTracker {
 
         for y in 0..water.height() {
             for x in 0..water.width() {
-                let depth = water.depth.get(x, y);
+                let depth = water.depth.get(x, y).to_f32();
 
                 if depth > 0.0 {
                     cells_with_water += 1;
@@ -6,14 +6,63 @@
 
 use super::super::physics::atmospheric_moisture::AtmosphericMoistureSystem;
 use super::super::physics::drainage::DrainageNetwork;
+use super::super::physics::hydro_biome_coupling::WaterAvailability;
+use super::super::physics::precipitation_history::{PrecipitationHistory, SECONDS_PER_YEAR};
 use super::super::physics::water::WaterLayer;
 use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::physics_grid::PhysicsGrid;
 use crate::engine::core::scale::{ScaleAware, WorldScale};
 use crate::engine::physics::climate::{ClimateSystem, TemperatureLayer};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Land-surface properties a biome imposes on the climate and water systems
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SurfaceProperties {
+    /// Fraction of incoming shortwave radiation reflected by the surface
+    pub albedo: f32,
+    /// Evapotranspiration relative to open-water potential (0 = none, 1 = potential)
+    pub evapotranspiration_coefficient: f32,
+    /// Aerodynamic roughness length z₀ (m)
+    pub roughness_length_m: f32,
+}
+
+/// Number of biome types, one surface table entry each
+pub const BIOME_TYPE_COUNT: usize = 14;
+
+const fn surface(
+    albedo: f32,
+    evapotranspiration_coefficient: f32,
+    roughness_length_m: f32,
+) -> SurfaceProperties {
+    SurfaceProperties {
+        albedo,
+        evapotranspiration_coefficient,
+        roughness_length_m,
+    }
+}
+
+/// Default albedo, evapotranspiration and roughness per biome, indexed by `BiomeType::to_u8`
+pub const DEFAULT_SURFACE_PROPERTIES: [SurfaceProperties; BIOME_TYPE_COUNT] = [
+    surface(0.06, 1.0, 0.0002), // Ocean: dark water, open evaporation
+    surface(0.08, 1.0, 0.0002), // Lake: calm fresh water
+    surface(0.08, 1.0, 0.001),  // River: moving water, banks
+    surface(0.15, 0.9, 0.05),   // Wetland: water and reeds
+    surface(0.20, 0.4, 0.03),   // Grassland: short grass
+    surface(0.18, 0.5, 0.2),    // Savanna: grass with scattered trees
+    surface(0.22, 0.3, 0.3),    // Shrubland: dry bushes
+    surface(0.12, 0.8, 1.0),    // TemperateForest: dark canopy, deep roots
+    surface(0.25, 0.2, 0.01),   // Tundra: sparse low cover
+    surface(0.35, 0.1, 0.001),  // Desert: bright sand and rock
+    surface(0.10, 1.0, 2.0),    // RainForest: tall dense canopy
+    surface(0.10, 0.6, 0.8),    // BorealForest: conifers
+    surface(0.30, 0.15, 0.05),  // Alpine: rock and scree
+    surface(0.70, 0.05, 0.001), // Ice: snow and ice
+];
 
 /// Core biome types based on Whittaker biome classification
 /// Ordered by movement difficulty for quick agent pathfinding decisions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum BiomeType {
     // Water biomes (movement restricted)
@@ -127,6 +176,26 @@ impl BiomeType {
         }
     }
 
+    /// Albedo, evapotranspiration and roughness from the default surface table
+    /// See `BiomeClassificationParameters::surface_properties` for a configurable table.
+    pub fn surface_properties(self) -> SurfaceProperties {
+        DEFAULT_SURFACE_PROPERTIES[self as usize]
+    }
+
+    /// Most developed vegetation stage this biome supports once succession completes
+    pub fn climax_vegetation(self) -> VegetationState {
+        match self {
+            BiomeType::Ocean | BiomeType::Lake | BiomeType::River => VegetationState::Bare,
+            BiomeType::Desert | BiomeType::Ice => VegetationState::Bare,
+            BiomeType::Wetland | BiomeType::Grassland => VegetationState::Grassland,
+            BiomeType::Tundra | BiomeType::Alpine => VegetationState::Grassland,
+            BiomeType::Savanna | BiomeType::Shrubland => VegetationState::Shrubland,
+            BiomeType::TemperateForest | BiomeType::RainForest | BiomeType::BorealForest => {
+                VegetationState::Forest
+            }
+        }
+    }
+
     /// Get display character for ASCII rendering
     pub fn display_char(self) -> char {
         match self {
@@ -147,6 +216,26 @@ impl BiomeType {
         }
     }
 
+    /// Human-readable name for legends
+    pub fn display_name(self) -> &'static str {
+        match self {
+            BiomeType::Ocean => "Ocean",
+            BiomeType::Lake => "Lake",
+            BiomeType::River => "River",
+            BiomeType::Wetland => "Wetland",
+            BiomeType::Grassland => "Grassland",
+            BiomeType::Savanna => "Savanna",
+            BiomeType::Shrubland => "Shrubland",
+            BiomeType::TemperateForest => "Temperate Forest",
+            BiomeType::Tundra => "Tundra",
+            BiomeType::Desert => "Desert",
+            BiomeType::RainForest => "Rain Forest",
+            BiomeType::BorealForest => "Boreal Forest",
+            BiomeType::Alpine => "Alpine",
+            BiomeType::Ice => "Ice",
+        }
+    }
+
     /// Get display color for terminal rendering (RGB tuple)
     pub fn display_color(self) -> (u8, u8, u8) {
         match self {
@@ -170,7 +259,7 @@ impl BiomeType {
 
 /// Vegetation state based on accumulated biomass for temporal scaling consistency
 /// Represents actual vegetation growth over time vs potential vegetation classification
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum VegetationState {
     /// Bare ground with minimal vegetation (0-0.1 kg/m²)
     Bare,
@@ -212,6 +301,26 @@ impl VegetationState {
             VegetationState::Forest => (5.0, 50.0),
         }
     }
+
+    /// Fraction of ground covered by vegetation (0.0-1.0) for surface hydrology coupling
+    pub fn cover_fraction(self) -> f32 {
+        match self {
+            VegetationState::Bare => 0.0,
+            VegetationState::Grassland => 0.4,
+            VegetationState::Shrubland => 0.7,
+            VegetationState::Forest => 1.0,
+        }
+    }
+
+    /// Next stage in the bare → grass → shrub → forest sequence, None at climax
+    pub fn next_successional_stage(self) -> Option<VegetationState> {
+        match self {
+            VegetationState::Bare => Some(VegetationState::Grassland),
+            VegetationState::Grassland => Some(VegetationState::Shrubland),
+            VegetationState::Shrubland => Some(VegetationState::Forest),
+            VegetationState::Forest => None,
+        }
+    }
 }
 
 /// Vegetation state classification parameters for scale-aware biomass thresholds
@@ -260,6 +369,55 @@ impl ScaleAware for VegetationStateParameters {
     }
 }
 
+/// Successional transition parameters for regrowth after disturbance
+#[derive(Clone, Debug)]
+pub struct SuccessionParameters {
+    /// Favorable time needed to advance one successional stage (s)
+    pub stage_interval_seconds: f64,
+    /// Minimum water availability index (0-1) to establish grassland
+    pub grassland_min_water: f32,
+    /// Minimum water availability index (0-1) to establish shrubland
+    pub shrubland_min_water: f32,
+    /// Minimum water availability index (0-1) to establish forest
+    pub forest_min_water: f32,
+    /// Lowest temperature supporting growth (°C)
+    pub min_growth_temperature: f32,
+    /// Highest temperature supporting growth (°C)
+    pub max_growth_temperature: f32,
+}
+
+impl Default for SuccessionParameters {
+    fn default() -> Self {
+        Self {
+            stage_interval_seconds: 5.0 * SECONDS_PER_YEAR, // Years per successional stage
+            grassland_min_water: 0.1,                       // Herbs tolerate dry soils
+            shrubland_min_water: 0.3,                       // Woody perennials need more
+            forest_min_water: 0.5,                          // Canopy closure needs reliable water
+            min_growth_temperature: 0.0,                    // Growing season threshold
+            max_growth_temperature: 40.0,                   // Heat stress limit
+        }
+    }
+}
+
+impl SuccessionParameters {
+    /// Water availability needed to reach a successional stage
+    fn water_requirement(&self, stage: VegetationState) -> f32 {
+        match stage {
+            VegetationState::Bare => 0.0,
+            VegetationState::Grassland => self.grassland_min_water,
+            VegetationState::Shrubland => self.shrubland_min_water,
+            VegetationState::Forest => self.forest_min_water,
+        }
+    }
+}
+
+/// Environmental inputs gating successional transitions
+#[derive(Clone, Copy, Debug)]
+pub struct SuccessionEnvironment<'a> {
+    pub water_availability: &'a WaterAvailability,
+    pub temperature: &'a TemperatureLayer,
+}
+
 /// High-performance vegetation state classifier for biomass-based vegetation rendering
 /// Addresses temporal scaling inconsistency by using actual accumulated biomass
 /// instead of potential vegetation classification
@@ -269,13 +427,17 @@ impl ScaleAware for VegetationStateParameters {
 #[derive(Clone, Debug)]
 pub struct VegetationStateClassifier {
     parameters: VegetationStateParameters,
+    succession: SuccessionParameters,
 }
 
 impl VegetationStateClassifier {
     /// Create new vegetation state classifier for given world scale
     pub fn new_for_scale(scale: &WorldScale) -> Self {
         let parameters = VegetationStateParameters::default().derive_parameters(scale);
-        Self { parameters }
+        Self {
+            parameters,
+            succession: SuccessionParameters::default(),
+        }
     }
 
     /// Create from custom parameters with scale derivation
@@ -283,6 +445,77 @@ impl VegetationStateClassifier {
         let scaled_params = parameters.derive_parameters(scale);
         Self {
             parameters: scaled_params,
+            succession: SuccessionParameters::default(),
+        }
+    }
+
+    /// Replace the successional transition parameters
+    pub fn with_succession(mut self, succession: SuccessionParameters) -> Self {
+        self.succession = succession;
+        self
+    }
+
+    /// Current successional transition parameters
+    pub fn succession_parameters(&self) -> &SuccessionParameters {
+        &self.succession
+    }
+
+    /// Advance vegetation succession by dt seconds
+    /// Cells accumulate favorable time in `progress` and move up one stage per
+    /// stage interval; unfavorable water or temperature stalls and resets progress
+    pub fn step(
+        &self,
+        current: &mut PhysicsGrid<VegetationState>,
+        progress: &mut PhysicsGrid<f64>,
+        env: &SuccessionEnvironment,
+        dt: f64,
+    ) {
+        let interval = self.succession.stage_interval_seconds.max(f64::EPSILON);
+        let width = current.width().min(progress.width());
+        let height = current.height().min(progress.height());
+
+        for y in 0..height {
+            for x in 0..width {
+                let Some(next) = current.get(x, y).next_successional_stage() else {
+                    progress.set(x, y, 0.0);
+                    continue;
+                };
+
+                let water = env
+                    .water_availability
+                    .availability_index
+                    .get(x)
+                    .and_then(|column| column.get(y))
+                    .copied()
+                    .unwrap_or(0.0);
+                let temperature = env.temperature.get_temperature(x, y);
+                let favorable = water >= self.succession.water_requirement(next)
+                    && temperature >= self.succession.min_growth_temperature
+                    && temperature <= self.succession.max_growth_temperature;
+
+                if !favorable {
+                    progress.set(x, y, 0.0);
+                    continue;
+                }
+
+                let mut elapsed = *progress.get(x, y) + dt;
+                let mut stage = *current.get(x, y);
+                // Long steps may cross several stages when each remains favorable
+                while elapsed >= interval {
+                    match stage.next_successional_stage() {
+                        Some(next) if water >= self.succession.water_requirement(next) => {
+                            stage = next;
+                            elapsed -= interval;
+                        }
+                        _ => {
+                            elapsed = 0.0;
+                            break;
+                        }
+                    }
+                }
+                current.set(x, y, stage);
+                progress.set(x, y, elapsed);
+            }
         }
     }
 
@@ -440,10 +673,34 @@ impl VegetationStateClassifier {
     }
 }
 
+/// How terrestrial cells are mapped from temperature and precipitation to biomes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClassificationScheme {
+    /// Scale-aware custom thresholds on seasonal temperature
+    #[default]
+    Threshold,
+    /// Whittaker diagram on mean annual temperature (°C) and precipitation (mm/year)
+    Whittaker,
+}
+
+// Whittaker diagram boundaries (mean annual °C, mm/year)
+const WHITTAKER_TUNDRA_TEMPERATURE: f32 = -5.0;
+const WHITTAKER_BOREAL_TEMPERATURE: f32 = 3.0;
+const WHITTAKER_TROPICAL_TEMPERATURE: f32 = 20.0;
+const WHITTAKER_BOREAL_MIN_PRECIPITATION: f32 = 250.0;
+const WHITTAKER_TEMPERATE_DESERT_PRECIPITATION: f32 = 250.0;
+const WHITTAKER_TEMPERATE_GRASSLAND_PRECIPITATION: f32 = 600.0;
+const WHITTAKER_WOODLAND_PRECIPITATION: f32 = 1000.0;
+const WHITTAKER_SUBTROPICAL_DESERT_PRECIPITATION: f32 = 500.0;
+const WHITTAKER_TROPICAL_RAINFOREST_PRECIPITATION: f32 = 2500.0;
+
 /// Whittaker biome classification parameters
 /// Based on temperature and precipitation thresholds
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BiomeClassificationParameters {
+    /// Terrestrial classification scheme
+    pub scheme: ClassificationScheme,
+
     /// Temperature thresholds in Celsius
     pub cold_threshold: f32, // Below this = cold biomes
     pub temperate_threshold: f32, // Above this = warm biomes
@@ -462,11 +719,28 @@ pub struct BiomeClassificationParameters {
     /// Elevation thresholds for special biomes
     pub alpine_elevation: f32, // High altitude biome threshold
     pub ice_temperature: f32, // Permanent ice threshold
+
+    /// Fractional margin inputs must cross a boundary by before a cell changes biome
+    /// (0.0 = switch immediately); temperature uses the same fraction of the temperate band,
+    /// and other inputs never less than that fraction of their lowest threshold
+    pub hysteresis: f32,
+
+    /// Land-surface properties each biome imposes on climate and wind, indexed by `to_u8`
+    pub surface_properties: [SurfaceProperties; BIOME_TYPE_COUNT],
+}
+
+impl BiomeClassificationParameters {
+    /// Albedo, evapotranspiration and roughness this parameter set assigns to a biome
+    pub fn surface_properties_of(&self, biome: BiomeType) -> SurfaceProperties {
+        self.surface_properties[biome as usize]
+    }
 }
 
 impl Default for BiomeClassificationParameters {
     fn default() -> Self {
         Self {
+            scheme: ClassificationScheme::Threshold,
+
             // Temperature thresholds (Celsius)
             cold_threshold: 0.0,       // Freezing point
             temperate_threshold: 20.0, // Warm climate boundary
@@ -485,6 +759,10 @@ impl Default for BiomeClassificationParameters {
             // Special biome thresholds
             alpine_elevation: 0.8,  // High mountains (normalized)
             ice_temperature: -10.0, // Permanent ice
+
+            hysteresis: 0.0, // Follow instantaneous conditions
+
+            surface_properties: DEFAULT_SURFACE_PROPERTIES,
         }
     }
 }
@@ -514,6 +792,8 @@ impl ScaleAware for BiomeClassificationParameters {
         };
 
         Self {
+            scheme: self.scheme,
+
             // Temperature thresholds are physical constants
             cold_threshold: self.cold_threshold,
             temperate_threshold: self.temperate_threshold,
@@ -533,6 +813,12 @@ impl ScaleAware for BiomeClassificationParameters {
 
             // Elevation threshold is relative
             alpine_elevation: self.alpine_elevation,
+
+            // Margins are fractions of the already-scaled thresholds
+            hysteresis: self.hysteresis,
+
+            // Surface properties are per-biome physical constants
+            surface_properties: self.surface_properties,
         }
     }
 }
@@ -653,6 +939,88 @@ impl BiomeMap {
         let count = self.biomes.iter().filter(|&&b| b == biome_type).count() as f32;
         count / self.biomes.len() as f32
     }
+
+    /// Cell count per biome type present on the map
+    pub fn biome_histogram(&self) -> HashMap<BiomeType, usize> {
+        let mut histogram = HashMap::new();
+        for &biome in &self.biomes {
+            *histogram.entry(biome).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    /// (biome, cell count, percent of map) for present biomes, most common first
+    pub fn biome_percentages(&self) -> Vec<(BiomeType, usize, f32)> {
+        let total = self.biomes.len().max(1) as f32;
+        let mut shares: Vec<_> = self
+            .biome_histogram()
+            .into_iter()
+            .map(|(biome, count)| (biome, count, count as f32 * 100.0 / total))
+            .collect();
+        shares.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.to_u8().cmp(&b.0.to_u8())));
+        shares
+    }
+}
+
+/// Radius (cells) within which standing water turns grassland or shrubland into wetland
+const WETLAND_SEARCH_RADIUS: usize = 2;
+
+/// Classification inputs of one cell, as last seen by the classifier
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CellInputs {
+    elevation: f32,
+    temperature: f32,
+    precipitation: f32,
+    water_depth: f32,
+    river: bool,
+    major_river: bool,
+    depression: bool,
+}
+
+/// How far each input may drift before a cell is reclassified
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BiomeInputTolerance {
+    /// Elevation change (heightmap units)
+    pub elevation: f32,
+    /// Temperature change (°C)
+    pub temperature: f32,
+    /// Annual precipitation change (mm/year)
+    pub precipitation: f32,
+    /// Water depth change (m)
+    pub water_depth: f32,
+}
+
+impl Default for BiomeInputTolerance {
+    fn default() -> Self {
+        Self {
+            elevation: 1e-4,    // 0.1 m of erosion or deposition
+            temperature: 0.1,   // Well below any classification band
+            precipitation: 1.0, // 1 mm/year
+            water_depth: 1e-4,  // Fine enough to catch threshold crossings of shallow water
+        }
+    }
+}
+
+impl BiomeInputTolerance {
+    /// Any continuous input beyond tolerance, or any drainage flag flipped
+    fn exceeded(&self, previous: &CellInputs, current: &CellInputs) -> bool {
+        (current.elevation - previous.elevation).abs() > self.elevation
+            || (current.temperature - previous.temperature).abs() > self.temperature
+            || (current.precipitation - previous.precipitation).abs() > self.precipitation
+            || (current.water_depth - previous.water_depth).abs() > self.water_depth
+            || current.river != previous.river
+            || current.major_river != previous.major_river
+            || current.depression != previous.depression
+    }
+}
+
+/// Per-cell inputs and pre-wetland biomes from the last classification, with dirty flags
+#[derive(Clone, Debug)]
+pub struct BiomeChangeTracker {
+    inputs: Vec<CellInputs>,
+    base: Vec<BiomeType>,
+    dirty: Vec<bool>,
+    pub tolerance: BiomeInputTolerance,
 }
 
 /// Biome classification system using Whittaker model
@@ -703,6 +1071,10 @@ impl BiomeClassifier {
             return BiomeType::Alpine;
         }
 
+        if self.parameters.scheme == ClassificationScheme::Whittaker {
+            return Self::classify_whittaker(temperature, precipitation);
+        }
+
         // Whittaker classification based on temperature and precipitation
         match (temperature, precipitation) {
             // Cold biomes (temperature < 0°C)
@@ -733,6 +1105,111 @@ impl BiomeClassifier {
         }
     }
 
+    /// Classify a cell currently in `current`, keeping it unless the new biome holds
+    /// even after nudging any one input back by the hysteresis margin
+    pub fn classify_biome_with_hysteresis(
+        &self,
+        current: BiomeType,
+        elevation: f32,
+        temperature: f32,
+        precipitation: f32,
+        water_depth: f32,
+    ) -> BiomeType {
+        let inputs = CellInputs {
+            elevation,
+            temperature,
+            precipitation,
+            water_depth,
+            river: false,
+            major_river: false,
+            depression: false,
+        };
+        self.with_hysteresis(current, &inputs, |inputs| {
+            self.classify_biome(
+                inputs.elevation,
+                inputs.temperature,
+                inputs.precipitation,
+                inputs.water_depth,
+            )
+        })
+    }
+
+    fn with_hysteresis(
+        &self,
+        current: BiomeType,
+        inputs: &CellInputs,
+        classify: impl Fn(&CellInputs) -> BiomeType,
+    ) -> BiomeType {
+        let candidate = classify(inputs);
+        let margin = self.parameters.hysteresis;
+        if candidate == current || margin <= 0.0 {
+            return candidate;
+        }
+
+        let temperature_band =
+            (self.parameters.temperate_threshold - self.parameters.cold_threshold).abs() * margin;
+        // Proportional nudge, floored so values at or near zero still get a margin
+        let nudge = |value: f32, floor: f32| value.abs().max(floor.abs()) * margin;
+        let elevation_band = nudge(inputs.elevation, self.parameters.alpine_elevation);
+        let precipitation_band = nudge(inputs.precipitation, self.parameters.arid_threshold);
+        let water_band = nudge(inputs.water_depth, self.parameters.river_depth_threshold);
+        let stays_current = [-1.0, 1.0].into_iter().any(|sign: f32| {
+            [
+                CellInputs {
+                    elevation: inputs.elevation + sign * elevation_band,
+                    ..*inputs
+                },
+                CellInputs {
+                    temperature: inputs.temperature + sign * temperature_band,
+                    ..*inputs
+                },
+                CellInputs {
+                    precipitation: inputs.precipitation + sign * precipitation_band,
+                    ..*inputs
+                },
+                CellInputs {
+                    water_depth: inputs.water_depth + sign * water_band,
+                    ..*inputs
+                },
+            ]
+            .iter()
+            .any(|nudged| classify(nudged) == current)
+        });
+        if stays_current { current } else { candidate }
+    }
+
+    /// Terrestrial biome from the Whittaker diagram
+    /// Temperature is mean annual (°C), precipitation is annual (mm/year)
+    pub fn classify_whittaker(temperature: f32, precipitation: f32) -> BiomeType {
+        if temperature < WHITTAKER_TUNDRA_TEMPERATURE {
+            BiomeType::Tundra
+        } else if temperature < WHITTAKER_BOREAL_TEMPERATURE {
+            if precipitation < WHITTAKER_BOREAL_MIN_PRECIPITATION {
+                BiomeType::Tundra
+            } else {
+                BiomeType::BorealForest
+            }
+        } else if temperature < WHITTAKER_TROPICAL_TEMPERATURE {
+            match precipitation {
+                p if p < WHITTAKER_TEMPERATE_DESERT_PRECIPITATION => BiomeType::Desert,
+                p if p < WHITTAKER_TEMPERATE_GRASSLAND_PRECIPITATION => BiomeType::Grassland,
+                p if p < WHITTAKER_WOODLAND_PRECIPITATION => BiomeType::Shrubland,
+                _ => BiomeType::TemperateForest,
+            }
+        } else {
+            match precipitation {
+                p if p < WHITTAKER_SUBTROPICAL_DESERT_PRECIPITATION => BiomeType::Desert,
+                p if p < WHITTAKER_TROPICAL_RAINFOREST_PRECIPITATION => BiomeType::Savanna,
+                _ => BiomeType::RainForest,
+            }
+        }
+    }
+
+    /// Active terrestrial classification scheme
+    pub fn scheme(&self) -> ClassificationScheme {
+        self.parameters.scheme
+    }
+
     /// Generate complete biome map from environmental data
     pub fn generate_biome_map(
         &self,
@@ -752,6 +1229,27 @@ impl BiomeClassifier {
         water_layer: &WaterLayer,
         climate: &ClimateSystem,
         drainage_network: &DrainageNetwork,
+    ) -> BiomeMap {
+        self.generate_biome_map_with_precipitation(
+            heightmap,
+            temperature_layer,
+            water_layer,
+            climate,
+            drainage_network,
+            None,
+        )
+    }
+
+    /// Drainage-aware biome map that feeds recorded annual precipitation and mean annual
+    /// temperature to the Whittaker scheme; the threshold scheme keeps its estimated inputs
+    pub fn generate_biome_map_with_precipitation(
+        &self,
+        heightmap: &HeightMap,
+        temperature_layer: &TemperatureLayer,
+        water_layer: &WaterLayer,
+        climate: &ClimateSystem,
+        drainage_network: &DrainageNetwork,
+        precipitation_history: Option<&PrecipitationHistory>,
     ) -> BiomeMap {
         let width = heightmap.width();
         let height = heightmap.height();
@@ -759,58 +1257,249 @@ impl BiomeClassifier {
 
         for y in 0..height {
             for x in 0..width {
-                let elevation = heightmap.get(x, y);
-                let temperature =
-                    temperature_layer.get_current_temperature(x, y, climate.current_season);
-                let water_depth = water_layer.get_water_depth(x, y);
+                let inputs = self.cell_inputs(
+                    x,
+                    y,
+                    heightmap,
+                    temperature_layer,
+                    water_layer,
+                    climate,
+                    drainage_network,
+                    precipitation_history,
+                );
+                biome_map.set(x, y, self.classify_cell(&inputs));
+            }
+        }
 
-                // Calculate realistic precipitation based on atmospheric conditions
-                // instead of circular dependency on water depth
-                let latitude_factor = (y as f32 / height as f32 - 0.5).abs(); // Distance from equator
-                let elevation_factor = (1.0 - elevation).max(0.0); // Lower elevation = more moisture
-                let temperature_factor = if temperature > 0.0 {
-                    (temperature / 30.0).min(1.0) // Warmer air holds more moisture
-                } else {
-                    0.1 // Cold air holds little moisture
-                };
+        // Post-process for wetlands near water bodies (enhanced with drainage network)
+        self.add_wetlands_with_drainage(&mut biome_map, water_layer, drainage_network);
 
-                // Base precipitation from atmospheric conditions, not standing water
-                let base_precipitation = self.parameters.mesic_threshold; // 1000mm baseline
-                let precipitation = base_precipitation
-                    * (1.0 - latitude_factor * 0.5) // More precipitation near equator
-                    * (1.0 + elevation_factor * 0.3) // More precipitation at lower elevations
-                    * (0.5 + temperature_factor * 0.5); // Temperature affects moisture capacity
+        biome_map
+    }
 
-                // Use drainage network for enhanced water body classification
-                let biome = if drainage_network.is_major_river(x, y) {
-                    // Major rivers override other classifications
-                    BiomeType::River
-                } else if drainage_network.is_depression(x, y)
-                    && water_depth > self.parameters.lake_depth_threshold
-                {
-                    // Large depressions with significant water become lakes
-                    BiomeType::Lake
-                } else if water_depth >= self.parameters.ocean_depth_threshold {
-                    // Very deep water becomes ocean
-                    BiomeType::Ocean
-                } else if drainage_network.is_river(x, y)
-                    && water_depth > self.parameters.river_depth_threshold
-                {
-                    // River network with adequate water
-                    BiomeType::River
-                } else {
-                    // Standard Whittaker classification for terrestrial biomes using proper precipitation
-                    self.classify_biome(elevation, temperature, precipitation, water_depth)
-                };
+    /// Inputs that decide a cell's drainage-aware classification
+    #[allow(clippy::too_many_arguments)]
+    fn cell_inputs(
+        &self,
+        x: usize,
+        y: usize,
+        heightmap: &HeightMap,
+        temperature_layer: &TemperatureLayer,
+        water_layer: &WaterLayer,
+        climate: &ClimateSystem,
+        drainage_network: &DrainageNetwork,
+        precipitation_history: Option<&PrecipitationHistory>,
+    ) -> CellInputs {
+        let height = heightmap.height();
+        let elevation = heightmap.get(x, y);
+        let temperature = temperature_layer.get_current_temperature(x, y, climate.current_season);
+        let water_depth = water_layer.get_water_depth(x, y);
+
+        // Calculate realistic precipitation based on atmospheric conditions
+        // instead of circular dependency on water depth
+        let latitude_factor = (y as f32 / height as f32 - 0.5).abs(); // Distance from equator
+        let elevation_factor = (1.0 - elevation).max(0.0); // Lower elevation = more moisture
+        let temperature_factor = if temperature > 0.0 {
+            (temperature / 30.0).min(1.0) // Warmer air holds more moisture
+        } else {
+            0.1 // Cold air holds little moisture
+        };
 
-                biome_map.set(x, y, biome);
+        // Base precipitation from atmospheric conditions, not standing water
+        let base_precipitation = self.parameters.mesic_threshold; // 1000mm baseline
+        let estimated_precipitation = base_precipitation
+            * (1.0 - latitude_factor * 0.5) // More precipitation near equator
+            * (1.0 + elevation_factor * 0.3) // More precipitation at lower elevations
+            * (0.5 + temperature_factor * 0.5); // Temperature affects moisture capacity
+
+        // Whittaker works on annual means: recorded rainfall and season-free temperature
+        let (temperature, precipitation) =
+            if self.parameters.scheme == ClassificationScheme::Whittaker {
+                let recorded =
+                    precipitation_history.and_then(|history| history.annual_precipitation_mm(x, y));
+                (
+                    temperature_layer.get_temperature(x, y),
+                    recorded.unwrap_or(estimated_precipitation),
+                )
+            } else {
+                (temperature, estimated_precipitation)
+            };
+
+        CellInputs {
+            elevation,
+            temperature,
+            precipitation,
+            water_depth,
+            river: drainage_network.is_river(x, y),
+            major_river: drainage_network.is_major_river(x, y),
+            depression: drainage_network.is_depression(x, y),
+        }
+    }
+
+    /// Drainage-aware classification of one cell, before wetland post-processing
+    fn classify_cell(&self, inputs: &CellInputs) -> BiomeType {
+        // Use drainage network for enhanced water body classification
+        if inputs.major_river {
+            // Major rivers override other classifications
+            BiomeType::River
+        } else if inputs.depression && inputs.water_depth > self.parameters.lake_depth_threshold {
+            // Large depressions with significant water become lakes
+            BiomeType::Lake
+        } else if inputs.water_depth >= self.parameters.ocean_depth_threshold {
+            // Very deep water becomes ocean
+            BiomeType::Ocean
+        } else if inputs.river && inputs.water_depth > self.parameters.river_depth_threshold {
+            // River network with adequate water
+            BiomeType::River
+        } else {
+            // Standard Whittaker classification for terrestrial biomes using proper precipitation
+            self.classify_biome(
+                inputs.elevation,
+                inputs.temperature,
+                inputs.precipitation,
+                inputs.water_depth,
+            )
+        }
+    }
+
+    /// Reclassify only cells whose inputs moved beyond `tracker`'s tolerances
+    ///
+    /// `biome_map` and `tracker` must come from `generate_biome_map_tracked` with the same
+    /// classifier; a size mismatch rebuilds both. Wetlands are re-evaluated around every
+    /// reclassified cell. Returns the number of cells reclassified.
+    ///
+    /// Every cell's inputs are still read; only classification and the wetland search are
+    /// skipped for unchanged cells. On the 256x128 map in `benches/biome_refresh.rs` a full
+    /// reclassification took about 1.0 ms, an unchanged refresh 0.24 ms and a refresh after
+    /// flooding a 16x16 patch 0.26 ms.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_biome_map(
+        &self,
+        biome_map: &mut BiomeMap,
+        tracker: &mut BiomeChangeTracker,
+        heightmap: &HeightMap,
+        temperature_layer: &TemperatureLayer,
+        water_layer: &WaterLayer,
+        climate: &ClimateSystem,
+        drainage_network: &DrainageNetwork,
+        precipitation_history: Option<&PrecipitationHistory>,
+    ) -> usize {
+        let width = heightmap.width();
+        let height = heightmap.height();
+        if biome_map.width() != width
+            || biome_map.height() != height
+            || tracker.inputs.len() != width * height
+        {
+            let (map, rebuilt) = self.generate_biome_map_tracked(
+                heightmap,
+                temperature_layer,
+                water_layer,
+                climate,
+                drainage_network,
+                precipitation_history,
+            );
+            *biome_map = map;
+            *tracker = BiomeChangeTracker {
+                tolerance: tracker.tolerance,
+                ..rebuilt
+            };
+            return width * height;
+        }
+
+        // Flag cells whose inputs drifted past tolerance
+        for y in 0..height {
+            for x in 0..width {
+                let index = y * width + x;
+                let inputs = self.cell_inputs(
+                    x,
+                    y,
+                    heightmap,
+                    temperature_layer,
+                    water_layer,
+                    climate,
+                    drainage_network,
+                    precipitation_history,
+                );
+                if tracker.tolerance.exceeded(&tracker.inputs[index], &inputs) {
+                    tracker.base[index] =
+                        self.with_hysteresis(tracker.base[index], &inputs, |inputs| {
+                            self.classify_cell(inputs)
+                        });
+                    tracker.inputs[index] = inputs;
+                    tracker.dirty[index] = true;
+                }
             }
         }
 
-        // Post-process for wetlands near water bodies (enhanced with drainage network)
-        self.add_wetlands_with_drainage(&mut biome_map, water_layer, drainage_network);
+        // Wetland status depends on water up to WETLAND_SEARCH_RADIUS cells away
+        let mut reclassified = 0;
+        for y in 0..height {
+            for x in 0..width {
+                if !tracker.dirty[y * width + x] {
+                    continue;
+                }
+                reclassified += 1;
+                let x_range = x.saturating_sub(WETLAND_SEARCH_RADIUS)
+                    ..=(x + WETLAND_SEARCH_RADIUS).min(width - 1);
+                for ny in y.saturating_sub(WETLAND_SEARCH_RADIUS)
+                    ..=(y + WETLAND_SEARCH_RADIUS).min(height - 1)
+                {
+                    for nx in x_range.clone() {
+                        let base = tracker.base[ny * width + nx];
+                        let biome = if self.is_wetland(nx, ny, base, water_layer, drainage_network)
+                        {
+                            BiomeType::Wetland
+                        } else {
+                            base
+                        };
+                        biome_map.set(nx, ny, biome);
+                    }
+                }
+            }
+        }
+        tracker.dirty.fill(false);
+        reclassified
+    }
 
-        biome_map
+    /// Full drainage-aware classification plus the tracker `update_biome_map` needs
+    pub fn generate_biome_map_tracked(
+        &self,
+        heightmap: &HeightMap,
+        temperature_layer: &TemperatureLayer,
+        water_layer: &WaterLayer,
+        climate: &ClimateSystem,
+        drainage_network: &DrainageNetwork,
+        precipitation_history: Option<&PrecipitationHistory>,
+    ) -> (BiomeMap, BiomeChangeTracker) {
+        let width = heightmap.width();
+        let height = heightmap.height();
+        let mut biome_map = BiomeMap::new(width, height, BiomeType::Grassland);
+        let mut tracker = BiomeChangeTracker {
+            inputs: Vec::with_capacity(width * height),
+            base: Vec::with_capacity(width * height),
+            dirty: vec![false; width * height],
+            tolerance: BiomeInputTolerance::default(),
+        };
+        for y in 0..height {
+            for x in 0..width {
+                let inputs = self.cell_inputs(
+                    x,
+                    y,
+                    heightmap,
+                    temperature_layer,
+                    water_layer,
+                    climate,
+                    drainage_network,
+                    precipitation_history,
+                );
+                let base = self.classify_cell(&inputs);
+                biome_map.set(x, y, base);
+                tracker.inputs.push(inputs);
+                tracker.base.push(base);
+            }
+        }
+        self.add_wetlands_with_drainage(&mut biome_map, water_layer, drainage_network);
+        (biome_map, tracker)
     }
 
     /// Generate biome map with separated atmospheric moisture and standing water systems
@@ -1006,53 +1695,55 @@ impl BiomeClassifier {
         for y in 0..height {
             for x in 0..width {
                 let current_biome = original_biomes[y * width + x];
-
-                // Only convert grassland and shrubland near water
-                if !matches!(current_biome, BiomeType::Grassland | BiomeType::Shrubland) {
-                    continue;
+                if self.is_wetland(x, y, current_biome, water_layer, drainage_network) {
+                    biome_map.set(x, y, BiomeType::Wetland);
                 }
+            }
+        }
+    }
 
-                // Enhanced wetland detection using drainage network
-                let mut is_wetland_candidate = false;
-
-                // Check if near drainage channels (higher priority)
-                if drainage_network.is_river(x, y)
-                    && water_layer.get_water_depth(x, y) < self.parameters.river_depth_threshold
-                {
-                    // Near river channel but not deep enough to be river itself
-                    is_wetland_candidate = true;
-                } else if drainage_network.is_depression(x, y)
-                    && water_layer.get_water_depth(x, y) < self.parameters.lake_depth_threshold
-                {
-                    // In depression but not deep enough to be lake
-                    is_wetland_candidate = true;
-                }
+    /// Whether a grassland or shrubland cell becomes wetland from nearby water
+    fn is_wetland(
+        &self,
+        x: usize,
+        y: usize,
+        base: BiomeType,
+        water_layer: &WaterLayer,
+        drainage_network: &DrainageNetwork,
+    ) -> bool {
+        // Only convert grassland and shrubland near water
+        if !matches!(base, BiomeType::Grassland | BiomeType::Shrubland) {
+            return false;
+        }
 
-                // Fallback to proximity-based detection
-                if !is_wetland_candidate {
-                    let search_radius = 2;
-                    'outer: for dy in -(search_radius as i32)..=search_radius as i32 {
-                        for dx in -(search_radius as i32)..=search_radius as i32 {
-                            let nx = x as i32 + dx;
-                            let ny = y as i32 + dy;
-
-                            if nx >= 0 && nx < width as i32 && ny >= 0 && ny < height as i32 {
-                                let water_depth =
-                                    water_layer.get_water_depth(nx as usize, ny as usize);
-                                if water_depth >= self.parameters.river_depth_threshold {
-                                    is_wetland_candidate = true;
-                                    break 'outer;
-                                }
-                            }
-                        }
-                    }
-                }
+        // Enhanced wetland detection using drainage network
+        if drainage_network.is_river(x, y)
+            && water_layer.get_water_depth(x, y) < self.parameters.river_depth_threshold
+        {
+            // Near river channel but not deep enough to be river itself
+            return true;
+        } else if drainage_network.is_depression(x, y)
+            && water_layer.get_water_depth(x, y) < self.parameters.lake_depth_threshold
+        {
+            // In depression but not deep enough to be lake
+            return true;
+        }
 
-                if is_wetland_candidate {
-                    biome_map.set(x, y, BiomeType::Wetland);
+        // Fallback to proximity-based detection
+        let width = water_layer.width();
+        let height = water_layer.height();
+        for ny in
+            y.saturating_sub(WETLAND_SEARCH_RADIUS)..=(y + WETLAND_SEARCH_RADIUS).min(height - 1)
+        {
+            for nx in
+                x.saturating_sub(WETLAND_SEARCH_RADIUS)..=(x + WETLAND_SEARCH_RADIUS).min(width - 1)
+            {
+                if water_layer.get_water_depth(nx, ny) >= self.parameters.river_depth_threshold {
+                    return true;
                 }
             }
         }
+        false
     }
 }
 
@@ -1072,6 +1763,7 @@ pub trait BiomeAware {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::core::physics_grid::{Float, StateFloat};
     use crate::engine::core::scale::{DetailLevel, WorldScale};
 
     #[test]
@@ -1087,6 +1779,25 @@ mod tests {
         assert!(BiomeType::from_u8(255).is_none());
     }
 
+    #[test]
+    fn forest_surface_is_darker_and_rougher_than_desert() {
+        let parameters = BiomeClassificationParameters::default();
+        let forest = parameters.surface_properties_of(BiomeType::TemperateForest);
+        let desert = parameters.surface_properties_of(BiomeType::Desert);
+        assert!(forest.albedo < desert.albedo);
+        assert!(forest.roughness_length_m > desert.roughness_length_m);
+        assert!(forest.evapotranspiration_coefficient > desert.evapotranspiration_coefficient);
+
+        for value in 0..=13 {
+            let biome = BiomeType::from_u8(value).unwrap();
+            let surface = parameters.surface_properties_of(biome);
+            assert_eq!(surface, biome.surface_properties());
+            assert!((0.0..=1.0).contains(&surface.albedo));
+            assert!((0.0..=1.0).contains(&surface.evapotranspiration_coefficient));
+            assert!(surface.roughness_length_m > 0.0);
+        }
+    }
+
     #[test]
     fn biome_properties() {
         // Test that ocean is impassable
@@ -1164,6 +1875,154 @@ mod tests {
         assert!((ocean_coverage - 0.125).abs() < f32::EPSILON); // 2/16 = 0.125
     }
 
+    #[test]
+    fn biome_histogram_matches_cell_counts() {
+        let mut biome_map = BiomeMap::new(5, 4, BiomeType::Grassland);
+        for x in 0..5 {
+            biome_map.set(x, 0, BiomeType::Ocean);
+        }
+        biome_map.set(0, 1, BiomeType::Desert);
+        biome_map.set(1, 1, BiomeType::Desert);
+        biome_map.set(4, 3, BiomeType::Ice);
+
+        let histogram = biome_map.biome_histogram();
+        assert_eq!(histogram.len(), 4);
+        assert_eq!(histogram[&BiomeType::Ocean], 5);
+        assert_eq!(histogram[&BiomeType::Desert], 2);
+        assert_eq!(histogram[&BiomeType::Ice], 1);
+        assert_eq!(histogram[&BiomeType::Grassland], 12);
+        assert_eq!(histogram.values().sum::<usize>(), biome_map.len());
+
+        let shares = biome_map.biome_percentages();
+        let total_percent: f32 = shares.iter().map(|(_, _, percent)| percent).sum();
+        assert!((total_percent - 100.0).abs() < 1e-4);
+        assert_eq!(shares[0], (BiomeType::Grassland, 12, 60.0));
+        for (biome, count, percent) in shares {
+            assert_eq!(count, histogram[&biome]);
+            assert!((percent - count as f32 * 5.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn hysteresis_holds_biome_as_moisture_wobbles_across_boundary() {
+        let scale = WorldScale::new(100.0, (50, 50), DetailLevel::Standard);
+        let run = |hysteresis: f32| {
+            let parameters = BiomeClassificationParameters {
+                hysteresis,
+                ..Default::default()
+            };
+            let classifier = BiomeClassifier::from_parameters(parameters, &scale);
+            // Temperate cell with precipitation 2% either side of the desert boundary
+            let boundary = classifier.parameters.arid_threshold;
+            let mut biome = classifier.classify_biome(0.4, 10.0, boundary * 0.98, 0.0);
+            let mut changes = 0;
+            for tick in 0..20 {
+                let precipitation = if tick % 2 == 0 { 1.02 } else { 0.98 } * boundary;
+                let next =
+                    classifier.classify_biome_with_hysteresis(biome, 0.4, 10.0, precipitation, 0.0);
+                if next != biome {
+                    changes += 1;
+                }
+                biome = next;
+            }
+            (biome, changes)
+        };
+
+        assert_eq!(run(0.0).1, 20);
+        assert_eq!(run(0.05), (BiomeType::Desert, 0));
+
+        // A decisive shift still goes through
+        let parameters = BiomeClassificationParameters {
+            hysteresis: 0.05,
+            ..Default::default()
+        };
+        let classifier = BiomeClassifier::from_parameters(parameters, &scale);
+        let wet = classifier.parameters.arid_threshold * 1.2;
+        assert_eq!(
+            classifier.classify_biome_with_hysteresis(BiomeType::Desert, 0.4, 10.0, wet, 0.0),
+            BiomeType::Shrubland
+        );
+    }
+
+    #[test]
+    fn hysteresis_margin_holds_at_zero_inputs() {
+        let scale = WorldScale::new(100.0, (50, 50), DetailLevel::Standard);
+        let parameters = BiomeClassificationParameters {
+            hysteresis: 0.05,
+            ..Default::default()
+        };
+        let classifier = BiomeClassifier::from_parameters(parameters, &scale);
+        // Any standing water at all counts as river
+        let classify = |inputs: &CellInputs| {
+            if inputs.water_depth > 0.0 {
+                BiomeType::River
+            } else {
+                BiomeType::Grassland
+            }
+        };
+        let dry = CellInputs {
+            elevation: 0.4,
+            temperature: 10.0,
+            precipitation: 800.0,
+            water_depth: 0.0,
+            river: false,
+            major_river: false,
+            depression: false,
+        };
+
+        // A purely proportional margin vanishes at zero depth; the floor keeps the river
+        assert_eq!(
+            classifier.with_hysteresis(BiomeType::River, &dry, classify),
+            BiomeType::River
+        );
+        let no_margin = BiomeClassifier::from_parameters(Default::default(), &scale);
+        assert_eq!(
+            no_margin.with_hysteresis(BiomeType::River, &dry, classify),
+            BiomeType::Grassland
+        );
+    }
+
+    #[test]
+    fn whittaker_places_hot_wet_cell_in_rainforest() {
+        let scale = WorldScale::new(100.0, (50, 50), DetailLevel::Standard);
+        let parameters = BiomeClassificationParameters {
+            scheme: ClassificationScheme::Whittaker,
+            ..Default::default()
+        };
+        let classifier = BiomeClassifier::from_parameters(parameters, &scale);
+        assert_eq!(classifier.scheme(), ClassificationScheme::Whittaker);
+
+        // 27°C and 3000 mm/year on dry lowland
+        assert_eq!(
+            classifier.classify_biome(0.4, 27.0, 3000.0, 0.0),
+            BiomeType::RainForest
+        );
+        assert_eq!(
+            classifier.classify_biome(0.4, 27.0, 1200.0, 0.0),
+            BiomeType::Savanna
+        );
+        assert_eq!(
+            classifier.classify_biome(0.4, 27.0, 200.0, 0.0),
+            BiomeType::Desert
+        );
+        assert_eq!(
+            classifier.classify_biome(0.4, 10.0, 1500.0, 0.0),
+            BiomeType::TemperateForest
+        );
+        assert_eq!(
+            classifier.classify_biome(0.4, -2.0, 500.0, 0.0),
+            BiomeType::BorealForest
+        );
+        assert_eq!(
+            classifier.classify_biome(0.4, -8.0, 500.0, 0.0),
+            BiomeType::Tundra
+        );
+
+        // The default scheme is unchanged
+        let default_classifier = BiomeClassifier::new_for_scale(&scale);
+        assert_eq!(default_classifier.scheme(), ClassificationScheme::Threshold);
+    }
+
     #[test]
     fn scale_aware_parameters() {
         let base_params = BiomeClassificationParameters::default();
@@ -1703,6 +2562,55 @@ mod tests {
         assert!(custom_forest != forest_thresh);
     }
 
+    #[test]
+    fn favorable_climate_advances_one_stage_per_interval() {
+        let scale = WorldScale::new(10.0, (2, 1), DetailLevel::Standard);
+        let classifier = VegetationStateClassifier::new_for_scale(&scale).with_succession(
+            SuccessionParameters {
+                stage_interval_seconds: 100.0,
+                ..Default::default()
+            },
+        );
+
+        // Cell (0,0) is well watered, cell (1,0) is dry
+        let water_availability = WaterAvailability {
+            residence_time: vec![vec![0.0]; 2],
+            upstream_watershed_km2: vec![vec![0.0]; 2],
+            flow_intensity_ms: vec![vec![0.0]; 2],
+            availability_index: vec![vec![0.9], vec![0.05]],
+            width: 2,
+            height: 1,
+        };
+        let mut temperature = TemperatureLayer::new(2, 1);
+        temperature.temperature.fill(20.0);
+        let env = SuccessionEnvironment {
+            water_availability: &water_availability,
+            temperature: &temperature,
+        };
+
+        let mut states = PhysicsGrid::new(2, 1, VegetationState::Bare);
+        let mut progress = PhysicsGrid::new(2, 1, 0.0);
+        let stages = [
+            VegetationState::Grassland,
+            VegetationState::Shrubland,
+            VegetationState::Forest,
+        ];
+        for stage in stages {
+            classifier.step(&mut states, &mut progress, &env, 50.0);
+            assert_ne!(*states.get(0, 0), stage, "advanced before a full interval");
+            classifier.step(&mut states, &mut progress, &env, 50.0);
+            assert_eq!(*states.get(0, 0), stage);
+        }
+
+        // Forest is the climax stage
+        classifier.step(&mut states, &mut progress, &env, 500.0);
+        assert_eq!(*states.get(0, 0), VegetationState::Forest);
+
+        // Too dry for grassland, so the bare cell never regrows
+        assert_eq!(*states.get(1, 0), VegetationState::Bare);
+        assert_eq!(*progress.get(1, 0), 0.0);
+    }
+
     #[test]
     fn vegetation_state_map_generation() {
         let scale = WorldScale::new(100.0, (50, 50), DetailLevel::Standard);
@@ -1936,4 +2844,71 @@ mod tests {
             "Ice temperature should take priority over alpine elevation classification"
         );
     }
+
+    #[test]
+    fn incremental_update_reclassifies_only_changed_cell() {
+        use crate::engine::physics::climate::ClimateSystem;
+
+        let heightmap = HeightMap::from_nested(
+            (0..10)
+                .map(|y| {
+                    (0..12)
+                        .map(|x| 0.2 + 0.01 * x as f32 + 0.005 * y as f32)
+                        .collect()
+                })
+                .collect(),
+        );
+        let scale = WorldScale::new(12.0, (12, 10), DetailLevel::Standard);
+        let drainage_network = DrainageNetwork::from_heightmap(&heightmap, &scale);
+        let climate_system = ClimateSystem::new_for_scale(&scale);
+        let temperature_layer = climate_system.generate_temperature_layer(&heightmap.to_nested());
+        let mut water_layer = WaterLayer::new(12, 10);
+        let classifier = BiomeClassifier::new_for_scale(&scale);
+
+        let (mut biome_map, mut tracker) = classifier.generate_biome_map_tracked(
+            &heightmap,
+            &temperature_layer,
+            &water_layer,
+            &climate_system,
+            &drainage_network,
+            None,
+        );
+        let update =
+            |biome_map: &mut BiomeMap, tracker: &mut BiomeChangeTracker, water: &WaterLayer| {
+                classifier.update_biome_map(
+                    biome_map,
+                    tracker,
+                    &heightmap,
+                    &temperature_layer,
+                    water,
+                    &climate_system,
+                    &drainage_network,
+                    None,
+                )
+            };
+        assert_eq!(update(&mut biome_map, &mut tracker, &water_layer), 0);
+
+        // Flood the highest cell, which no channel drains through
+        let dry_biome = biome_map.get(11, 9);
+        water_layer.depth.set(
+            11,
+            9,
+            StateFloat::from_f32(classifier.parameters.ocean_depth_threshold * 2.0),
+        );
+        assert_eq!(update(&mut biome_map, &mut tracker, &water_layer), 1);
+        assert_eq!(biome_map.get(11, 9), BiomeType::Ocean);
+        assert_ne!(dry_biome, BiomeType::Ocean);
+
+        let full = classifier.generate_biome_map_with_precipitation(
+            &heightmap,
+            &temperature_layer,
+            &water_layer,
+            &climate_system,
+            &drainage_network,
+            None,
+        );
+        for (x, y, biome) in full.iter_coords() {
+            assert_eq!(biome_map.get(x, y), biome, "cell ({}, {})", x, y);
+        }
+    }
 }
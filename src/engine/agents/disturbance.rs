@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Fire disturbance model igniting dry, hot, fuel-rich cells and spreading to neighbors
+// ABOUTME: Burned cells reset to bare vegetation and carry a fading albedo scar for ecosystem feedback
+
+use super::biome::VegetationState;
+use crate::engine::core::physics_grid::PhysicsGrid;
+use crate::engine::physics::climate::TemperatureLayer;
+use crate::engine::physics::ecosystem_feedback::{BiomeMap, EcosystemFeedbackEffects};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+
+/// Fire ignition and spread parameters
+#[derive(Clone, Debug)]
+pub struct DisturbanceParameters {
+    /// Ignition chance per step for a cell at maximum flammability
+    pub base_ignition_probability: f32,
+    /// Temperature below which fuel is too cool to ignite (°C)
+    pub ignition_temperature: f32,
+    /// Temperature at which heat no longer limits flammability (°C)
+    pub peak_fire_temperature: f32,
+    /// Relative humidity (0-1) at or above which fuel is too moist to burn
+    pub moisture_of_extinction: f32,
+    /// Minimum flammability for fire to spread into a cell
+    pub spread_threshold: f32,
+    /// Chance a burning cell ignites each flammable neighbor
+    pub spread_probability: f32,
+    /// Steps a burn scar keeps its albedo change
+    pub scar_duration_steps: u32,
+    /// Albedo change of fresh char (negative = darker)
+    pub scar_albedo_change: f32,
+}
+
+impl Default for DisturbanceParameters {
+    fn default() -> Self {
+        Self {
+            base_ignition_probability: 0.001, // Rare lightning or human ignition
+            ignition_temperature: 15.0,       // Cool fuels rarely carry fire
+            peak_fire_temperature: 40.0,      // Extreme fire weather
+            moisture_of_extinction: 0.6,      // Humid air keeps fuels damp
+            spread_threshold: 0.3,            // Sparse or damp fuel stops the front
+            spread_probability: 0.8,          // Continuous fuel carries fire readily
+            scar_duration_steps: 50,          // Char weathers away over time
+            scar_albedo_change: -0.08,        // Fresh char darkens the surface
+        }
+    }
+}
+
+/// Environmental inputs driving ignition and spread
+#[derive(Clone, Copy, Debug)]
+pub struct DisturbanceEnvironment<'a> {
+    pub temperature: &'a TemperatureLayer,
+    /// Relative humidity (0-1) at each cell
+    pub relative_humidity: &'a PhysicsGrid<f32>,
+}
+
+/// Seeded fire disturbance system resetting burned vegetation to bare ground
+#[derive(Clone, Debug)]
+pub struct DisturbanceSystem {
+    pub parameters: DisturbanceParameters,
+    rng: StdRng,
+    /// Remaining scar steps per cell
+    scar_steps: PhysicsGrid<u32>,
+    /// Cells burned during the most recent step
+    last_burned: Vec<(usize, usize)>,
+    /// Cells that ignited during the most recent step, before spread
+    last_ignitions: Vec<(usize, usize)>,
+}
+
+impl DisturbanceSystem {
+    pub fn new(parameters: DisturbanceParameters, width: usize, height: usize, seed: u64) -> Self {
+        Self {
+            parameters,
+            rng: StdRng::seed_from_u64(seed),
+            scar_steps: PhysicsGrid::new(width, height, 0),
+            last_burned: Vec::new(),
+            last_ignitions: Vec::new(),
+        }
+    }
+
+    /// Fuel load contributed by a vegetation state (0-1)
+    pub fn fuel_load(state: VegetationState) -> f32 {
+        match state {
+            VegetationState::Bare => 0.0,
+            VegetationState::Grassland => 0.6,
+            VegetationState::Shrubland => 0.8,
+            VegetationState::Forest => 1.0,
+        }
+    }
+
+    /// Flammability (0-1) from fuel, heat and dryness
+    pub fn flammability(&self, state: VegetationState, temperature: f32, humidity: f32) -> f32 {
+        let heat_span = (self.parameters.peak_fire_temperature
+            - self.parameters.ignition_temperature)
+            .max(1e-3);
+        let heat =
+            ((temperature - self.parameters.ignition_temperature) / heat_span).clamp(0.0, 1.0);
+        let dryness =
+            (1.0 - humidity / self.parameters.moisture_of_extinction.max(1e-3)).clamp(0.0, 1.0);
+        Self::fuel_load(state) * heat * dryness
+    }
+
+    /// Run one disturbance step: age scars, ignite, spread and reset burned vegetation
+    /// Returns the number of cells burned this step
+    pub fn step(
+        &mut self,
+        vegetation: &mut PhysicsGrid<VegetationState>,
+        env: &DisturbanceEnvironment,
+    ) -> usize {
+        let width = vegetation.width().min(self.scar_steps.width());
+        let height = vegetation.height().min(self.scar_steps.height());
+
+        for steps in self.scar_steps.iter_mut() {
+            *steps = steps.saturating_sub(1);
+        }
+
+        let mut flammability = PhysicsGrid::new(width, height, 0.0);
+        for y in 0..height {
+            for x in 0..width {
+                let humidity =
+                    if x < env.relative_humidity.width() && y < env.relative_humidity.height() {
+                        *env.relative_humidity.get(x, y)
+                    } else {
+                        1.0
+                    };
+                let value = self.flammability(
+                    *vegetation.get(x, y),
+                    env.temperature.get_temperature(x, y),
+                    humidity,
+                );
+                flammability.set(x, y, value);
+            }
+        }
+
+        // Ignition: independent chance per cell scaled by flammability
+        let mut burning = PhysicsGrid::new(width, height, false);
+        let mut front = VecDeque::new();
+        for y in 0..height {
+            for x in 0..width {
+                let chance = self.parameters.base_ignition_probability * *flammability.get(x, y);
+                if chance > 0.0 && self.rng.r#gen::<f32>() < chance {
+                    burning.set(x, y, true);
+                    front.push_back((x, y));
+                }
+            }
+        }
+        self.last_ignitions = front.iter().copied().collect();
+
+        // Spread: breadth-first through sufficiently flammable neighbors
+        while let Some((x, y)) = front.pop_front() {
+            let neighbors = [
+                (x.wrapping_sub(1), y),
+                (x + 1, y),
+                (x, y.wrapping_sub(1)),
+                (x, y + 1),
+            ];
+            for (nx, ny) in neighbors {
+                if nx >= width || ny >= height || *burning.get(nx, ny) {
+                    continue;
+                }
+                if *flammability.get(nx, ny) >= self.parameters.spread_threshold
+                    && self.rng.r#gen::<f32>() < self.parameters.spread_probability
+                {
+                    burning.set(nx, ny, true);
+                    front.push_back((nx, ny));
+                }
+            }
+        }
+
+        self.last_burned.clear();
+        for (x, y, &burned) in burning.iter_coords() {
+            if burned {
+                vegetation.set(x, y, VegetationState::Bare);
+                self.scar_steps
+                    .set(x, y, self.parameters.scar_duration_steps);
+                self.last_burned.push((x, y));
+            }
+        }
+
+        self.last_burned.len()
+    }
+
+    /// Cells that ignited during the most recent step; every burned cell spread from one
+    pub fn last_ignitions(&self) -> &[(usize, usize)] {
+        &self.last_ignitions
+    }
+
+    /// Cells burned during the most recent step
+    pub fn last_burned(&self) -> &[(usize, usize)] {
+        &self.last_burned
+    }
+
+    /// Whether a cell carries an active burn scar
+    pub fn is_scarred(&self, x: usize, y: usize) -> bool {
+        x < self.scar_steps.width()
+            && y < self.scar_steps.height()
+            && *self.scar_steps.get(x, y) > 0
+    }
+
+    /// Albedo change from a fading burn scar (0.0 once weathered)
+    pub fn scar_albedo_change(&self, x: usize, y: usize) -> f32 {
+        if !self.is_scarred(x, y) || self.parameters.scar_duration_steps == 0 {
+            return 0.0;
+        }
+        let remaining =
+            *self.scar_steps.get(x, y) as f32 / self.parameters.scar_duration_steps as f32;
+        self.parameters.scar_albedo_change * remaining
+    }
+
+    /// Strip vegetation and biomass from cells burned in the most recent step
+    pub fn apply_to_biome_map(&self, biome_map: &mut BiomeMap) {
+        for &(x, y) in &self.last_burned {
+            biome_map.set_vegetation_state(x, y, VegetationState::Bare);
+            biome_map.set_biomass(x, y, 0.0);
+        }
+    }
+
+    /// Add burn scar albedo changes to ecosystem feedback effects
+    pub fn apply_to_effects(&self, effects: &mut EcosystemFeedbackEffects) {
+        for (x, column) in effects.albedo_modification.iter_mut().enumerate() {
+            for (y, albedo) in column.iter_mut().enumerate() {
+                *albedo += self.scar_albedo_change(x, y);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn burned_grid(seed: u64) -> (PhysicsGrid<VegetationState>, DisturbanceSystem) {
+        // Forested 6x6 patch in bare ground, hot and bone dry everywhere
+        let mut vegetation = PhysicsGrid::new(16, 16, VegetationState::Bare);
+        for y in 5..11 {
+            for x in 5..11 {
+                vegetation.set(x, y, VegetationState::Forest);
+            }
+        }
+        let mut temperature = TemperatureLayer::new(16, 16);
+        temperature.temperature.fill(40.0);
+        let humidity = PhysicsGrid::new(16, 16, 0.0);
+        let env = DisturbanceEnvironment {
+            temperature: &temperature,
+            relative_humidity: &humidity,
+        };
+
+        let mut system = DisturbanceSystem::new(
+            DisturbanceParameters {
+                base_ignition_probability: 0.05,
+                ..Default::default()
+            },
+            16,
+            16,
+            seed,
+        );
+        // Ignition takes a handful of steps; the cap turns a regression into a failure, not a hang
+        let ignited = (0..1000).any(|_| system.step(&mut vegetation, &env) > 0);
+        assert!(ignited, "dry forest patch never ignited");
+        assert!(system.is_scarred(system.last_burned()[0].0, system.last_burned()[0].1));
+        (vegetation, system)
+    }
+
+    #[test]
+    fn dry_hot_forest_patch_burns_contiguous_scar() {
+        let original = |x: usize, y: usize| (5..11).contains(&x) && (5..11).contains(&y);
+        let (vegetation, system) = burned_grid(42);
+
+        let scar = system.last_burned().to_vec();
+        let ignitions = system.last_ignitions();
+        assert!(!ignitions.is_empty());
+        assert!(scar.iter().all(|&(x, y)| original(x, y)));
+        assert!(
+            scar.len() > ignitions.len(),
+            "fire should spread beyond the ignition cells"
+        );
+
+        // Every scar cell is reachable from an ignition through 4-connected scar cells
+        let mut visited = ignitions.to_vec();
+        let mut index = 0;
+        while index < visited.len() {
+            let (x, y) = visited[index];
+            for &cell in &scar {
+                let adjacent = x.abs_diff(cell.0) + y.abs_diff(cell.1) == 1;
+                if adjacent && !visited.contains(&cell) {
+                    visited.push(cell);
+                }
+            }
+            index += 1;
+        }
+        assert_eq!(visited.len(), scar.len(), "burn scar should be contiguous");
+
+        // Same seed reproduces the same scar
+        let (repeat, _) = burned_grid(42);
+        assert_eq!(repeat.data(), vegetation.data());
+    }
+}
@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Habitat suitability scoring for agents from temperature, water availability and elevation
+// ABOUTME: Smooth tolerance curves give 1.0 inside preferred ranges and fall off toward 0.0 outside
+
+use crate::engine::sim::Simulation;
+
+/// Preferred environmental ranges and how quickly suitability drops outside them
+#[derive(Clone, Debug, PartialEq)]
+pub struct HabitatModel {
+    /// Preferred air temperature range (°C)
+    pub temp_range: (f32, f32),
+    /// Preferred water availability index (0 = parched, 1 = abundant)
+    pub water_pref: f32,
+    /// Preferred elevation range (heightmap units)
+    pub elevation_range: (f32, f32),
+    /// Distance outside the temperature range at which suitability falls to ~37% (°C)
+    pub temp_tolerance: f32,
+    /// Water availability difference at which suitability falls to ~37%
+    pub water_tolerance: f32,
+    /// Distance outside the elevation range at which suitability falls to ~37%
+    pub elevation_tolerance: f32,
+}
+
+impl Default for HabitatModel {
+    fn default() -> Self {
+        Self {
+            temp_range: (10.0, 25.0), // Temperate comfort band
+            water_pref: 0.5,          // Moderate water availability
+            elevation_range: (0.2, 0.6),
+            temp_tolerance: 5.0,
+            water_tolerance: 0.25,
+            elevation_tolerance: 0.1,
+        }
+    }
+}
+
+/// Gaussian falloff with distance outside [min, max]; 1.0 anywhere inside
+fn tolerance_curve(value: f32, (min, max): (f32, f32), tolerance: f32) -> f32 {
+    let distance = if value < min {
+        min - value
+    } else if value > max {
+        value - max
+    } else {
+        0.0
+    };
+    let scaled = distance / tolerance.max(f32::EPSILON);
+    (-scaled * scaled).exp()
+}
+
+impl HabitatModel {
+    /// Suitability (0-1) of a cell: the product of the temperature, water and elevation curves
+    pub fn suitability(&self, sim: &Simulation, x: usize, y: usize) -> f32 {
+        if x >= sim.get_width() || y >= sim.get_height() {
+            return 0.0;
+        }
+        let temperature = sim.get_temperature_layer().get_temperature(x, y);
+        let water = sim.get_water_availability(x, y).availability_index();
+        let elevation = sim.get_elevation(x, y);
+
+        tolerance_curve(temperature, self.temp_range, self.temp_tolerance)
+            * tolerance_curve(
+                water,
+                (self.water_pref, self.water_pref),
+                self.water_tolerance,
+            )
+            * tolerance_curve(elevation, self.elevation_range, self.elevation_tolerance)
+    }
+
+    /// Most suitable of the cell and its eight neighbors, for gradient-ascent movement
+    pub fn best_neighbor(&self, sim: &Simulation, x: usize, y: usize) -> (usize, usize) {
+        let mut best = ((x, y), self.suitability(sim, x, y));
+        for dy in -1i32..=1 {
+            for dx in -1i32..=1 {
+                let nx = x as i32 + dx;
+                let ny = y as i32 + dy;
+                if nx < 0 || ny < 0 {
+                    continue;
+                }
+                let (nx, ny) = (nx as usize, ny as usize);
+                let score = self.suitability(sim, nx, ny);
+                if score > best.1 {
+                    best = ((nx, ny), score);
+                }
+            }
+        }
+        best.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::core::heightmap::HeightMap;
+
+    #[test]
+    fn suitability_is_high_inside_and_low_outside_preferred_ranges() {
+        let terrain: Vec<Vec<f32>> = (0..8)
+            .map(|_| (0..8).map(|x| x as f32 / 8.0).collect())
+            .collect();
+        let sim = Simulation::new(HeightMap::from_nested(terrain));
+        let temperature = sim.get_temperature_layer().get_temperature(3, 4);
+        let water = sim.get_water_availability(3, 4).availability_index();
+        let elevation = sim.get_elevation(3, 4);
+
+        let preferred = HabitatModel {
+            temp_range: (temperature - 2.0, temperature + 2.0),
+            water_pref: water,
+            elevation_range: (elevation - 0.05, elevation + 0.05),
+            ..Default::default()
+        };
+        assert!(preferred.suitability(&sim, 3, 4) > 0.99);
+
+        let hostile = HabitatModel {
+            temp_range: (temperature + 40.0, temperature + 50.0),
+            water_pref: if water > 0.5 { 0.0 } else { 1.0 },
+            elevation_range: (elevation + 2.0, elevation + 3.0),
+            ..Default::default()
+        };
+        assert!(hostile.suitability(&sim, 3, 4) < 0.01);
+
+        // Elevation rises eastward, so a model preferring high ground climbs east
+        let highlands = HabitatModel {
+            elevation_range: (0.8, 1.0),
+            temp_range: (-100.0, 100.0),
+            water_tolerance: 10.0,
+            ..Default::default()
+        };
+        assert_eq!(highlands.best_neighbor(&sim, 3, 4).0, 4);
+    }
+}
@@ -6,11 +6,22 @@
 
 pub mod agents;
 pub mod biome;
+pub mod disturbance;
+pub mod habitat;
+pub mod water_agent;
 
 // Re-export key agent types
 
 // Re-export biome and vegetation classification systems for rendering integration
 pub use biome::{
-    BiomeClassificationParameters, BiomeClassifier, BiomeMap, BiomeType, VegetationState,
-    VegetationStateClassifier, VegetationStateParameters,
+    BiomeChangeTracker, BiomeClassificationParameters, BiomeClassifier, BiomeInputTolerance,
+    BiomeMap, BiomeType, SuccessionEnvironment, SuccessionParameters, SurfaceProperties,
+    VegetationState, VegetationStateClassifier, VegetationStateParameters,
 };
+
+// Re-export fire disturbance for dynamic biomes
+pub use disturbance::{DisturbanceEnvironment, DisturbanceParameters, DisturbanceSystem};
+
+// Re-export flow-following water agents and habitat scoring
+pub use habitat::HabitatModel;
+pub use water_agent::Agent;
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Mobile water agent (fish, boat, debris) advected by the sampled surface-water velocity
+// ABOUTME: Refuses to move into cells shallower than its draft and stops once beached on dry land
+
+use crate::engine::core::math::Vec2;
+use crate::engine::core::physics_grid::Float;
+use crate::engine::physics::flow_engine::VelocityField;
+use crate::engine::physics::water::WaterLayer;
+
+/// Default minimum water depth an agent can occupy (m)
+pub const DEFAULT_MIN_DEPTH: f32 = 0.01;
+
+/// Longest advection sub-step in cells, so fast agents cannot skip over dry cells
+const MAX_SUBSTEP_CELLS: f32 = 0.5;
+
+/// Agent carried by the current, positioned in grid coordinates (cell centers at integers)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Agent {
+    pub position: Vec2,
+    /// Shallowest water (m) the agent can enter
+    pub min_depth: f32,
+    beached: bool,
+}
+
+impl Agent {
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            min_depth: DEFAULT_MIN_DEPTH,
+            beached: false,
+        }
+    }
+
+    pub fn with_min_depth(mut self, min_depth: f32) -> Self {
+        self.min_depth = min_depth.max(0.0);
+        self
+    }
+
+    /// Whether the agent has been left on dry land and no longer moves
+    pub fn is_beached(&self) -> bool {
+        self.beached
+    }
+
+    /// Cell containing a grid position, or None outside the water layer
+    fn cell_at(water: &WaterLayer, position: Vec2) -> Option<(usize, usize)> {
+        let x = (position.x + 0.5).floor();
+        let y = (position.y + 0.5).floor();
+        if x < 0.0 || y < 0.0 || x as usize >= water.width() || y as usize >= water.height() {
+            None
+        } else {
+            Some((x as usize, y as usize))
+        }
+    }
+
+    fn is_navigable(&self, water: &WaterLayer, position: Vec2) -> bool {
+        Self::cell_at(water, position)
+            .is_some_and(|(x, y)| water.depth.get(x, y).to_f32() >= self.min_depth)
+    }
+
+    /// Advect the agent by the local water velocity (m/s) for `dt` seconds
+    ///
+    /// Movement stops at the last navigable position before a dry cell or the domain edge.
+    /// An agent whose own cell has dried out is beached and stays put from then on.
+    /// Returns the distance moved in cells.
+    pub fn step(&mut self, velocity_field: &VelocityField, water: &WaterLayer, dt: f32) -> f32 {
+        if self.beached {
+            return 0.0;
+        }
+        if !self.is_navigable(water, self.position) {
+            self.beached = true;
+            return 0.0;
+        }
+
+        let meters_per_cell = velocity_field.meters_per_pixel.max(f64::EPSILON) as f32;
+        let velocity = velocity_field.sample_velocity(self.position);
+        let displacement = velocity * (dt / meters_per_cell);
+        let distance = displacement.magnitude();
+        if distance <= 0.0 {
+            return 0.0;
+        }
+
+        let substeps = (distance / MAX_SUBSTEP_CELLS).ceil().max(1.0) as usize;
+        let increment = displacement * (1.0 / substeps as f32);
+        let start = self.position;
+        for _ in 0..substeps {
+            let next = self.position + increment;
+            if !self.is_navigable(water, next) {
+                break;
+            }
+            self.position = next;
+        }
+        (self.position - start).magnitude()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::core::scale::{DetailLevel, WorldScale};
+
+    #[test]
+    fn agent_drifts_with_flow_and_stops_at_dry_boundary() {
+        // 1 km cells; water in columns 0-11, dry land from column 12
+        let scale = WorldScale::new(20.0, (20, 5), DetailLevel::Standard);
+        let mut field = VelocityField::new(20, 5, &scale);
+        for x in 0..20 {
+            for y in 0..5 {
+                field.set_velocity(x, y, Vec2::new(1.0, 0.0));
+            }
+        }
+        let mut water = WaterLayer::new(20, 5);
+        for x in 0..12 {
+            for y in 0..5 {
+                water.depth.set(x, y, 0.5);
+            }
+        }
+
+        let mut agent = Agent::new(Vec2::new(2.0, 2.0));
+        // 1 m/s for 500 s moves half a cell east
+        let moved = agent.step(&field, &water, 500.0);
+        assert!((moved - 0.5).abs() < 1e-4);
+        assert!((agent.position.x - 2.5).abs() < 1e-4);
+        assert_eq!(agent.position.y, 2.0);
+
+        for _ in 0..100 {
+            agent.step(&field, &water, 1000.0);
+        }
+        assert!(agent.position.x > 10.0, "agent should drift east");
+        assert!(agent.position.x < 11.5, "agent must not enter dry cells");
+        assert!(!agent.is_beached());
+        assert_eq!(agent.step(&field, &water, 1000.0), 0.0);
+
+        // Draining the water under the agent leaves it beached
+        water.depth.set(11, 2, 0.0);
+        agent.step(&field, &water, 1000.0);
+        assert!(agent.is_beached());
+        water.depth.set(11, 2, 0.5);
+        assert_eq!(agent.step(&field, &water, 1000.0), 0.0);
+    }
+}
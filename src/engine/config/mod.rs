@@ -5,8 +5,104 @@
 // ABOUTME: Enables persistent analysis workflows, shareable templates, and version-controlled research setups
 
 use super::core::temporal_scaling::TemporalScalingConfig;
+use super::core::visualization_layer::VisualizationLayer;
 use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
 use std::collections::HashMap;
+use std::fmt;
+
+/// Configuration schema version written by this crate
+pub const CURRENT_CONFIG_VERSION: &str = "2.1";
+
+/// Upgrade step rewriting a raw YAML document from one schema version to the next
+struct ConfigMigration {
+    from: &'static str,
+    to: &'static str,
+    migrate: fn(&mut Value),
+}
+
+/// Registered migrations, applied in sequence until the document reaches the current version
+const MIGRATIONS: &[ConfigMigration] = &[
+    ConfigMigration {
+        from: "1.0",
+        to: "2.0",
+        migrate: |_| {}, // Same layout; 2.0 only relabelled the schema
+    },
+    ConfigMigration {
+        from: "2.0",
+        to: "2.1",
+        migrate: add_temporal_scaling_defaults,
+    },
+];
+
+/// Documents before 2.1 predate temporal scaling in the simulation defaults
+fn add_temporal_scaling_defaults(document: &mut Value) {
+    if let Some(defaults) = document.get_mut("defaults").and_then(Value::as_mapping_mut) {
+        let key = Value::from("temporal_scaling");
+        if !defaults.contains_key(&key) {
+            let temporal = serde_yaml::to_value(TemporalScalingConfig::default())
+                .expect("temporal scaling defaults serialize");
+            defaults.insert(key, temporal);
+        }
+    }
+}
+
+/// Errors from loading, saving or validating a workspace configuration
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Reading or writing the configuration file failed
+    Io(std::io::Error),
+    /// The YAML could not be parsed into a workspace configuration
+    Parse(serde_yaml::Error),
+    /// A layer name does not match any visualization layer
+    UnknownLayer(String),
+    /// One or more settings are out of range
+    ValidationFailed(Vec<String>),
+    /// The document's schema version has no migration path to the current version
+    UnsupportedVersion(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(error) => write!(f, "workspace config I/O error: {}", error),
+            ConfigError::Parse(error) => write!(f, "invalid workspace config YAML: {}", error),
+            ConfigError::UnknownLayer(layer) => {
+                write!(f, "unknown visualization layer '{}'", layer)
+            }
+            ConfigError::ValidationFailed(problems) => {
+                write!(f, "invalid workspace config: {}", problems.join("; "))
+            }
+            ConfigError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported workspace config version '{}' (this build reads up to {})",
+                version, CURRENT_CONFIG_VERSION
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(error) => Some(error),
+            ConfigError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(error: std::io::Error) -> Self {
+        ConfigError::Io(error)
+    }
+}
+
+impl From<serde_yaml::Error> for ConfigError {
+    fn from(error: serde_yaml::Error) -> Self {
+        ConfigError::Parse(error)
+    }
+}
 
 /// Complete scientific workspace configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -101,7 +197,7 @@ impl Default for WorkspaceConfig {
                 created: chrono::Utc::now().to_rfc3339(),
                 modified: None,
                 description: Some("Default configuration for scientific analysis".to_string()),
-                version: "1.0".to_string(),
+                version: CURRENT_CONFIG_VERSION.to_string(),
             },
             defaults: SimulationDefaults {
                 seed: None,
@@ -197,20 +293,88 @@ impl WorkspaceConfig {
         config
     }
 
-    /// Load workspace configuration from YAML file
-    pub fn load_from_file(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Load, migrate and validate workspace configuration from YAML file
+    pub fn load_from_file(path: &str) -> Result<Self, ConfigError> {
         let content = std::fs::read_to_string(path)?;
-        let config: WorkspaceConfig = serde_yaml::from_str(&content)?;
+        let config = Self::from_yaml(&content)?;
+        config.validate()?;
         Ok(config)
     }
 
+    /// Parse a YAML document, first upgrading older schema versions to the current one
+    /// Documents without a version are treated as 1.0
+    pub fn from_yaml(content: &str) -> Result<Self, ConfigError> {
+        let mut document: Value = serde_yaml::from_str(content)?;
+        let mut version = document
+            .get("metadata")
+            .and_then(|metadata| metadata.get("version"))
+            .and_then(Value::as_str)
+            .unwrap_or("1.0")
+            .to_string();
+
+        while version != CURRENT_CONFIG_VERSION {
+            let migration = MIGRATIONS
+                .iter()
+                .find(|migration| migration.from == version)
+                .ok_or_else(|| ConfigError::UnsupportedVersion(version.clone()))?;
+            (migration.migrate)(&mut document);
+            version = migration.to.to_string();
+        }
+
+        if let Some(metadata) = document.get_mut("metadata").and_then(Value::as_mapping_mut) {
+            metadata.insert(Value::from("version"), Value::from(CURRENT_CONFIG_VERSION));
+        }
+        Ok(serde_yaml::from_value(document)?)
+    }
+
     /// Save workspace configuration to YAML file
-    pub fn save_to_file(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    pub fn save_to_file(&self, path: &str) -> Result<(), ConfigError> {
         let yaml = serde_yaml::to_string(self)?;
         std::fs::write(path, yaml)?;
         Ok(())
     }
 
+    /// Check layer names against the known visualization layers and dimensions for sanity
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let setting_layers = self
+            .layout
+            .layer_settings
+            .iter()
+            .flat_map(|settings| settings.keys());
+        for layer in self.layout.layers.iter().chain(setting_layers) {
+            if VisualizationLayer::from_str(layer).is_none() {
+                return Err(ConfigError::UnknownLayer(layer.clone()));
+            }
+        }
+
+        let mut problems = Vec::new();
+        let (width, height) = self.defaults.dimensions;
+        if width == 0 || height == 0 {
+            problems.push(format!("dimensions {}x{} must be non-zero", width, height));
+        }
+        if !(self.defaults.scale_km.is_finite() && self.defaults.scale_km > 0.0) {
+            problems.push(format!(
+                "scale_km {} must be positive",
+                self.defaults.scale_km
+            ));
+        }
+        if self.defaults.interval == 0 {
+            problems.push("interval must be at least 1 tick".to_string());
+        }
+        if self.layout.buffer_size == 0 {
+            problems.push("buffer_size must be at least 1 frame".to_string());
+        }
+        if self.layout.subsample_rate == 0 {
+            problems.push("subsample_rate must be at least 1".to_string());
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::ValidationFailed(problems))
+        }
+    }
+
     /// Update the modified timestamp
     pub fn mark_modified(&mut self) {
         self.metadata.modified = Some(chrono::Utc::now().to_rfc3339());
@@ -238,3 +402,94 @@ impl ToTitleCase for str {
             .join(" ")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_layer_is_reported_by_name() {
+        let mut config = WorkspaceConfig::from_preset("storm-tracking", "tester");
+        config.layout.layers.push("plasma".to_string());
+        let path = std::env::temp_dir().join(format!(
+            "kosmarium_unknown_layer_config_{}.yaml",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+        config.save_to_file(path).unwrap();
+
+        let result = WorkspaceConfig::load_from_file(path);
+        std::fs::remove_file(path).ok();
+        match result {
+            Err(ConfigError::UnknownLayer(layer)) => assert_eq!(layer, "plasma"),
+            other => panic!("expected UnknownLayer, got {:?}", other),
+        }
+
+        // Missing files and malformed YAML are distinguishable
+        assert!(matches!(
+            WorkspaceConfig::load_from_file("/nonexistent/kosmarium.yaml"),
+            Err(ConfigError::Io(_))
+        ));
+        let mut broken = WorkspaceConfig::default();
+        broken.defaults.dimensions = (0, 120);
+        assert!(matches!(
+            broken.validate(),
+            Err(ConfigError::ValidationFailed(problems)) if problems.len() == 1
+        ));
+    }
+
+    #[test]
+    fn version_1_0_document_migrates_to_current() {
+        let document = r#"
+metadata:
+  name: Legacy Workspace
+  author: tester
+  created: 2025-01-01T00:00:00Z
+  version: "1.0"
+defaults:
+  seed: 42
+  scale_km: 200.0
+  roughness: 0.7
+  persistence: 0.6
+  dimensions: [240, 120]
+  interval: 10
+layout:
+  buffer_size: 5
+  layers: [elevation, water]
+  zoom: continental
+  frame_size: [0, 0]
+  show_timestamps: true
+  highlight_changes: false
+  subsample_rate: 1
+"#;
+        // The unmigrated document lacks temporal scaling and would not deserialize
+        assert!(serde_yaml::from_str::<WorkspaceConfig>(document).is_err());
+
+        let config = WorkspaceConfig::from_yaml(document).unwrap();
+        assert_eq!(config.metadata.version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.defaults.seed, Some(42));
+        let expected = TemporalScalingConfig::default();
+        assert_eq!(config.defaults.temporal_scaling.mode, expected.mode);
+        assert_eq!(
+            config.defaults.temporal_scaling.custom_scaling_factor,
+            expected.custom_scaling_factor
+        );
+
+        let future = document.replace("version: \"1.0\"", "version: \"9.0\"");
+        match WorkspaceConfig::from_yaml(&future) {
+            Err(ConfigError::UnsupportedVersion(version)) => assert_eq!(version, "9.0"),
+            other => panic!("expected UnsupportedVersion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn version_2_0_workspace_file_loads() {
+        let document = include_str!("../../../shared-climate-team.yaml");
+        assert!(document.contains("version: '2.0'"));
+
+        let config = WorkspaceConfig::from_yaml(document).unwrap();
+        assert_eq!(config.metadata.version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.defaults.seed, Some(12345));
+        assert!(config.validate().is_ok());
+    }
+}
@@ -5,6 +5,8 @@
 // ABOUTME: Provides unit conversion, physical validation, and dimensional correctness checking
 
 use super::scale::WorldScale;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Sub};
 
 /// Physical units for dimensional analysis
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -44,6 +46,112 @@ pub enum PhysicalUnit {
     Dimensionless,
 }
 
+/// Exponents of the base dimensions (length, time, temperature) making up a unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dimensions {
+    pub length: i8,
+    pub time: i8,
+    pub temperature: i8,
+}
+
+impl Dimensions {
+    pub const fn new(length: i8, time: i8, temperature: i8) -> Self {
+        Self {
+            length,
+            time,
+            temperature,
+        }
+    }
+
+    fn combine(self, other: Dimensions, sign: i8) -> Dimensions {
+        Dimensions::new(
+            self.length + sign * other.length,
+            self.time + sign * other.time,
+            self.temperature + sign * other.temperature,
+        )
+    }
+}
+
+impl PhysicalUnit {
+    /// Base dimensions of the unit
+    pub fn dimensions(&self) -> Dimensions {
+        use PhysicalUnit::*;
+        match self {
+            Meters | Millimeters | Kilometers => Dimensions::new(1, 0, 0),
+            Seconds | Hours => Dimensions::new(0, 1, 0),
+            MetersPerSecond | KilometersPerHour | MillimetersPerHour => Dimensions::new(1, -1, 0),
+            CubicMeters => Dimensions::new(3, 0, 0),
+            CubicMetersPerSecond => Dimensions::new(3, -1, 0),
+            SquareMeters => Dimensions::new(2, 0, 0),
+            Celsius | Kelvin => Dimensions::new(0, 0, 1),
+            CelsiusPerMeter | CelsiusPerKilometer => Dimensions::new(-1, 0, 1),
+            Dimensionless => Dimensions::new(0, 0, 0),
+        }
+    }
+
+    /// Factor taking a value in this unit to the SI unit of the same dimensions
+    /// Temperatures scale as differences, so Celsius and Kelvin both have factor 1
+    fn si_factor(&self) -> f64 {
+        use PhysicalUnit::*;
+        match self {
+            Millimeters => 1e-3,
+            Kilometers => 1e3,
+            Hours => 3600.0,
+            KilometersPerHour => 1.0 / 3.6,
+            MillimetersPerHour => 1e-3 / 3600.0,
+            CelsiusPerKilometer => 1e-3,
+            _ => 1.0,
+        }
+    }
+
+    /// SI unit with the given dimensions, if the unit set has one
+    pub fn si_unit_for(dimensions: Dimensions) -> Option<PhysicalUnit> {
+        use PhysicalUnit::*;
+        [
+            Dimensionless,
+            Meters,
+            Seconds,
+            MetersPerSecond,
+            SquareMeters,
+            CubicMeters,
+            CubicMetersPerSecond,
+            Celsius,
+            CelsiusPerMeter,
+        ]
+        .into_iter()
+        .find(|unit| unit.dimensions() == dimensions)
+    }
+}
+
+/// Arithmetic between quantities whose units cannot be combined
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnitError {
+    /// Addition or subtraction of quantities with different dimensions
+    Mismatch {
+        left: PhysicalUnit,
+        right: PhysicalUnit,
+    },
+    /// A product or quotient whose dimensions have no unit in `PhysicalUnit`
+    Unrepresentable(Dimensions),
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnitError::Mismatch { left, right } => {
+                write!(f, "cannot combine {:?} with {:?}", left, right)
+            }
+            UnitError::Unrepresentable(dimensions) => write!(
+                f,
+                "no unit for dimensions L^{} T^{} K^{}",
+                dimensions.length, dimensions.time, dimensions.temperature
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnitError {}
+
 /// A physical quantity with both value and units
 #[derive(Clone, Copy, Debug)]
 pub struct PhysicalQuantity {
@@ -137,6 +245,110 @@ impl PhysicalQuantity {
             (CelsiusPerMeter, CelsiusPerKilometer) | (CelsiusPerKilometer, CelsiusPerMeter)
         )
     }
+
+    /// Right-hand side in this quantity's unit, or a mismatch error
+    fn aligned(&self, rhs: PhysicalQuantity) -> Result<f64, UnitError> {
+        if self.is_compatible_with(rhs.unit) {
+            Ok(rhs.convert_to(self.unit).value)
+        } else {
+            Err(UnitError::Mismatch {
+                left: self.unit,
+                right: rhs.unit,
+            })
+        }
+    }
+
+    /// Sum in this quantity's unit; compatible units are converted first
+    pub fn checked_add(self, rhs: PhysicalQuantity) -> Result<PhysicalQuantity, UnitError> {
+        Ok(PhysicalQuantity::new(
+            self.value + self.aligned(rhs)?,
+            self.unit,
+        ))
+    }
+
+    /// Difference in this quantity's unit; compatible units are converted first
+    pub fn checked_sub(self, rhs: PhysicalQuantity) -> Result<PhysicalQuantity, UnitError> {
+        Ok(PhysicalQuantity::new(
+            self.value - self.aligned(rhs)?,
+            self.unit,
+        ))
+    }
+
+    /// Product with combined dimensions, expressed in SI units
+    /// Scaling by a dimensionless quantity keeps the original unit
+    pub fn checked_mul(self, rhs: PhysicalQuantity) -> Result<PhysicalQuantity, UnitError> {
+        if rhs.unit == PhysicalUnit::Dimensionless {
+            return Ok(PhysicalQuantity::new(self.value * rhs.value, self.unit));
+        }
+        if self.unit == PhysicalUnit::Dimensionless {
+            return Ok(PhysicalQuantity::new(self.value * rhs.value, rhs.unit));
+        }
+        let dimensions = self.unit.dimensions().combine(rhs.unit.dimensions(), 1);
+        let unit =
+            PhysicalUnit::si_unit_for(dimensions).ok_or(UnitError::Unrepresentable(dimensions))?;
+        Ok(PhysicalQuantity::new(
+            self.value * self.unit.si_factor() * rhs.value * rhs.unit.si_factor(),
+            unit,
+        ))
+    }
+
+    /// Quotient with combined dimensions, expressed in SI units
+    /// Dividing by a dimensionless quantity keeps the original unit
+    pub fn checked_div(self, rhs: PhysicalQuantity) -> Result<PhysicalQuantity, UnitError> {
+        if rhs.unit == PhysicalUnit::Dimensionless {
+            return Ok(PhysicalQuantity::new(self.value / rhs.value, self.unit));
+        }
+        if self.unit == rhs.unit {
+            return Ok(PhysicalQuantity::new(
+                self.value / rhs.value,
+                PhysicalUnit::Dimensionless,
+            ));
+        }
+        let dimensions = self.unit.dimensions().combine(rhs.unit.dimensions(), -1);
+        let unit =
+            PhysicalUnit::si_unit_for(dimensions).ok_or(UnitError::Unrepresentable(dimensions))?;
+        Ok(PhysicalQuantity::new(
+            (self.value * self.unit.si_factor()) / (rhs.value * rhs.unit.si_factor()),
+            unit,
+        ))
+    }
+}
+
+/// Operators panic on unit errors; use the `checked_*` methods to handle them
+impl Add for PhysicalQuantity {
+    type Output = PhysicalQuantity;
+
+    fn add(self, rhs: PhysicalQuantity) -> PhysicalQuantity {
+        self.checked_add(rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl Sub for PhysicalQuantity {
+    type Output = PhysicalQuantity;
+
+    fn sub(self, rhs: PhysicalQuantity) -> PhysicalQuantity {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl Mul for PhysicalQuantity {
+    type Output = PhysicalQuantity;
+
+    fn mul(self, rhs: PhysicalQuantity) -> PhysicalQuantity {
+        self.checked_mul(rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl Div for PhysicalQuantity {
+    type Output = PhysicalQuantity;
+
+    fn div(self, rhs: PhysicalQuantity) -> PhysicalQuantity {
+        self.checked_div(rhs)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
 }
 
 /// Physical parameters for water flow with proper dimensional analysis
@@ -572,6 +784,34 @@ mod tests {
         assert!((time_s.value - 3600.0).abs() < 0.001);
     }
 
+    #[test]
+    fn quantity_arithmetic_checks_units() {
+        let a = PhysicalQuantity::new(2.0, PhysicalUnit::Meters);
+        let b = PhysicalQuantity::new(500.0, PhysicalUnit::Millimeters);
+        let sum = a + PhysicalQuantity::new(3.0, PhysicalUnit::Meters);
+        assert_eq!(sum.unit, PhysicalUnit::Meters);
+        assert!((sum.value - 5.0).abs() < 1e-12);
+        assert!(((a - b).value - 1.5).abs() < 1e-12);
+
+        let time = PhysicalQuantity::new(4.0, PhysicalUnit::Seconds);
+        assert_eq!(
+            a.checked_add(time).unwrap_err(),
+            UnitError::Mismatch {
+                left: PhysicalUnit::Meters,
+                right: PhysicalUnit::Seconds,
+            }
+        );
+        assert!(std::panic::catch_unwind(|| a + time).is_err());
+
+        let speed = a / time;
+        assert_eq!(speed.unit, PhysicalUnit::MetersPerSecond);
+        assert!((speed.value - 0.5).abs() < 1e-12);
+        let area = a * PhysicalQuantity::new(1.0, PhysicalUnit::Kilometers);
+        assert_eq!(area.unit, PhysicalUnit::SquareMeters);
+        assert!((area.value - 2000.0).abs() < 1e-9);
+        assert_eq!((a / b).unit, PhysicalUnit::Dimensionless);
+    }
+
     #[test]
     fn cfl_validation() {
         let params = DimensionalWaterFlowParameters::new(
@@ -4,6 +4,87 @@
 // ABOUTME: High-performance 2D terrain storage with flat memory layout for cache efficiency
 // ABOUTME: Replaces Vec<Vec<f32>> pattern with contiguous Vec<f32> storage and fast indexing functions
 
+use super::physics_grid::{Float, PhysicsGrid};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Metres of relief per normalized heightmap unit (heights are stored in km)
+pub const METERS_PER_ELEVATION_UNIT: f32 = 1000.0;
+
+/// Heightmap elevation below which a cell counts as ocean
+pub const SEA_LEVEL_ELEVATION: f32 = 0.0;
+
+/// Summary statistics of a terrain's elevation distribution
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TerrainStats {
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    /// Population standard deviation of elevation
+    pub stddev: f32,
+    /// Difference between highest and lowest elevation
+    pub relief: f32,
+}
+
+/// How `HeightMap::fill_pits` removes closed depressions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PitMethod {
+    /// Raise depressions to their spill level (Priority-Flood with a minimal outward gradient)
+    PriorityFlood,
+    /// Carve a descending channel from each pit to the nearest lower cell or map edge
+    Breach,
+}
+
+/// Grids passed to a per-cell heightmap operation must match the heightmap's size
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    /// Heightmap size (width, height)
+    pub expected: (usize, usize),
+    /// Size of the grid that did not match
+    pub found: (usize, usize),
+}
+
+impl std::fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "grid is {}x{} but the heightmap is {}x{}",
+            self.found.0, self.found.1, self.expected.0, self.expected.1
+        )
+    }
+}
+
+impl std::error::Error for DimensionMismatch {}
+
+/// Smallest per-cell drop along a breach channel (heightmap units)
+const BREACH_MIN_DROP: f32 = 1e-6;
+
+/// Min-heap entry ordering cells by elevation, lowest first
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LowestFirst {
+    elevation: f32,
+    index: usize,
+}
+
+impl Eq for LowestFirst {}
+
+impl Ord for LowestFirst {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so BinaryHeap pops the lowest elevation
+        other
+            .elevation
+            .total_cmp(&self.elevation)
+            .then_with(|| other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for LowestFirst {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 /// High-performance 2D heightmap using flat memory layout
 ///
 /// This replaces the cache-unfriendly Vec<Vec<f32>> pattern throughout the codebase
@@ -14,25 +95,29 @@
 /// - Reduced heap fragmentation from eliminating nested allocations
 /// - SIMD-friendly memory layout for vectorized operations
 /// - Better memory locality for typical access patterns
-#[derive(Clone, Debug)]
-pub struct HeightMap {
-    data: Vec<f32>,
+///
+/// The cell type defaults to f32; state layers that accumulate many small updates
+/// (water depth) store `HeightMap<StateFloat>` so the `f64-state` feature can widen them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HeightMap<T = f32> {
+    data: Vec<T>,
     width: usize,
     height: usize,
 }
 
-impl HeightMap {
-    /// Create a new heightmap with the given dimensions and default value
-    pub fn new(width: usize, height: usize, default: f32) -> Self {
+// Storage and access shared by every cell precision
+impl<T: Float> HeightMap<T> {
+    /// Create a heightmap of the given dimensions with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
         Self {
-            data: vec![default; width * height],
+            data: vec![value; width * height],
             width,
             height,
         }
     }
 
-    /// Create from existing Vec<Vec<f32>> data for compatibility
-    pub fn from_nested(nested: Vec<Vec<f32>>) -> Self {
+    /// Create from existing Vec<Vec<T>> data for compatibility
+    pub fn from_nested(nested: Vec<Vec<T>>) -> Self {
         let height = nested.len();
         let width = if height > 0 { nested[0].len() } else { 0 };
         let data = nested.into_iter().flatten().collect();
@@ -43,17 +128,9 @@ impl HeightMap {
         }
     }
 
-    /// Convert to Vec<Vec<f32>> format for compatibility with legacy code
-    pub fn to_nested(&self) -> Vec<Vec<f32>> {
-        self.data
-            .chunks(self.width)
-            .map(|chunk| chunk.to_vec())
-            .collect()
-    }
-
     /// Get value at (x, y) coordinate with bounds checking in debug builds
     #[inline]
-    pub fn get(&self, x: usize, y: usize) -> f32 {
+    pub fn get(&self, x: usize, y: usize) -> T {
         debug_assert!(
             x < self.width && y < self.height,
             "HeightMap index out of bounds: ({}, {}) for {}x{}",
@@ -67,7 +144,7 @@ impl HeightMap {
 
     /// Set value at (x, y) coordinate with bounds checking in debug builds
     #[inline]
-    pub fn set(&mut self, x: usize, y: usize, value: f32) {
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
         debug_assert!(
             x < self.width && y < self.height,
             "HeightMap index out of bounds: ({}, {}) for {}x{}",
@@ -83,7 +160,7 @@ impl HeightMap {
 
     /// Get mutable reference at (x, y) coordinate with bounds checking in debug builds
     #[inline]
-    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut f32 {
+    pub fn get_mut(&mut self, x: usize, y: usize) -> &mut T {
         debug_assert!(
             x < self.width && y < self.height,
             "HeightMap index out of bounds: ({}, {}) for {}x{}",
@@ -121,36 +198,61 @@ impl HeightMap {
 
     /// Get raw data slice for SIMD operations
     #[inline]
-    pub fn data(&self) -> &[f32] {
+    pub fn data(&self) -> &[T] {
         &self.data
     }
 
     /// Get mutable raw data slice for SIMD operations
     #[inline]
-    pub fn data_mut(&mut self) -> &mut [f32] {
+    pub fn data_mut(&mut self) -> &mut [T] {
         &mut self.data
     }
 
     /// Iterator over all values
-    pub fn iter(&self) -> impl Iterator<Item = f32> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
         self.data.iter().copied()
     }
 
     /// Mutable iterator over all values
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
         self.data.iter_mut()
     }
 
     /// Iterator over (x, y, value) tuples
-    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize, f32)> + '_ {
+    pub fn iter_coords(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
         (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y, self.get(x, y))))
     }
 
     /// Fill entire heightmap with a value
-    pub fn fill(&mut self, value: f32) {
+    pub fn fill(&mut self, value: T) {
         self.data.fill(value);
     }
 
+    /// Copy contents from another heightmap (same dimensions required)
+    pub fn copy_from(&mut self, other: &HeightMap<T>) {
+        debug_assert_eq!(
+            self.data.len(),
+            other.data.len(),
+            "HeightMaps must have same dimensions for copy_from"
+        );
+        self.data.copy_from_slice(&other.data);
+    }
+}
+
+impl HeightMap {
+    /// Create a new heightmap with the given dimensions and default value
+    pub fn new(width: usize, height: usize, default: f32) -> Self {
+        Self::filled(width, height, default)
+    }
+
+    /// Convert to Vec<Vec<f32>> format for compatibility with legacy code
+    pub fn to_nested(&self) -> Vec<Vec<f32>> {
+        self.data
+            .chunks(self.width)
+            .map(|chunk| chunk.to_vec())
+            .collect()
+    }
+
     /// Apply function to all values in-place
     pub fn map_in_place<F>(&mut self, f: F)
     where
@@ -161,16 +263,6 @@ impl HeightMap {
         }
     }
 
-    /// Copy contents from another heightmap (same dimensions required)
-    pub fn copy_from(&mut self, other: &HeightMap) {
-        debug_assert_eq!(
-            self.data.len(),
-            other.data.len(),
-            "HeightMaps must have same dimensions for copy_from"
-        );
-        self.data.copy_from_slice(&other.data);
-    }
-
     /// Create new heightmap by applying function to all values
     pub fn map<F>(&self, f: F) -> Self
     where
@@ -206,11 +298,381 @@ impl HeightMap {
             }
         }
     }
+
+    /// Error unless a grid of `size` matches this heightmap
+    fn check_dimensions(&self, size: (usize, usize)) -> Result<(), DimensionMismatch> {
+        if size == (self.width, self.height) {
+            Ok(())
+        } else {
+            Err(DimensionMismatch {
+                expected: (self.width, self.height),
+                found: size,
+            })
+        }
+    }
+
+    /// Per-cell weighted average: weight 0 keeps this heightmap, 1 takes `other`
+    ///
+    /// Weights are clamped to 0-1, so a mask grid can cut a region from one map into another.
+    pub fn blend(
+        &self,
+        other: &HeightMap,
+        weight: &PhysicsGrid<f32>,
+    ) -> Result<HeightMap, DimensionMismatch> {
+        self.check_dimensions((other.width, other.height))?;
+        self.check_dimensions((weight.width(), weight.height()))?;
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .zip(weight.data())
+            .map(|((&base, &overlay), &w)| {
+                let w = w.clamp(0.0, 1.0);
+                base * (1.0 - w) + overlay * w
+            })
+            .collect();
+        Ok(Self {
+            data,
+            width: self.width,
+            height: self.height,
+        })
+    }
+
+    /// Add `amplitude` times a detail map centered on 0.5, as the terrain generators produce
+    ///
+    /// A detail value of 0.5 leaves the cell unchanged, so mean elevation is roughly preserved.
+    pub fn add_detail(
+        &mut self,
+        detail: &HeightMap,
+        amplitude: f32,
+    ) -> Result<(), DimensionMismatch> {
+        self.check_dimensions((detail.width, detail.height))?;
+        for (value, &offset) in self.data.iter_mut().zip(&detail.data) {
+            *value += (offset - 0.5) * amplitude;
+        }
+        Ok(())
+    }
+
+    /// Elevation statistics over all cells (all zero for an empty heightmap)
+    pub fn stats(&self) -> TerrainStats {
+        if self.data.is_empty() {
+            return TerrainStats {
+                min: 0.0,
+                max: 0.0,
+                mean: 0.0,
+                stddev: 0.0,
+                relief: 0.0,
+            };
+        }
+
+        let min = self.min();
+        let max = self.max();
+        let count = self.data.len() as f64;
+        let mean = self.data.iter().map(|&v| v as f64).sum::<f64>() / count;
+        let variance = self
+            .data
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        TerrainStats {
+            min,
+            max,
+            mean: mean as f32,
+            stddev: variance.sqrt() as f32,
+            relief: max - min,
+        }
+    }
+
+    /// Slope angle in degrees at every cell from Horn's 3x3 gradient
+    ///
+    /// Edge cells reuse their nearest interior neighbors (edge replication).
+    pub fn slope_field(&self, meters_per_pixel: f32) -> PhysicsGrid<f32> {
+        let mut slope = PhysicsGrid::new(self.width, self.height, 0.0);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (dz_dx, dz_dy) = self.horn_gradient(x, y, meters_per_pixel);
+                slope.set(x, y, dz_dx.hypot(dz_dy).atan().to_degrees());
+            }
+        }
+        slope
+    }
+
+    /// Compass direction of steepest descent in degrees (0 = north/up, 90 = east)
+    ///
+    /// Flat cells have no downslope direction and are marked -1.0.
+    pub fn aspect_field(&self) -> PhysicsGrid<f32> {
+        let mut aspect = PhysicsGrid::new(self.width, self.height, -1.0);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (dz_dx, dz_dy) = self.horn_gradient(x, y, 1.0);
+                if dz_dx.abs() <= f32::EPSILON && dz_dy.abs() <= f32::EPSILON {
+                    continue;
+                }
+                // Descent runs against the gradient; north is toward row 0
+                let east = -dz_dx;
+                let north = dz_dy;
+                aspect.set(x, y, east.atan2(north).to_degrees().rem_euclid(360.0));
+            }
+        }
+        aspect
+    }
+
+    /// Terrain ruggedness index: mean absolute elevation difference to the 8 neighbors
+    ///
+    /// Edge and corner cells average over the neighbors inside the map.
+    pub fn terrain_ruggedness_index(&self) -> PhysicsGrid<f32> {
+        let mut ruggedness = PhysicsGrid::new(self.width, self.height, 0.0);
+        for (index, &center) in self.data.iter().enumerate() {
+            let (total, count) =
+                self.neighbor_indices(index)
+                    .fold((0.0, 0usize), |(total, count), neighbor| {
+                        (total + (self.data[neighbor] - center).abs(), count + 1)
+                    });
+            if count > 0 {
+                ruggedness.set(index % self.width, index / self.width, total / count as f32);
+            }
+        }
+        ruggedness
+    }
+
+    /// Horn's weighted 3x3 finite-difference gradient (dz/dx, dz/dy), y increasing southward
+    fn horn_gradient(&self, x: usize, y: usize, spacing: f32) -> (f32, f32) {
+        let x0 = x.saturating_sub(1);
+        let x1 = (x + 1).min(self.width - 1);
+        let y0 = y.saturating_sub(1);
+        let y1 = (y + 1).min(self.height - 1);
+
+        let (a, b, c) = (self.get(x0, y0), self.get(x, y0), self.get(x1, y0));
+        let (d, f) = (self.get(x0, y), self.get(x1, y));
+        let (g, h, i) = (self.get(x0, y1), self.get(x, y1), self.get(x1, y1));
+
+        // Use the actual stencil span so replicated edges keep the true gradient
+        let span_x = (x1 - x0).max(1) as f32 * spacing;
+        let span_y = (y1 - y0).max(1) as f32 * spacing;
+        let dz_dx = ((c + 2.0 * f + i) - (a + 2.0 * d + g)) / (4.0 * span_x);
+        let dz_dy = ((g + 2.0 * h + i) - (a + 2.0 * b + c)) / (4.0 * span_y);
+        (dz_dx, dz_dy)
+    }
+
+    /// Hypsometric curve as (normalized elevation, fraction of area at or above it) pairs
+    ///
+    /// Elevation thresholds are spaced evenly from 0 (lowest cell) to 1 (highest cell),
+    /// giving bins + 1 points. A flat heightmap puts all of its area at elevation 0.
+    pub fn hypsometry(&self, bins: usize) -> Vec<(f32, f32)> {
+        if self.data.is_empty() {
+            return Vec::new();
+        }
+
+        let bins = bins.max(1);
+        let min_val = self.min();
+        let range = self.max() - min_val;
+        let mut normalized: Vec<f32> = if range > f32::EPSILON {
+            self.data.iter().map(|&v| (v - min_val) / range).collect()
+        } else {
+            vec![0.0; self.data.len()]
+        };
+        normalized.sort_by(f32::total_cmp);
+
+        let count = normalized.len() as f32;
+        (0..=bins)
+            .map(|i| {
+                let threshold = i as f32 / bins as f32;
+                let below = normalized.partition_point(|&v| v < threshold);
+                (threshold, (normalized.len() - below) as f32 / count)
+            })
+            .collect()
+    }
+
+    /// Indices of the up-to-eight neighbors of a flat index
+    fn neighbor_indices(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let (x, y) = ((index % self.width) as i64, (index / self.width) as i64);
+        (-1i64..=1)
+            .flat_map(move |dy| (-1i64..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |&(nx, ny)| {
+                (nx, ny) != (x, y)
+                    && nx >= 0
+                    && ny >= 0
+                    && nx < self.width as i64
+                    && ny < self.height as i64
+            })
+            .map(|(nx, ny)| ny as usize * self.width + nx as usize)
+    }
+
+    fn is_edge_index(&self, index: usize) -> bool {
+        let (x, y) = (index % self.width, index / self.width);
+        x == 0 || y == 0 || x + 1 == self.width || y + 1 == self.height
+    }
+
+    /// Remove every closed depression so all cells drain to the map edge
+    /// Returns the number of cells whose elevation changed
+    pub fn fill_pits(&mut self, method: PitMethod) -> usize {
+        self.fill_pits_up_to_depth(method, f32::INFINITY)
+    }
+
+    /// Remove depressions that need at most `max_depth` of change (heightmap units)
+    ///
+    /// PriorityFlood leaves depressions deeper than `max_depth` below their spill level
+    /// untouched; Breach skips pits whose channel would have to cut deeper than `max_depth`.
+    /// Returns the number of cells whose elevation changed.
+    pub fn fill_pits_up_to_depth(&mut self, method: PitMethod, max_depth: f32) -> usize {
+        if self.width < 3 || self.height < 3 {
+            return 0;
+        }
+        match method {
+            PitMethod::PriorityFlood => self.priority_flood(max_depth),
+            PitMethod::Breach => self.breach_pits(max_depth),
+        }
+    }
+
+    /// Priority-Flood from the edges, then apply each raised region no deeper than `max_depth`
+    fn priority_flood(&mut self, max_depth: f32) -> usize {
+        let mut filled = self.data.clone();
+        let mut visited = vec![false; self.data.len()];
+        let mut queue = BinaryHeap::new();
+        for index in 0..self.data.len() {
+            if self.is_edge_index(index) {
+                visited[index] = true;
+                queue.push(LowestFirst {
+                    elevation: filled[index],
+                    index,
+                });
+            }
+        }
+
+        while let Some(LowestFirst { elevation, index }) = queue.pop() {
+            for neighbor in self.neighbor_indices(index) {
+                if visited[neighbor] {
+                    continue;
+                }
+                visited[neighbor] = true;
+                // Fill to just above the spill cell so D8 routing still finds a way out
+                if filled[neighbor] <= elevation {
+                    filled[neighbor] = elevation.next_up();
+                }
+                queue.push(LowestFirst {
+                    elevation: filled[neighbor],
+                    index: neighbor,
+                });
+            }
+        }
+
+        // Group raised cells into depressions and apply the shallow ones
+        let mut changed = 0;
+        let mut seen = vec![false; self.data.len()];
+        for start in 0..self.data.len() {
+            if seen[start] || filled[start] <= self.data[start] {
+                continue;
+            }
+            seen[start] = true;
+            let mut region = vec![start];
+            let mut cursor = 0;
+            let mut depth = 0.0f32;
+            while cursor < region.len() {
+                let index = region[cursor];
+                cursor += 1;
+                depth = depth.max(filled[index] - self.data[index]);
+                for neighbor in self.neighbor_indices(index) {
+                    if !seen[neighbor] && filled[neighbor] > self.data[neighbor] {
+                        seen[neighbor] = true;
+                        region.push(neighbor);
+                    }
+                }
+            }
+            if depth <= max_depth {
+                for index in region {
+                    self.data[index] = filled[index];
+                    changed += 1;
+                }
+            }
+        }
+        changed
+    }
+
+    /// Carve the least-cut channel from each interior pit to a lower cell or the map edge
+    fn breach_pits(&mut self, max_depth: f32) -> usize {
+        let is_pit = |map: &HeightMap, index: usize| {
+            !map.is_edge_index(index)
+                && map
+                    .neighbor_indices(index)
+                    .all(|neighbor| map.data[neighbor] >= map.data[index])
+        };
+        let mut pits: Vec<usize> = (0..self.data.len())
+            .filter(|&index| is_pit(self, index))
+            .collect();
+        pits.sort_by(|&a, &b| self.data[a].total_cmp(&self.data[b]));
+
+        let mut changed = vec![false; self.data.len()];
+        for pit in pits {
+            if !is_pit(self, pit) {
+                continue;
+            }
+            let pit_elevation = self.data[pit];
+
+            // Dijkstra on the elevation that would have to be cut away to pass each cell
+            let mut cost = vec![f32::INFINITY; self.data.len()];
+            let mut previous = vec![usize::MAX; self.data.len()];
+            let mut queue = BinaryHeap::new();
+            cost[pit] = 0.0;
+            queue.push(LowestFirst {
+                elevation: 0.0,
+                index: pit,
+            });
+            let mut outlet = None;
+            while let Some(LowestFirst { elevation, index }) = queue.pop() {
+                if elevation > cost[index] {
+                    continue;
+                }
+                if index != pit && (self.data[index] < pit_elevation || self.is_edge_index(index)) {
+                    outlet = Some(index);
+                    break;
+                }
+                for neighbor in self.neighbor_indices(index) {
+                    let cut = (self.data[neighbor] - pit_elevation).max(0.0);
+                    if cut > max_depth {
+                        continue;
+                    }
+                    let next = elevation + cut;
+                    if next < cost[neighbor] {
+                        cost[neighbor] = next;
+                        previous[neighbor] = index;
+                        queue.push(LowestFirst {
+                            elevation: next,
+                            index: neighbor,
+                        });
+                    }
+                }
+            }
+            let Some(outlet) = outlet else {
+                continue;
+            };
+
+            let mut path = vec![outlet];
+            while *path.last().unwrap() != pit {
+                path.push(previous[*path.last().unwrap()]);
+            }
+            path.reverse();
+
+            // Lower the channel along an even descent from the pit to the outlet
+            let steps = (path.len() - 1) as f32;
+            let drop = (pit_elevation - self.data[outlet]).max(steps * BREACH_MIN_DROP);
+            for (step, &index) in path.iter().enumerate().skip(1) {
+                let target = pit_elevation - drop * step as f32 / steps;
+                if self.data[index] > target {
+                    self.data[index] = target;
+                    changed[index] = true;
+                }
+            }
+        }
+        changed.iter().filter(|&&cell| cell).count()
+    }
 }
 
 /// Implementation for compatibility with existing code that expects &[Vec<f32>]
-impl std::ops::Index<usize> for HeightMap {
-    type Output = [f32];
+impl<T> std::ops::Index<usize> for HeightMap<T> {
+    type Output = [T];
 
     fn index(&self, y: usize) -> &Self::Output {
         let start = y * self.width;
@@ -220,7 +682,7 @@ impl std::ops::Index<usize> for HeightMap {
 }
 
 /// Mutable indexing support for HeightMap[y][x] = value syntax
-impl std::ops::IndexMut<usize> for HeightMap {
+impl<T> std::ops::IndexMut<usize> for HeightMap<T> {
     fn index_mut(&mut self, y: usize) -> &mut Self::Output {
         let start = y * self.width;
         let end = start + self.width;
@@ -229,7 +691,7 @@ impl std::ops::IndexMut<usize> for HeightMap {
 }
 
 /// Alternative structure for vector data (velocities, gradients)
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Vec2Map {
     x_data: Vec<f32>,
     y_data: Vec<f32>,
@@ -339,6 +801,169 @@ mod tests {
         assert!((map.get(1, 1) - 1.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_linear_ramp_hypsometry_and_stats() {
+        // 101 cells rising evenly from 100 m to 300 m
+        let ramp = HeightMap::from_nested(vec![(0..101).map(|i| 100.0 + 2.0 * i as f32).collect()]);
+
+        let stats = ramp.stats();
+        assert_eq!(stats.min, 100.0);
+        assert_eq!(stats.max, 300.0);
+        assert!((stats.mean - 200.0).abs() < 1e-4);
+        assert_eq!(stats.relief, 200.0);
+        // Uniform distribution over 101 evenly spaced values
+        assert!(
+            (stats.stddev - 2.0 * (101.0f32 * 101.0 - 1.0).sqrt() / 12.0f32.sqrt()).abs() < 1e-2
+        );
+
+        // Area above each threshold falls off in a straight line
+        let curve = ramp.hypsometry(10);
+        assert_eq!(curve.len(), 11);
+        assert_eq!(curve[0], (0.0, 1.0));
+        for &(elevation, area) in &curve[1..] {
+            assert!((area - (1.0 - elevation)).abs() < 1.0 / 101.0 + 1e-5);
+        }
+
+        let flat = HeightMap::new(3, 3, 5.0);
+        assert_eq!(flat.stats().relief, 0.0);
+        assert_eq!(flat.hypsometry(2), vec![(0.0, 1.0), (0.5, 0.0), (1.0, 0.0)]);
+    }
+
+    #[test]
+    fn test_uniform_plane_slope_and_aspect() {
+        // Rises one cell spacing per cell toward the east: a 45 degree west-facing slope
+        let spacing = 30.0;
+        let east_rising = HeightMap::from_nested(
+            (0..6)
+                .map(|_| (0..8).map(|x| x as f32 * spacing).collect())
+                .collect(),
+        );
+        let slope = east_rising.slope_field(spacing);
+        let aspect = east_rising.aspect_field();
+        for y in 1..5 {
+            for x in 1..7 {
+                assert!((slope.get(x, y) - 45.0).abs() < 1e-3);
+                assert!((aspect.get(x, y) - 270.0).abs() < 1e-3);
+            }
+        }
+
+        // Rising toward row 0 faces south; rising toward the last row faces north
+        let north_rising =
+            HeightMap::from_nested((0..6).map(|y| vec![(5 - y) as f32; 8]).collect());
+        assert!((north_rising.aspect_field().get(3, 3) - 180.0).abs() < 1e-3);
+        let south_rising = HeightMap::from_nested((0..6).map(|y| vec![y as f32; 8]).collect());
+        assert!(south_rising.aspect_field().get(3, 3).abs() < 1e-3);
+
+        let flat = HeightMap::new(4, 4, 2.0);
+        assert_eq!(*flat.slope_field(spacing).get(1, 1), 0.0);
+        assert_eq!(*flat.aspect_field().get(1, 1), -1.0);
+    }
+
+    #[test]
+    fn test_terrain_ruggedness_index() {
+        let flat = HeightMap::new(6, 5, 0.7);
+        assert!(
+            flat.terrain_ruggedness_index()
+                .iter()
+                .all(|&tri| tri == 0.0)
+        );
+
+        // Orthogonal neighbors differ by 1 and diagonals match: 4 of 8 differences
+        let checkerboard = HeightMap::from_nested(
+            (0..5)
+                .map(|y| (0..6).map(|x| ((x + y) % 2) as f32).collect())
+                .collect(),
+        );
+        let tri = checkerboard.terrain_ruggedness_index();
+        for y in 1..4 {
+            for x in 1..5 {
+                assert_eq!(*tri.get(x, y), 0.5);
+            }
+        }
+        assert!(tri.iter().all(|&value| value >= 0.5));
+    }
+
+    #[test]
+    fn test_blend_and_add_detail() {
+        let low = HeightMap::new(4, 3, 0.2);
+        let high = HeightMap::new(4, 3, 0.8);
+        let half = PhysicsGrid::new(4, 3, 0.5);
+        let blended = low.blend(&high, &half).unwrap();
+        assert!(blended.iter().all(|v| (v - 0.5).abs() < 1e-6));
+
+        // A mask takes one column from the other map
+        let mut mask = PhysicsGrid::new(4, 3, 0.0);
+        for y in 0..3 {
+            mask.set(2, y, 1.0);
+        }
+        let masked = low.blend(&high, &mask).unwrap();
+        assert_eq!(masked.get(2, 1), 0.8);
+        assert_eq!(masked.get(1, 1), 0.2);
+
+        let wrong_size = HeightMap::new(3, 3, 0.0);
+        assert_eq!(
+            low.blend(&wrong_size, &half).unwrap_err(),
+            DimensionMismatch {
+                expected: (4, 3),
+                found: (3, 3)
+            }
+        );
+        assert!(low.blend(&high, &PhysicsGrid::new(4, 4, 0.5)).is_err());
+
+        let mut base = low.clone();
+        let mut detail = HeightMap::new(4, 3, 0.5);
+        detail.set(0, 0, 1.0);
+        base.add_detail(&detail, 0.4).unwrap();
+        assert!((base.get(0, 0) - 0.4).abs() < 1e-6);
+        assert_eq!(base.get(1, 0), 0.2);
+        assert!(base.add_detail(&wrong_size, 0.4).is_err());
+    }
+
+    /// Plane tilting down toward the west edge, with a one-cell pit at (4, 3)
+    fn tilted_plane_with_pit() -> HeightMap {
+        let mut map = HeightMap::new(9, 7, 0.0);
+        for y in 0..7 {
+            for x in 0..9 {
+                map.set(x, y, 0.1 + 0.01 * x as f32);
+            }
+        }
+        map.set(4, 3, 0.05);
+        map
+    }
+
+    #[test]
+    fn test_fill_pits_removes_injected_pit() {
+        // Lowest neighbor of the pit is (3, y) at 0.13, its spill level
+        let mut filled = tilted_plane_with_pit();
+        assert_eq!(filled.fill_pits(PitMethod::PriorityFlood), 1);
+        assert!((filled.get(4, 3) - 0.13).abs() < 1e-6);
+        assert!(filled.get(4, 3) > filled.get(3, 3));
+        assert_eq!(filled.get(5, 3), 0.15);
+
+        // Too deep for a 1 cm limit: left alone
+        let mut shallow_only = tilted_plane_with_pit();
+        assert_eq!(
+            shallow_only.fill_pits_up_to_depth(PitMethod::PriorityFlood, 0.01),
+            0
+        );
+        assert_eq!(shallow_only.get(4, 3), 0.05);
+
+        // Breaching keeps the pit floor and cuts a channel that descends to the map edge
+        let mut breached = tilted_plane_with_pit();
+        assert!(breached.fill_pits(PitMethod::Breach) > 0);
+        assert_eq!(breached.get(4, 3), 0.05);
+        let mut cell = 3 * 9 + 4;
+        while let Some(lower) = breached
+            .neighbor_indices(cell)
+            .filter(|&n| breached.data[n] < breached.data[cell])
+            .min_by(|&a, &b| breached.data[a].total_cmp(&breached.data[b]))
+        {
+            cell = lower;
+        }
+        assert!(breached.is_edge_index(cell));
+        assert!(breached.data[cell] < 0.05);
+    }
+
     #[test]
     fn test_vec2map() {
         let mut vec_map = Vec2Map::new(2, 2);
@@ -4,9 +4,11 @@
 // ABOUTME: Unified mathematical types and utilities for cross-system data sharing
 // ABOUTME: Consolidated from duplicate Vec2 implementations in water.rs and tectonics.rs
 
+use serde::{Deserialize, Serialize};
+
 /// 2D vector type for physics calculations
 /// Unified across all physics systems to enable cross-system data sharing
-#[derive(Clone, Debug, PartialEq, Copy)]
+#[derive(Clone, Debug, PartialEq, Copy, Serialize, Deserialize)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -101,6 +103,50 @@ impl std::ops::Mul<Vec2> for f32 {
     }
 }
 
+/// Deterministic splitmix64 pseudo-random generator for reproducible procedural placement
+/// The same seed always yields the same sequence on every platform
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Next raw 64-bit value
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        // Top 24 bits fill the f32 mantissa exactly
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Uniform value in [min, max)
+    pub fn next_f32_in(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Uniform index in [0, n), or 0 when n is 0
+    pub fn next_usize_below(&mut self, n: usize) -> usize {
+        // Multiply-high maps the full 64-bit range onto [0, n) without modulo bias
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Fair coin flip
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+}
+
 /// Mathematical constants and utility functions
 pub mod constants {
     pub const PI: f32 = std::f32::consts::PI;
@@ -151,4 +197,28 @@ mod tests {
         let zero = Vec2::zero();
         assert_eq!(zero.normalize(), Vec2::zero());
     }
+
+    #[test]
+    fn rng_is_reproducible_and_uniform() {
+        let mut a = Rng::new(12345);
+        let mut b = Rng::new(12345);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        // 10k samples spread evenly over 10 bins (expected 1000, sigma ~30)
+        let mut rng = Rng::new(42);
+        let mut float_bins = [0usize; 10];
+        let mut index_bins = [0usize; 10];
+        for _ in 0..10_000 {
+            let value = rng.next_f32_in(-5.0, 5.0);
+            assert!((-5.0..5.0).contains(&value));
+            float_bins[((value + 5.0) as usize).min(9)] += 1;
+            index_bins[rng.next_usize_below(10)] += 1;
+        }
+        for count in float_bins.iter().chain(&index_bins) {
+            assert!((850..=1150).contains(count), "bin count {count}");
+        }
+        assert_eq!(rng.next_usize_below(0), 0);
+    }
 }
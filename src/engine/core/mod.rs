@@ -8,18 +8,23 @@ pub mod cache_system;
 pub mod dimensional;
 pub mod heightmap;
 pub mod math;
+pub mod netcdf;
 pub mod optimized_heightmap;
 pub mod physics_grid;
+pub mod planet;
 pub mod scale;
 pub mod temporal_performance;
 pub mod temporal_scaling;
 pub mod unified_temporal_scaling;
+pub mod visualization_layer;
 
 // Re-export key types for convenience
-pub use physics_grid::PhysicsGrid;
-pub use scale::{DetailLevel, WorldScale};
+pub use physics_grid::{Float, PhysicsGrid, StateFloat};
+pub use planet::PlanetConfig;
+pub use scale::{DetailLevel, WorldScale, WorldScaleBuilder};
 pub use temporal_performance::{
     PerformanceSummary, TemporalPerformanceMonitor, TemporalScalingTimer,
 };
 pub use temporal_scaling::{TemporalMode, TemporalScalingConfig, TemporalScalingService};
 pub use unified_temporal_scaling::{TemporalScale, TemporalScaleBuilder};
+pub use visualization_layer::VisualizationLayer;
@@ -0,0 +1,627 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Minimal NetCDF classic (CDF-1) reader and writer for 32-bit float variables
+// ABOUTME: Self-describing grid exports with an unlimited record dimension, readable by xarray/scipy
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+
+const MAGIC: &[u8; 4] = b"CDF\x01";
+const NC_DIMENSION: u32 = 0x0A;
+const NC_VARIABLE: u32 = 0x0B;
+const NC_ATTRIBUTE: u32 = 0x0C;
+const NC_CHAR: u32 = 2;
+const NC_FLOAT: u32 = 5;
+const FLOAT_BYTES: usize = 4;
+// Byte offset of the record count in the header
+const NUMRECS_OFFSET: u64 = 4;
+// Bytes read at a time while looking for the end of the header
+const HEADER_CHUNK: usize = 4096;
+
+/// Named dimension; `None` length marks the unlimited record dimension
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dimension {
+    pub name: String,
+    pub length: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    Text(String),
+    Float(Vec<f32>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Attribute {
+    pub name: String,
+    pub value: AttributeValue,
+}
+
+impl Attribute {
+    pub fn text(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value: AttributeValue::Text(value.to_string()),
+        }
+    }
+}
+
+/// Float variable over the listed dimension indices (record dimension first, if used)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Variable {
+    pub name: String,
+    pub dimensions: Vec<usize>,
+    pub attributes: Vec<Attribute>,
+}
+
+/// Dimensions, global attributes and variables of a classic-format file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetCdfSchema {
+    pub dimensions: Vec<Dimension>,
+    pub attributes: Vec<Attribute>,
+    pub variables: Vec<Variable>,
+}
+
+impl NetCdfSchema {
+    /// Dimension by name
+    pub fn dimension(&self, name: &str) -> Option<&Dimension> {
+        self.dimensions.iter().find(|dim| dim.name == name)
+    }
+
+    /// Whether a variable grows along the record dimension
+    fn is_record(&self, variable: &Variable) -> bool {
+        variable
+            .dimensions
+            .first()
+            .is_some_and(|&dim| self.dimensions[dim].length.is_none())
+    }
+
+    /// Values in one slab of a variable (one record's worth for record variables)
+    fn slab_len(&self, variable: &Variable) -> usize {
+        variable
+            .dimensions
+            .iter()
+            .filter_map(|&dim| self.dimensions[dim].length)
+            .product()
+    }
+
+    fn validate(&self) -> io::Result<()> {
+        let unlimited = self
+            .dimensions
+            .iter()
+            .filter(|dim| dim.length.is_none())
+            .count();
+        if unlimited > 1 {
+            return Err(invalid_input("only one unlimited dimension is allowed"));
+        }
+        for variable in &self.variables {
+            if variable
+                .dimensions
+                .iter()
+                .any(|&dim| dim >= self.dimensions.len())
+            {
+                return Err(invalid_input(&format!(
+                    "variable '{}' uses an undefined dimension",
+                    variable.name
+                )));
+            }
+            if variable
+                .dimensions
+                .iter()
+                .skip(1)
+                .any(|&dim| self.dimensions[dim].length.is_none())
+            {
+                return Err(invalid_input(&format!(
+                    "variable '{}' must list the record dimension first",
+                    variable.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Header bytes with the given record count and per-variable data offsets
+    fn encode_header(&self, num_records: usize, begins: &[usize]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(MAGIC);
+        put_u32(&mut buf, num_records as u32);
+
+        if self.dimensions.is_empty() {
+            put_u32(&mut buf, 0);
+            put_u32(&mut buf, 0);
+        } else {
+            put_u32(&mut buf, NC_DIMENSION);
+            put_u32(&mut buf, self.dimensions.len() as u32);
+            for dim in &self.dimensions {
+                put_name(&mut buf, &dim.name);
+                put_u32(&mut buf, dim.length.unwrap_or(0) as u32);
+            }
+        }
+
+        put_attributes(&mut buf, &self.attributes);
+
+        if self.variables.is_empty() {
+            put_u32(&mut buf, 0);
+            put_u32(&mut buf, 0);
+        } else {
+            put_u32(&mut buf, NC_VARIABLE);
+            put_u32(&mut buf, self.variables.len() as u32);
+            for (variable, &begin) in self.variables.iter().zip(begins) {
+                put_name(&mut buf, &variable.name);
+                put_u32(&mut buf, variable.dimensions.len() as u32);
+                for &dim in &variable.dimensions {
+                    put_u32(&mut buf, dim as u32);
+                }
+                put_attributes(&mut buf, &variable.attributes);
+                put_u32(&mut buf, NC_FLOAT);
+                put_u32(&mut buf, (self.slab_len(variable) * FLOAT_BYTES) as u32);
+                put_u32(&mut buf, begin as u32);
+            }
+        }
+        buf
+    }
+
+    /// Data offsets of every variable and the start of the record section
+    fn layout(&self, header_len: usize) -> (Vec<usize>, usize) {
+        let mut begins = vec![0; self.variables.len()];
+        let mut offset = header_len;
+        for (index, variable) in self.variables.iter().enumerate() {
+            if !self.is_record(variable) {
+                begins[index] = offset;
+                offset += self.slab_len(variable) * FLOAT_BYTES;
+            }
+        }
+        let record_start = offset;
+        for (index, variable) in self.variables.iter().enumerate() {
+            if self.is_record(variable) {
+                begins[index] = offset;
+                offset += self.slab_len(variable) * FLOAT_BYTES;
+            }
+        }
+        (begins, record_start)
+    }
+
+    /// Bytes per record across all record variables
+    fn record_size(&self) -> usize {
+        self.variables
+            .iter()
+            .filter(|variable| self.is_record(variable))
+            .map(|variable| self.slab_len(variable) * FLOAT_BYTES)
+            .sum()
+    }
+}
+
+/// Contents of a classic-format file read back from disk
+#[derive(Clone, Debug)]
+pub struct NetCdfFile {
+    pub schema: NetCdfSchema,
+    pub num_records: usize,
+    /// All values of each variable in schema order, records concatenated
+    values: Vec<Vec<f32>>,
+}
+
+impl NetCdfFile {
+    /// Values of a variable in row-major order, or None if the file lacks it
+    pub fn variable(&self, name: &str) -> Option<&[f32]> {
+        self.schema
+            .variables
+            .iter()
+            .position(|variable| variable.name == name)
+            .map(|index| self.values[index].as_slice())
+    }
+
+    /// Length of a dimension, counting records for the unlimited dimension
+    pub fn dimension_length(&self, name: &str) -> Option<usize> {
+        self.schema
+            .dimension(name)
+            .map(|dim| dim.length.unwrap_or(self.num_records))
+    }
+
+    /// Attribute of a variable by name
+    pub fn variable_attribute(&self, variable: &str, attribute: &str) -> Option<&AttributeValue> {
+        self.schema
+            .variables
+            .iter()
+            .find(|v| v.name == variable)?
+            .attributes
+            .iter()
+            .find(|a| a.name == attribute)
+            .map(|a| &a.value)
+    }
+}
+
+/// Write a new file holding the schema and the data of its fixed (non-record)
+/// variables; record variables start empty and grow with `append_record`
+pub fn create(path: &str, schema: &NetCdfSchema, fixed: &[(&str, &[f32])]) -> io::Result<()> {
+    schema.validate()?;
+    let header_len = schema
+        .encode_header(0, &vec![0; schema.variables.len()])
+        .len();
+    let (begins, _) = schema.layout(header_len);
+
+    let mut bytes = schema.encode_header(0, &begins);
+    for variable in schema.variables.iter().filter(|v| !schema.is_record(v)) {
+        let data = slab_for(fixed, variable, schema.slab_len(variable))?;
+        put_floats(&mut bytes, data);
+    }
+    fs::write(path, bytes)
+}
+
+/// Append one record of every record variable, returning the new record count
+/// Only the header is read, so appending costs the same however many records exist
+pub fn append_record(path: &str, record: &[(&str, &[f32])]) -> io::Result<usize> {
+    let (schema, num_records, begins) = read_header(File::open(path)?)?;
+
+    let record_size = schema.record_size();
+    let mut slab_bytes = Vec::with_capacity(record_size);
+    let mut record_start = None;
+    for (variable, &begin) in schema.variables.iter().zip(&begins) {
+        if schema.is_record(variable) {
+            record_start.get_or_insert(begin);
+            put_floats(
+                &mut slab_bytes,
+                slab_for(record, variable, schema.slab_len(variable))?,
+            );
+        }
+    }
+    let Some(record_start) = record_start else {
+        return Err(invalid_input("file has no record variables"));
+    };
+
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    file.seek(SeekFrom::Start(
+        (record_start + num_records * record_size) as u64,
+    ))?;
+    file.write_all(&slab_bytes)?;
+    file.seek(SeekFrom::Start(NUMRECS_OFFSET))?;
+    file.write_all(&((num_records + 1) as u32).to_be_bytes())?;
+    Ok(num_records + 1)
+}
+
+/// Schema and record count of a file, reading only its header
+pub fn read_schema(path: &str) -> io::Result<(NetCdfSchema, usize)> {
+    let (schema, num_records, _) = read_header(File::open(path)?)?;
+    Ok((schema, num_records))
+}
+
+/// Read a classic-format file holding only float variables
+pub fn read(path: &str) -> io::Result<NetCdfFile> {
+    let bytes = fs::read(path)?;
+    let (schema, num_records, begins) = decode_header(&bytes)?;
+    let record_size = schema.record_size();
+
+    let mut values = Vec::with_capacity(schema.variables.len());
+    for (variable, &begin) in schema.variables.iter().zip(&begins) {
+        let slab = schema.slab_len(variable) * FLOAT_BYTES;
+        let (count, stride) = if schema.is_record(variable) {
+            (num_records, record_size)
+        } else {
+            (1, 0)
+        };
+        let mut data = Vec::with_capacity(count * slab / FLOAT_BYTES);
+        for record in 0..count {
+            let start = begin + record * stride;
+            let chunk = bytes
+                .get(start..start + slab)
+                .ok_or_else(|| invalid_data("variable data runs past end of file"))?;
+            data.extend(
+                chunk
+                    .chunks_exact(FLOAT_BYTES)
+                    .map(|b| f32::from_be_bytes([b[0], b[1], b[2], b[3]])),
+            );
+        }
+        values.push(data);
+    }
+
+    Ok(NetCdfFile {
+        schema,
+        num_records,
+        values,
+    })
+}
+
+fn slab_for<'a>(
+    data: &[(&str, &'a [f32])],
+    variable: &Variable,
+    expected: usize,
+) -> io::Result<&'a [f32]> {
+    let (_, values) = data
+        .iter()
+        .find(|(name, _)| *name == variable.name)
+        .ok_or_else(|| invalid_input(&format!("no data for variable '{}'", variable.name)))?;
+    if values.len() != expected {
+        return Err(invalid_input(&format!(
+            "variable '{}' needs {} values, got {}",
+            variable.name,
+            expected,
+            values.len()
+        )));
+    }
+    Ok(values)
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message.to_string())
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn put_u32(buf: &mut Vec<u8>, value: u32) {
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+/// Length-prefixed bytes zero-padded to a 4-byte boundary
+fn put_padded(buf: &mut Vec<u8>, bytes: &[u8]) {
+    put_u32(buf, bytes.len() as u32);
+    buf.extend_from_slice(bytes);
+    buf.resize(buf.len() + (4 - bytes.len() % 4) % 4, 0);
+}
+
+fn put_name(buf: &mut Vec<u8>, name: &str) {
+    put_padded(buf, name.as_bytes());
+}
+
+fn put_floats(buf: &mut Vec<u8>, values: &[f32]) {
+    for value in values {
+        buf.extend_from_slice(&value.to_be_bytes());
+    }
+}
+
+fn put_attributes(buf: &mut Vec<u8>, attributes: &[Attribute]) {
+    if attributes.is_empty() {
+        put_u32(buf, 0);
+        put_u32(buf, 0);
+        return;
+    }
+    put_u32(buf, NC_ATTRIBUTE);
+    put_u32(buf, attributes.len() as u32);
+    for attribute in attributes {
+        put_name(buf, &attribute.name);
+        match &attribute.value {
+            AttributeValue::Text(text) => {
+                put_u32(buf, NC_CHAR);
+                put_padded(buf, text.as_bytes());
+            }
+            AttributeValue::Float(values) => {
+                put_u32(buf, NC_FLOAT);
+                put_u32(buf, values.len() as u32);
+                put_floats(buf, values);
+            }
+        }
+    }
+}
+
+/// Cursor over header bytes
+struct HeaderReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl HeaderReader<'_> {
+    fn take(&mut self, len: usize) -> io::Result<&[u8]> {
+        let chunk = self.bytes.get(self.pos..self.pos + len).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::UnexpectedEof, "truncated NetCDF header")
+        })?;
+        self.pos += len;
+        Ok(chunk)
+    }
+
+    fn u32(&mut self) -> io::Result<u32> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn padded(&mut self) -> io::Result<Vec<u8>> {
+        let len = self.u32()? as usize;
+        let bytes = self.take(len)?.to_vec();
+        self.take((4 - len % 4) % 4)?;
+        Ok(bytes)
+    }
+
+    fn name(&mut self) -> io::Result<String> {
+        String::from_utf8(self.padded()?).map_err(|_| invalid_data("name is not UTF-8"))
+    }
+
+    /// Element count of a tagged list, or 0 when the list is absent
+    fn list(&mut self, tag: u32) -> io::Result<usize> {
+        let found = self.u32()?;
+        let count = self.u32()? as usize;
+        if found != tag && !(found == 0 && count == 0) {
+            return Err(invalid_data("unexpected NetCDF header tag"));
+        }
+        Ok(count)
+    }
+
+    fn attributes(&mut self) -> io::Result<Vec<Attribute>> {
+        let count = self.list(NC_ATTRIBUTE)?;
+        let mut attributes = Vec::with_capacity(count);
+        for _ in 0..count {
+            let name = self.name()?;
+            let value = match self.u32()? {
+                NC_CHAR => AttributeValue::Text(
+                    String::from_utf8(self.padded()?)
+                        .map_err(|_| invalid_data("attribute is not UTF-8"))?,
+                ),
+                NC_FLOAT => {
+                    let len = self.u32()? as usize;
+                    let bytes = self.take(len * FLOAT_BYTES)?;
+                    AttributeValue::Float(
+                        bytes
+                            .chunks_exact(FLOAT_BYTES)
+                            .map(|b| f32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+                            .collect(),
+                    )
+                }
+                _ => return Err(invalid_data("only text and float attributes are supported")),
+            };
+            attributes.push(Attribute { name, value });
+        }
+        Ok(attributes)
+    }
+}
+
+/// Decode the header from the start of `reader`, reading little further than it extends
+fn read_header(mut reader: impl Read) -> io::Result<(NetCdfSchema, usize, Vec<usize>)> {
+    let mut bytes = Vec::new();
+    let mut chunk = HEADER_CHUNK;
+    loop {
+        let read = reader.by_ref().take(chunk as u64).read_to_end(&mut bytes)?;
+        match decode_header(&bytes) {
+            Err(error) if error.kind() == io::ErrorKind::UnexpectedEof && read > 0 => {
+                chunk *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Schema, record count and variable data offsets from a file's header
+fn decode_header(bytes: &[u8]) -> io::Result<(NetCdfSchema, usize, Vec<usize>)> {
+    let mut reader = HeaderReader { bytes, pos: 0 };
+    if reader.take(4)? != MAGIC {
+        return Err(invalid_data("not a NetCDF classic (CDF-1) file"));
+    }
+    let num_records = reader.u32()? as usize;
+
+    let mut schema = NetCdfSchema::default();
+    for _ in 0..reader.list(NC_DIMENSION)? {
+        let name = reader.name()?;
+        let length = reader.u32()? as usize;
+        schema.dimensions.push(Dimension {
+            name,
+            length: (length > 0).then_some(length),
+        });
+    }
+    schema.attributes = reader.attributes()?;
+
+    let mut begins = Vec::new();
+    for _ in 0..reader.list(NC_VARIABLE)? {
+        let name = reader.name()?;
+        let rank = reader.u32()? as usize;
+        let dimensions = (0..rank)
+            .map(|_| reader.u32().map(|dim| dim as usize))
+            .collect::<io::Result<Vec<_>>>()?;
+        let attributes = reader.attributes()?;
+        if reader.u32()? != NC_FLOAT {
+            return Err(invalid_data("only float variables are supported"));
+        }
+        let _vsize = reader.u32()?;
+        begins.push(reader.u32()? as usize);
+        schema.variables.push(Variable {
+            name,
+            dimensions,
+            attributes,
+        });
+    }
+    schema
+        .validate()
+        .map_err(|e| invalid_data(&e.to_string()))?;
+
+    Ok((schema, num_records, begins))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_and_record_variables_round_trip() {
+        let path = std::env::temp_dir().join("kosmarium_netcdf_round_trip.nc");
+        let path = path.to_str().unwrap();
+
+        let schema = NetCdfSchema {
+            dimensions: vec![
+                Dimension {
+                    name: "time".to_string(),
+                    length: None,
+                },
+                Dimension {
+                    name: "x".to_string(),
+                    length: Some(3),
+                },
+            ],
+            attributes: vec![Attribute::text("title", "test")],
+            variables: vec![
+                Variable {
+                    name: "x".to_string(),
+                    dimensions: vec![1],
+                    attributes: vec![Attribute::text("units", "km")],
+                },
+                Variable {
+                    name: "time".to_string(),
+                    dimensions: vec![0],
+                    attributes: Vec::new(),
+                },
+                Variable {
+                    name: "depth".to_string(),
+                    dimensions: vec![0, 1],
+                    attributes: vec![Attribute {
+                        name: "valid_range".to_string(),
+                        value: AttributeValue::Float(vec![0.0, 10.0]),
+                    }],
+                },
+            ],
+        };
+
+        create(path, &schema, &[("x", &[0.5, 1.5, 2.5])]).unwrap();
+        assert_eq!(&fs::read(path).unwrap()[..4], MAGIC);
+        assert_eq!(
+            append_record(path, &[("time", &[0.0]), ("depth", &[1.0, 2.0, 3.0])]).unwrap(),
+            1
+        );
+        assert_eq!(
+            append_record(path, &[("time", &[6.0]), ("depth", &[4.0, 5.0, 6.0])]).unwrap(),
+            2
+        );
+        assert!(append_record(path, &[("time", &[12.0])]).is_err());
+        assert_eq!(read_schema(path).unwrap(), (schema.clone(), 2));
+
+        let file = read(path).unwrap();
+        assert_eq!(file.schema, schema);
+        assert_eq!(file.dimension_length("time"), Some(2));
+        assert_eq!(file.variable("x"), Some(&[0.5, 1.5, 2.5][..]));
+        assert_eq!(file.variable("time"), Some(&[0.0, 6.0][..]));
+        assert_eq!(
+            file.variable("depth"),
+            Some(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0][..])
+        );
+        assert_eq!(
+            file.variable_attribute("x", "units"),
+            Some(&AttributeValue::Text("km".to_string()))
+        );
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn header_spanning_several_read_chunks_decodes() {
+        let path = std::env::temp_dir().join("kosmarium_netcdf_long_header.nc");
+        let path = path.to_str().unwrap();
+        let history = "x".repeat(3 * HEADER_CHUNK);
+        let schema = NetCdfSchema {
+            dimensions: vec![Dimension {
+                name: "time".to_string(),
+                length: None,
+            }],
+            attributes: vec![Attribute::text("history", &history)],
+            variables: vec![Variable {
+                name: "time".to_string(),
+                dimensions: vec![0],
+                attributes: Vec::new(),
+            }],
+        };
+
+        create(path, &schema, &[]).unwrap();
+        for hours in [0.0, 6.0, 12.0] {
+            append_record(path, &[("time", &[hours])]).unwrap();
+        }
+        assert_eq!(read_schema(path).unwrap(), (schema, 3));
+        assert_eq!(
+            read(path).unwrap().variable("time"),
+            Some(&[0.0, 6.0, 12.0][..])
+        );
+        let _ = fs::remove_file(path);
+    }
+}
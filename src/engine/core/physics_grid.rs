@@ -5,6 +5,8 @@
 // ABOUTME: Extends HeightMap pattern to any data type T for cache-efficient physics simulations
 
 use crate::engine::physics::water::Vec2;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 
 /// High-performance 2D physics grid using flat memory layout
 ///
@@ -15,7 +17,7 @@ use crate::engine::physics::water::Vec2;
 /// - Better memory locality for typical physics access patterns
 ///
 /// Replaces Vec<Vec<T>> patterns throughout physics layers for uniform performance
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PhysicsGrid<T> {
     data: Vec<T>,
     width: usize,
@@ -183,29 +185,128 @@ impl<T> PhysicsGrid<T> {
     }
 }
 
-// Specialized implementations for common physics data types
-impl PhysicsGrid<f32> {
+/// Floating-point cell types for numeric grids
+///
+/// f32 halves memory and is the default for simulation layers; f64 keeps long runs
+/// conservative where many small updates accumulate into large totals.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + std::fmt::Debug
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self>
+    + std::ops::AddAssign
+    + std::ops::SubAssign
+    + std::iter::Sum
+{
+    const ZERO: Self;
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn from_f32(value: f32) -> Self;
+    fn to_f32(self) -> f32;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($float:ident),*) => {$(
+        impl Float for $float {
+            const ZERO: Self = 0.0;
+            const EPSILON: Self = $float::EPSILON;
+            const INFINITY: Self = $float::INFINITY;
+            const NEG_INFINITY: Self = $float::NEG_INFINITY;
+
+            fn from_f64(value: f64) -> Self {
+                value as $float
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn from_f32(value: f32) -> Self {
+                value as $float
+            }
+
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn min(self, other: Self) -> Self {
+                $float::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $float::max(self, other)
+            }
+
+            fn abs(self) -> Self {
+                $float::abs(self)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, f64);
+
+/// Cell precision for water depth and atmospheric pressure
+///
+/// f32 unless the `f64-state` feature is enabled. Code that reads these layers into
+/// f32 physics goes through `Float::to_f32`/`from_f32` so it compiles either way.
+#[cfg(not(feature = "f64-state"))]
+pub type StateFloat = f32;
+#[cfg(feature = "f64-state")]
+pub type StateFloat = f64;
+
+/// Borrow a state layer's cells as f32, copying only when they are stored wider
+#[cfg(not(feature = "f64-state"))]
+pub fn state_as_f32(values: &[StateFloat]) -> Cow<'_, [f32]> {
+    Cow::Borrowed(values)
+}
+
+/// Borrow a state layer's cells as f32, copying only when they are stored wider
+#[cfg(feature = "f64-state")]
+pub fn state_as_f32(values: &[StateFloat]) -> Cow<'_, [f32]> {
+    Cow::Owned(values.iter().map(|value| value.to_f32()).collect())
+}
+
+// Numeric operations for scalar physics fields in either precision
+impl<T: Float> PhysicsGrid<T> {
     /// Get minimum value in physics grid
-    pub fn min(&self) -> f32 {
-        self.data.iter().copied().fold(f32::INFINITY, f32::min)
+    pub fn min(&self) -> T {
+        self.data.iter().copied().fold(T::INFINITY, T::min)
     }
 
     /// Get maximum value in physics grid
-    pub fn max(&self) -> f32 {
-        self.data.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+    pub fn max(&self) -> T {
+        self.data.iter().copied().fold(T::NEG_INFINITY, T::max)
     }
 
     /// Sum all values in physics grid
-    pub fn sum(&self) -> f32 {
-        self.data.iter().sum()
+    pub fn sum(&self) -> T {
+        self.data.iter().copied().sum()
     }
 
-    /// Get average value in physics grid
-    pub fn average(&self) -> f32 {
+    /// Sum all values with Kahan compensated summation
+    /// Values are always accumulated in row-major order, so the result is
+    /// deterministic and independent of how callers parallelize around it
+    pub fn sum_kahan(&self) -> T {
+        kahan_sum(self.data.iter().copied())
+    }
+
+    /// Get average value in physics grid (compensated summation, see `sum_kahan`)
+    pub fn average(&self) -> T {
         if self.data.is_empty() {
-            0.0
+            T::ZERO
         } else {
-            self.sum() / self.data.len() as f32
+            self.sum_kahan() / T::from_f64(self.data.len() as f64)
         }
     }
 
@@ -215,7 +316,7 @@ impl PhysicsGrid<f32> {
         let max_val = self.max();
         let range = max_val - min_val;
 
-        if range > f32::EPSILON {
+        if range > T::EPSILON {
             for value in &mut self.data {
                 *value = (*value - min_val) / range;
             }
@@ -229,22 +330,34 @@ impl PhysicsGrid<Vec2> {
         self.get(x, y).magnitude()
     }
 
-    /// Get maximum magnitude in the grid
+    /// Get maximum magnitude in the grid (exact for any evaluation order)
     pub fn max_magnitude(&self) -> f32 {
         self.data.iter().map(|v| v.magnitude()).fold(0.0, f32::max)
     }
 
-    /// Get average magnitude in the grid
+    /// Get average magnitude in the grid (compensated summation in row-major order)
     pub fn average_magnitude(&self) -> f32 {
         if self.data.is_empty() {
             0.0
         } else {
-            let sum: f32 = self.data.iter().map(|v| v.magnitude()).sum();
-            sum / self.data.len() as f32
+            kahan_sum(self.data.iter().map(|v| v.magnitude())) / self.data.len() as f32
         }
     }
 }
 
+/// Kahan compensated sum, bounding rounding error independently of the value count
+fn kahan_sum<T: Float>(values: impl Iterator<Item = T>) -> T {
+    let mut sum = T::ZERO;
+    let mut compensation = T::ZERO;
+    for value in values {
+        let corrected = value - compensation;
+        let next = sum + corrected;
+        compensation = (next - sum) - corrected;
+        sum = next;
+    }
+    sum
+}
+
 /// Implementation for compatibility with existing code that expects &[Vec<T>]
 impl<T> std::ops::Index<usize> for PhysicsGrid<T> {
     type Output = [T];
@@ -329,9 +442,26 @@ mod tests {
         assert_eq!(grid.min(), 10.0);
         assert_eq!(grid.max(), 40.0);
         assert_eq!(grid.sum(), 100.0);
+        assert_eq!(grid.sum_kahan(), 100.0);
         assert_eq!(grid.average(), 25.0);
     }
 
+    #[test]
+    fn kahan_sum_stays_accurate_where_naive_sum_drifts() {
+        let grid = PhysicsGrid::<f32>::new(1000, 1000, 0.1);
+        let exact = 100_000.0;
+
+        assert!((grid.sum() - exact).abs() > 100.0);
+        assert!((grid.sum_kahan() - exact).abs() < 0.1);
+        assert!((grid.average() - 0.1).abs() < 1e-7);
+    }
+
+    #[test]
+    fn f64_grids_share_the_numeric_operations() {
+        let grid = PhysicsGrid::<f64>::from_nested(vec![vec![1.0, 2.0], vec![3.0, 6.0]]);
+        assert_eq!((grid.min(), grid.max(), grid.average()), (1.0, 6.0, 3.0));
+    }
+
     #[test]
     fn test_normalization() {
         let mut grid = PhysicsGrid::from_nested(vec![vec![10.0, 20.0], vec![30.0, 40.0]]);
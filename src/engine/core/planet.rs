@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Planetary constants - surface gravity, rotation rate and radius shared by the physics
+// ABOUTME: Defaults describe Earth; other planets change flow speeds, Coriolis and scale height
+
+use serde::{Deserialize, Serialize};
+
+/// Earth surface gravity (m/s²)
+pub const EARTH_GRAVITY: f32 = 9.81;
+
+/// Earth rotation rate (rad/s)
+pub const EARTH_ROTATION_RATE: f64 = 7.27e-5;
+
+/// Earth mean radius (km)
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Bulk properties of the simulated planet
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlanetConfig {
+    /// Surface gravity (m/s²)
+    pub gravity: f32,
+    /// Rotation rate (rad/s); sets the Coriolis parameter f = 2Ω sin(φ)
+    pub rotation_rate: f64,
+    /// Mean radius (km); a smaller planet spans more latitude over the same domain
+    pub radius_km: f64,
+}
+
+impl Default for PlanetConfig {
+    fn default() -> Self {
+        Self::earth()
+    }
+}
+
+impl PlanetConfig {
+    pub fn earth() -> Self {
+        Self {
+            gravity: EARTH_GRAVITY,
+            rotation_rate: EARTH_ROTATION_RATE,
+            radius_km: EARTH_RADIUS_KM,
+        }
+    }
+
+    /// Gravity relative to Earth, for scaling quantities derived at Earth gravity
+    pub fn gravity_ratio(&self) -> f32 {
+        self.gravity / EARTH_GRAVITY
+    }
+}
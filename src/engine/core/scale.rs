@@ -5,11 +5,12 @@
 // ABOUTME: Provides WorldScale context and ScaleAware trait for consistent parameter derivation
 
 use crate::engine::core::unified_temporal_scaling::TemporalScale;
+use serde::{Deserialize, Serialize};
 
 /// Represents the scale context for world generation
 /// Separates physical scale (real-world size) from resolution scale (output detail)
 /// and provides unified temporal scaling context for physics consistency
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WorldScale {
     /// Physical size the map represents in kilometers
     pub physical_size_km: f64,
@@ -20,6 +21,11 @@ pub struct WorldScale {
     /// Unified temporal scaling context for all physics systems
     /// This ensures temporal coupling and conservation law compliance
     pub temporal_scale: TemporalScale,
+    /// Explicit physical (width, height) in kilometers for non-square domains
+    /// None means square cells sized from `physical_size_km`
+    pub physical_extent_km: Option<(f64, f64)>,
+    /// Domain center as (latitude, longitude) in degrees, if placed on the globe
+    pub geographic_center: Option<(f64, f64)>,
 }
 
 impl WorldScale {
@@ -30,6 +36,8 @@ impl WorldScale {
             resolution,
             _detail_level: detail_level,
             temporal_scale: TemporalScale::default_demo(),
+            physical_extent_km: None,
+            geographic_center: None,
         }
     }
     
@@ -45,6 +53,8 @@ impl WorldScale {
             resolution,
             _detail_level: detail_level,
             temporal_scale,
+            physical_extent_km: None,
+            geographic_center: None,
         }
     }
 
@@ -53,6 +63,38 @@ impl WorldScale {
         (self.physical_size_km * 1000.0) / self.resolution.0.max(self.resolution.1) as f64
     }
 
+    /// Get the east-west cell spacing in meters
+    pub fn meters_per_pixel_x(&self) -> f64 {
+        match self.physical_extent_km {
+            Some((width_km, _)) => width_km * 1000.0 / self.resolution.0.max(1) as f64,
+            None => self.meters_per_pixel(),
+        }
+    }
+
+    /// Get the north-south cell spacing in meters
+    pub fn meters_per_pixel_y(&self) -> f64 {
+        match self.physical_extent_km {
+            Some((_, height_km)) => height_km * 1000.0 / self.resolution.1.max(1) as f64,
+            None => self.meters_per_pixel(),
+        }
+    }
+
+    /// Whether cells have the same spacing along both axes
+    pub fn has_square_cells(&self) -> bool {
+        let (dx, dy) = (self.meters_per_pixel_x(), self.meters_per_pixel_y());
+        (dx - dy).abs() <= 1e-9 * dx.max(dy)
+    }
+
+    /// Latitude of the domain center in degrees, if placed on the globe
+    pub fn center_latitude(&self) -> Option<f64> {
+        self.geographic_center.map(|(latitude, _)| latitude)
+    }
+
+    /// Longitude of the domain center in degrees, if placed on the globe
+    pub fn center_longitude(&self) -> Option<f64> {
+        self.geographic_center.map(|(_, longitude)| longitude)
+    }
+
     /// Get how many pixels represent one kilometer
     pub fn _pixels_per_km(&self) -> f64 {
         self.resolution.0.max(self.resolution.1) as f64 / self.physical_size_km
@@ -71,8 +113,73 @@ impl WorldScale {
     }
 }
 
+/// Builder for WorldScale with separate physical width/height and geographic placement
+pub struct WorldScaleBuilder {
+    resolution: (u32, u32),
+    physical_extent_km: Option<(f64, f64)>,
+    geographic_center: Option<(f64, f64)>,
+    detail_level: DetailLevel,
+    temporal_scale: TemporalScale,
+}
+
+impl WorldScaleBuilder {
+    /// Start a builder for the given output resolution (width, height)
+    /// Defaults to 1 km square cells, demo temporal scaling and no geographic placement
+    pub fn new(resolution: (u32, u32)) -> Self {
+        Self {
+            resolution,
+            physical_extent_km: None,
+            geographic_center: None,
+            detail_level: DetailLevel::Standard,
+            temporal_scale: TemporalScale::default_demo(),
+        }
+    }
+
+    /// Physical width and height of the domain in kilometers
+    pub fn physical_size_km(mut self, width_km: f64, height_km: f64) -> Self {
+        self.physical_extent_km = Some((width_km, height_km));
+        self
+    }
+
+    /// Domain center latitude and longitude in degrees
+    pub fn center(mut self, latitude: f64, longitude: f64) -> Self {
+        self.geographic_center = Some((latitude.clamp(-90.0, 90.0), longitude));
+        self
+    }
+
+    pub fn detail_level(mut self, detail_level: DetailLevel) -> Self {
+        self.detail_level = detail_level;
+        self
+    }
+
+    pub fn temporal_scale(mut self, temporal_scale: TemporalScale) -> Self {
+        self.temporal_scale = temporal_scale;
+        self
+    }
+
+    pub fn build(self) -> WorldScale {
+        let (width, height) = self.resolution;
+        let (width_km, height_km) = self
+            .physical_extent_km
+            .unwrap_or((width as f64, height as f64));
+
+        // Keep `physical_size_km` meaning the extent along the longer pixel axis,
+        // so `meters_per_pixel()` stays consistent for square-cell consumers
+        let physical_size_km = if width >= height { width_km } else { height_km };
+
+        WorldScale {
+            physical_size_km,
+            resolution: self.resolution,
+            _detail_level: self.detail_level,
+            temporal_scale: self.temporal_scale,
+            physical_extent_km: Some((width_km, height_km)),
+            geographic_center: self.geographic_center,
+        }
+    }
+}
+
 /// Quality/performance trade-off levels for generation
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum DetailLevel {
     /// Fast generation with basic features only
     _Preview,
@@ -109,6 +216,27 @@ mod tests {
         assert_eq!(scale.total_cells(), 500_000);
     }
 
+    #[test]
+    fn builder_surfaces_anisotropic_spacing() {
+        let scale = WorldScaleBuilder::new((200, 200))
+            .physical_size_km(400.0, 100.0)
+            .center(45.0, -120.0)
+            .build();
+
+        assert!((scale.meters_per_pixel_x() - 2000.0).abs() < 1e-9);
+        assert!((scale.meters_per_pixel_y() - 500.0).abs() < 1e-9);
+        assert!(!scale.has_square_cells());
+        assert_eq!(scale.center_latitude(), Some(45.0));
+        assert_eq!(scale.center_longitude(), Some(-120.0));
+
+        // Legacy constructor keeps square cells on both axes
+        let legacy = WorldScale::new(10.0, (1000, 500), DetailLevel::Standard);
+        assert!(legacy.has_square_cells());
+        assert_eq!(legacy.meters_per_pixel_x(), legacy.meters_per_pixel());
+        assert_eq!(legacy.meters_per_pixel_y(), legacy.meters_per_pixel());
+        assert_eq!(legacy.center_latitude(), None);
+    }
+
     #[test]
     fn scale_factor_calculation() {
         let scale = WorldScale::new(10.0, (480, 240), DetailLevel::Standard);
@@ -6,6 +6,10 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Simulated seconds per tick in Realistic mode (6 minutes of world time)
+/// Other modes scale this in proportion to their factor relative to Realistic
+pub const REALISTIC_SECONDS_PER_TICK: f64 = 360.0;
+
 /// Temporal scaling modes for simulation realism
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TemporalMode {
@@ -62,6 +66,7 @@ impl Default for TemporalScalingConfig {
 }
 
 /// Temporal scaling service for converting between demo and realistic time scales
+#[derive(Serialize, Deserialize)]
 pub struct TemporalScalingService {
     config: TemporalScalingConfig,
 
@@ -171,13 +176,26 @@ impl TemporalScalingService {
         &self.config
     }
 
-    /// Calculate scaling factors based on current configuration
-    fn update_scaling_factors(&mut self) {
-        let base_factor = match self.config.mode {
+    /// Single factor for the configured mode, applied uniformly when all physics
+    /// systems advance together (season, pressure and water)
+    pub fn unified_scaling_factor(&self) -> f64 {
+        match self.config.mode {
             TemporalMode::Demo => 1.0,
             TemporalMode::Realistic => 2.5 / 3650.0, // Scale to achieve 2.5 kg/m²/year target from 10.0 kg/m²/day
             TemporalMode::Research => self.config.custom_scaling_factor,
-        };
+        }
+    }
+
+    /// Simulated world seconds that elapse during one simulation tick
+    /// Realistic mode advances `REALISTIC_SECONDS_PER_TICK`; Demo runs 1460x faster
+    pub fn seconds_per_tick(&self) -> f64 {
+        let realistic_factor = 2.5 / 3650.0;
+        REALISTIC_SECONDS_PER_TICK * self.unified_scaling_factor() / realistic_factor
+    }
+
+    /// Calculate scaling factors based on current configuration
+    fn update_scaling_factors(&mut self) {
+        let base_factor = self.unified_scaling_factor();
 
         self.biological_scaling_factor = if self.config.scale_biological {
             base_factor
@@ -364,6 +382,25 @@ mod tests {
         assert_eq!(service.biological_scaling_factor(), 1.0);
     }
 
+    #[test]
+    fn test_seconds_per_tick_follows_mode() {
+        let realistic = TemporalScalingService::new(TemporalScalingConfig {
+            mode: TemporalMode::Realistic,
+            ..Default::default()
+        });
+        assert!((realistic.seconds_per_tick() - REALISTIC_SECONDS_PER_TICK).abs() < 1e-9);
+
+        let demo = TemporalScalingService::new(TemporalScalingConfig::default());
+        assert!((demo.seconds_per_tick() - 360.0 * 1460.0).abs() < 1e-6);
+
+        let research = TemporalScalingService::new(TemporalScalingConfig {
+            mode: TemporalMode::Research,
+            custom_scaling_factor: 2.0 * 2.5 / 3650.0,
+            ..Default::default()
+        });
+        assert!((research.seconds_per_tick() - 720.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_realistic_mode_scaling() {
         let service = TemporalScalingService::new(TemporalScalingConfig {
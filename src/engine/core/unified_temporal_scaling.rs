@@ -246,6 +246,33 @@ impl Default for TemporalScaleBuilder {
     }
 }
 
+/// Conversion from unified TemporalScale back to a TemporalScalingConfig
+///
+/// All process families are scaled so the service reproduces the unified factor.
+/// Factors that differ from a mode's canonical rate are kept as Research scaling.
+impl From<&TemporalScale> for TemporalScalingConfig {
+    fn from(temporal_scale: &TemporalScale) -> Self {
+        let canonical_factor = match temporal_scale.mode {
+            TemporalMode::Demo => Some(1.0),
+            TemporalMode::Realistic => Some(2.5 / 3650.0),
+            TemporalMode::Research => None,
+        };
+        let mode = if canonical_factor == Some(temporal_scale.global_temporal_factor) {
+            temporal_scale.mode
+        } else {
+            TemporalMode::Research
+        };
+
+        TemporalScalingConfig {
+            mode,
+            custom_scaling_factor: temporal_scale.global_temporal_factor,
+            scale_biological: true,
+            scale_geological: true,
+            scale_atmospheric: true,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
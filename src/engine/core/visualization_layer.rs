@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Visualization layer names shared by workspace configuration, simulation and renderers
+// ABOUTME: Kept outside rendering so configuration can name layers without depending on renderers
+
+/// Simulation fields that can be displayed as a visualization layer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisualizationLayer {
+    Elevation,
+    Water,
+    Biomes,
+    Temperature,
+    Pressure,
+    Wind,
+    Flow,
+    Changes,
+    Sediment,
+    Clouds,
+    Divides,
+}
+
+impl VisualizationLayer {
+    /// Parse layer from string for CLI arguments
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "elevation" | "elev" | "height" => Some(Self::Elevation),
+            "water" | "depth" => Some(Self::Water),
+            "biomes" | "biome" => Some(Self::Biomes),
+            "temperature" | "temp" => Some(Self::Temperature),
+            "pressure" | "press" => Some(Self::Pressure),
+            "wind" => Some(Self::Wind),
+            "flow" | "velocity" => Some(Self::Flow),
+            "changes" | "diff" => Some(Self::Changes),
+            "sediment" | "sed" => Some(Self::Sediment),
+            "clouds" | "cloud" => Some(Self::Clouds),
+            "divides" | "divide" | "watersheds" => Some(Self::Divides),
+            _ => None,
+        }
+    }
+
+    /// Get display name for headers
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Elevation => "ELEVATION",
+            Self::Water => "WATER",
+            Self::Biomes => "BIOMES",
+            Self::Temperature => "TEMPERATURE",
+            Self::Pressure => "PRESSURE",
+            Self::Wind => "WIND",
+            Self::Flow => "FLOW",
+            Self::Changes => "CHANGES",
+            Self::Sediment => "SEDIMENT",
+            Self::Clouds => "CLOUDS",
+            Self::Divides => "DIVIDES",
+        }
+    }
+}
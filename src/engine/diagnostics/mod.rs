@@ -4,9 +4,11 @@
 // ABOUTME: Diagnostic modules for comprehensive physics system validation
 // ABOUTME: Provides real-time monitoring and validation of physics systems
 
+pub mod regression_fixture;
 pub mod water_flow_validation;
 // pub mod legacy_simulation_diagnostics; // Temporarily disabled during water flow validation
 
+pub use regression_fixture::{Fixture, Mismatch, generate_fixture};
 pub use water_flow_validation::*;
 // pub use legacy_simulation_diagnostics::*; // Temporarily disabled
 
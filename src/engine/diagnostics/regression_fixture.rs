@@ -0,0 +1,249 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Deterministic regression fixtures - layer checksums and sampled cells for a canonical run
+// ABOUTME: Compares a simulation against a stored fixture and lists values outside tolerance
+
+use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::physics_grid::Float;
+use crate::engine::physics::{DiamondSquareConfig, DiamondSquareGenerator, TerrainGenerator};
+use crate::engine::sim::Simulation;
+use serde::{Deserialize, Serialize};
+
+/// Cells sampled per axis; samples are spread evenly from edge to edge
+const SAMPLES_PER_AXIS: usize = 4;
+
+/// Layers captured in a fixture, in capture order
+const FIXTURE_LAYERS: [&str; 5] = [
+    "elevation",
+    "water_depth",
+    "temperature",
+    "pressure",
+    "wind_speed",
+];
+
+/// Fingerprint of one layer: a whole-field checksum plus exact values at sampled cells
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayerFingerprint {
+    pub layer: String,
+    /// Sum of all cell values, accumulated in f64
+    pub checksum: f64,
+    /// (x, y, value) at the sampled cells
+    pub samples: Vec<(usize, usize, f32)>,
+}
+
+/// Expected layer state after running a canonical simulation for a number of ticks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fixture {
+    pub seed: u64,
+    pub size: (usize, usize),
+    pub ticks: u64,
+    pub layers: Vec<LayerFingerprint>,
+}
+
+/// A fixture value the simulation no longer reproduces within tolerance
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    pub layer: String,
+    /// Sampled cell, or None for the layer checksum
+    pub cell: Option<(usize, usize)>,
+    pub expected: f64,
+    pub actual: f64,
+}
+
+/// Canonical simulation for fixtures: Diamond-Square terrain from the seed with default settings
+pub fn fixture_simulation(seed: u64, size: (usize, usize)) -> Simulation {
+    let heightmap: HeightMap =
+        DiamondSquareGenerator::new(seed).generate(size.0, size.1, &DiamondSquareConfig::default());
+    Simulation::new(heightmap)
+}
+
+/// Run the canonical simulation for `ticks` and record its fingerprint
+pub fn generate_fixture(seed: u64, size: (usize, usize), ticks: u64) -> Fixture {
+    let mut simulation = fixture_simulation(seed, size);
+    for _ in 0..ticks {
+        simulation.tick();
+    }
+    Fixture {
+        seed,
+        size,
+        ticks,
+        layers: FIXTURE_LAYERS
+            .iter()
+            .map(|&layer| fingerprint(&simulation, layer))
+            .collect(),
+    }
+}
+
+/// Value of a fixture layer at a cell
+fn layer_value(simulation: &Simulation, layer: &str, x: usize, y: usize) -> f32 {
+    match layer {
+        "elevation" => simulation.heightmap.get(x, y),
+        "water_depth" => simulation.water.depth.get(x, y).to_f32(),
+        "temperature" => simulation.temperature_layer.get_temperature(x, y),
+        "pressure" => simulation.pressure_layer.get_pressure(x, y),
+        "wind_speed" => simulation.wind_layer.velocity.get(x, y).magnitude(),
+        _ => f32::NAN,
+    }
+}
+
+/// Evenly spaced sample coordinates along an axis of `length` cells
+fn sample_positions(length: usize) -> Vec<usize> {
+    if length <= SAMPLES_PER_AXIS {
+        return (0..length).collect();
+    }
+    (0..SAMPLES_PER_AXIS)
+        .map(|i| i * (length - 1) / (SAMPLES_PER_AXIS - 1))
+        .collect()
+}
+
+fn fingerprint(simulation: &Simulation, layer: &str) -> LayerFingerprint {
+    let (width, height) = (simulation.get_width(), simulation.get_height());
+    let mut checksum = 0.0;
+    for y in 0..height {
+        for x in 0..width {
+            checksum += layer_value(simulation, layer, x, y) as f64;
+        }
+    }
+
+    let mut samples = Vec::new();
+    for &y in &sample_positions(height) {
+        for &x in &sample_positions(width) {
+            samples.push((x, y, layer_value(simulation, layer, x, y)));
+        }
+    }
+
+    LayerFingerprint {
+        layer: layer.to_string(),
+        checksum,
+        samples,
+    }
+}
+
+/// Whether two values agree within `tolerance`, relative for magnitudes above 1
+fn within_tolerance(expected: f64, actual: f64, tolerance: f64) -> bool {
+    (expected - actual).abs() <= tolerance * expected.abs().max(1.0)
+}
+
+impl Fixture {
+    /// Compare a simulation against this fixture
+    /// The tolerance is absolute for values up to 1 and relative above; an empty result is a pass
+    pub fn compare(&self, simulation: &Simulation, tolerance: f64) -> Vec<Mismatch> {
+        if (simulation.get_width(), simulation.get_height()) != self.size {
+            return vec![Mismatch {
+                layer: "size".to_string(),
+                cell: None,
+                expected: (self.size.0 * self.size.1) as f64,
+                actual: (simulation.get_width() * simulation.get_height()) as f64,
+            }];
+        }
+
+        let mut mismatches = Vec::new();
+        for expected in &self.layers {
+            let actual = fingerprint(simulation, &expected.layer);
+            if !within_tolerance(expected.checksum, actual.checksum, tolerance) {
+                mismatches.push(Mismatch {
+                    layer: expected.layer.clone(),
+                    cell: None,
+                    expected: expected.checksum,
+                    actual: actual.checksum,
+                });
+            }
+            for &(x, y, value) in &expected.samples {
+                let actual = layer_value(simulation, &expected.layer, x, y) as f64;
+                if !within_tolerance(value as f64, actual, tolerance) {
+                    mismatches.push(Mismatch {
+                        layer: expected.layer.clone(),
+                        cell: Some((x, y)),
+                        expected: value as f64,
+                        actual,
+                    });
+                }
+            }
+        }
+        mismatches
+    }
+
+    /// Re-run the fixture's canonical inputs and compare the result
+    pub fn verify(&self, tolerance: f64) -> Vec<Mismatch> {
+        let mut simulation = fixture_simulation(self.seed, self.size);
+        for _ in 0..self.ticks {
+            simulation.tick();
+        }
+        self.compare(&simulation, tolerance)
+    }
+
+    /// Write the fixture as pretty-printed JSON for committing alongside tests
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn load(path: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Committed fingerprint of the canonical run; regenerate with
+    /// `cargo test --lib regenerate_committed_fixture -- --ignored` after intended physics changes
+    const COMMITTED_FIXTURE: &str = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/regression_seed1234_24x16_8ticks.json"
+    );
+
+    #[test]
+    fn committed_fixture_matches_fresh_run() {
+        let fixture = Fixture::load(COMMITTED_FIXTURE).expect("committed fixture should load");
+        assert_eq!(
+            (fixture.seed, fixture.size, fixture.ticks),
+            (1234, (24, 16), 8)
+        );
+
+        let mut simulation = fixture_simulation(fixture.seed, fixture.size);
+        for _ in 0..fixture.ticks {
+            simulation.tick();
+        }
+        let mismatches = fixture.compare(&simulation, 1e-5);
+        assert!(mismatches.is_empty(), "regression: {:?}", mismatches);
+    }
+
+    #[test]
+    #[ignore = "rewrites the committed fixture"]
+    fn regenerate_committed_fixture() {
+        generate_fixture(1234, (24, 16), 8)
+            .save(COMMITTED_FIXTURE)
+            .unwrap();
+    }
+
+    #[test]
+    fn regenerated_fixture_has_no_mismatches() {
+        let fixture = generate_fixture(1234, (24, 16), 8);
+        assert_eq!(fixture.layers.len(), FIXTURE_LAYERS.len());
+        assert_eq!(fixture.layers[0].samples.len(), 16);
+
+        assert!(fixture.verify(0.0).is_empty());
+
+        let mut simulation = fixture_simulation(1234, (24, 16));
+        for _ in 0..8 {
+            simulation.tick();
+        }
+        simulation
+            .heightmap
+            .set(0, 0, simulation.heightmap.get(0, 0) + 0.5);
+        let mismatches = fixture.compare(&simulation, 1e-6);
+        assert!(
+            mismatches
+                .iter()
+                .any(|m| m.layer == "elevation" && m.cell == Some((0, 0)))
+        );
+        assert!(
+            mismatches
+                .iter()
+                .any(|m| m.layer == "elevation" && m.cell.is_none())
+        );
+    }
+}
@@ -4,10 +4,12 @@
 // ABOUTME: Water flow physics diagnostic framework for real-time validation of hydrodynamics
 // ABOUTME: Comprehensive validation of mass conservation, CFL stability, and physics quality metrics
 
-use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::heightmap::{HeightMap, METERS_PER_ELEVATION_UNIT};
+use crate::engine::core::physics_grid::Float;
 use crate::engine::core::scale::WorldScale;
+use crate::engine::physics::flow_engine::FlowParameters;
 use crate::engine::physics::water::WaterLayer;
-use crate::engine::sim::WaterFlowSystem;
+use crate::engine::sim::{Simulation, WaterFlowSystem};
 
 /// Safety parameters derived from SageMath mathematical analysis
 /// Following successful atmospheric physics pattern (F_THRESHOLD → 99.6% improvement)
@@ -42,6 +44,14 @@ pub mod safety_parameters {
     pub const VELOCITY_WARNING_THRESHOLD: f32 = 8.0; // 80% of max realistic
     pub const CFL_WARNING_THRESHOLD: f32 = 0.8; // CFL ratio warning
     pub const MASS_ERROR_WARNING: f32 = 1e-5; // 10x tolerance warning
+
+    /// Relative error tolerance for steady-state profile comparison against Manning's equation
+    pub const STEADY_STATE_TOLERANCE: f32 = 0.1;
+
+    /// Fraction of the slope below the divide left out of the steady-state pass check
+    /// The analytic depth and velocity vanish at the divide while a first-order solver
+    /// still carries a diffusive flux there, so relative errors are ill-conditioned
+    pub const DIVIDE_LAYER_FRACTION: f32 = 0.2;
 }
 
 /// Comprehensive water flow validation results
@@ -442,6 +452,216 @@ impl WaterFlowDiagnostics {
     }
 }
 
+/// Per-row comparison of simulated and analytic steady-state flow on a tilted plane
+#[derive(Debug, Clone)]
+pub struct RowProfileComparison {
+    pub row: usize,
+    pub flow_length_m: f32, // Upslope contributing length to this row's centre
+    pub simulated_depth_m: f32, // Row-mean simulated depth
+    pub analytic_depth_m: f32, // Manning kinematic-wave depth
+    pub simulated_velocity_ms: f32, // Row-mean simulated speed
+    pub analytic_velocity_ms: f32, // Manning kinematic-wave velocity
+    pub depth_relative_error: f32,
+    pub velocity_relative_error: f32,
+    pub in_divide_layer: bool, // Near the divide; reported but not judged
+    pub exceeds_tolerance: bool,
+}
+
+/// Steady-state validation report against the Manning-equation analytic solution
+#[derive(Debug, Clone)]
+pub struct ValidationReport {
+    pub slope: f32,            // Bed slope S (m/m)
+    pub manning_n: f32,        // Roughness coefficient used for the analytic solution
+    pub rainfall_rate_ms: f32, // Rainfall excess (m/s) driving the steady state
+    pub tolerance: f32,
+    pub rows: Vec<RowProfileComparison>,
+    pub max_depth_error: f32,
+    pub max_velocity_error: f32,
+    pub passed: bool,
+}
+
+impl ValidationReport {
+    /// Rows outside the divide layer whose depth or velocity error exceeds the tolerance
+    pub fn failing_rows(&self) -> Vec<usize> {
+        self.rows
+            .iter()
+            .filter(|row| row.exceeds_tolerance)
+            .map(|row| row.row)
+            .collect()
+    }
+}
+
+impl WaterFlowValidation {
+    /// One-call physical sanity check of steady-state overland flow
+    ///
+    /// Assumes the simulation holds a plane tilted along y under constant rainfall.
+    /// At steady state, continuity gives unit discharge q = r·L at distance L from the
+    /// upslope edge, and Manning's equation q = (1/n)·h^(5/3)·S^(1/2) gives
+    /// h = (n·q / √S)^(3/5) and v = q / h. Simulated row-mean depth and speed are
+    /// compared against these profiles. Rows within `DIVIDE_LAYER_FRACTION` of the slope
+    /// below the divide are reported but do not count toward `passed`.
+    pub fn steady_state_report(simulation: &Simulation) -> ValidationReport {
+        Self::steady_state_report_with_tolerance(
+            simulation,
+            safety_parameters::STEADY_STATE_TOLERANCE,
+        )
+    }
+
+    /// Steady-state report with an explicit relative error tolerance
+    pub fn steady_state_report_with_tolerance(
+        simulation: &Simulation,
+        tolerance: f32,
+    ) -> ValidationReport {
+        Self::steady_state_report_from_layers(
+            &simulation.heightmap,
+            &simulation.water,
+            simulation.get_world_scale().meters_per_pixel() as f32,
+            Self::steady_state_rainfall_rate(simulation),
+            FlowParameters::default().roughness,
+            tolerance,
+        )
+    }
+
+    /// Steady-state report for water on a tilted plane, independent of any simulation
+    /// `rainfall_rate_ms` is the rainfall excess (m/s) that drove the flow to steady state
+    pub fn steady_state_report_from_layers(
+        heightmap: &HeightMap,
+        water: &WaterLayer,
+        dx: f32,
+        rainfall_rate_ms: f32,
+        manning_n: f32,
+        tolerance: f32,
+    ) -> ValidationReport {
+        let width = heightmap.width();
+        let height = heightmap.height();
+
+        // Mean bed slope along y; the higher edge is the upslope boundary
+        let top_mean = (0..width).map(|x| heightmap.get(x, 0)).sum::<f32>() / width as f32;
+        let bottom_mean = (0..width)
+            .map(|x| heightmap.get(x, height - 1))
+            .sum::<f32>()
+            / width as f32;
+        let run_m = (height.max(2) - 1) as f32 * dx;
+        let slope = (top_mean - bottom_mean).abs() * METERS_PER_ELEVATION_UNIT / run_m;
+        let downslope_increasing_y = top_mean >= bottom_mean;
+
+        let mut rows = Vec::with_capacity(height);
+        let mut max_depth_error = 0.0f32;
+        let mut max_velocity_error = 0.0f32;
+
+        for y in 0..height {
+            let cells_from_divide = if downslope_increasing_y {
+                y
+            } else {
+                height - 1 - y
+            };
+            let flow_length_m = (cells_from_divide as f32 + 0.5) * dx;
+            let (analytic_depth_m, analytic_velocity_ms) =
+                manning_steady_state(rainfall_rate_ms, flow_length_m, slope, manning_n);
+
+            let mut depth_sum = 0.0;
+            let mut speed_sum = 0.0;
+            for x in 0..width {
+                let (u, v) = water.velocity.get(x, y);
+                depth_sum += water.depth.get(x, y).to_f32();
+                speed_sum += (u * u + v * v).sqrt();
+            }
+            let simulated_depth_m = depth_sum / width as f32;
+            let simulated_velocity_ms = speed_sum / width as f32;
+
+            let depth_relative_error = relative_error(simulated_depth_m, analytic_depth_m);
+            let velocity_relative_error =
+                relative_error(simulated_velocity_ms, analytic_velocity_ms);
+            let in_divide_layer = (cells_from_divide as f32)
+                < safety_parameters::DIVIDE_LAYER_FRACTION * height as f32;
+            let exceeds_tolerance = !in_divide_layer
+                && (depth_relative_error > tolerance || velocity_relative_error > tolerance);
+
+            if !in_divide_layer {
+                max_depth_error = max_depth_error.max(depth_relative_error);
+                max_velocity_error = max_velocity_error.max(velocity_relative_error);
+            }
+
+            rows.push(RowProfileComparison {
+                row: y,
+                flow_length_m,
+                simulated_depth_m,
+                analytic_depth_m,
+                simulated_velocity_ms,
+                analytic_velocity_ms,
+                depth_relative_error,
+                velocity_relative_error,
+                in_divide_layer,
+                exceeds_tolerance,
+            });
+        }
+
+        let passed = slope > 0.0 && rows.iter().all(|row| !row.exceeds_tolerance);
+
+        ValidationReport {
+            slope,
+            manning_n,
+            rainfall_rate_ms,
+            tolerance,
+            rows,
+            max_depth_error,
+            max_velocity_error,
+            passed,
+        }
+    }
+
+    /// Rainfall excess in m/s as applied by the simulation's water system
+    /// Each water update adds effective_rainfall_rate × temporal_factor over one CFL timestep
+    pub fn steady_state_rainfall_rate(simulation: &Simulation) -> f32 {
+        let water_system = simulation.get_water_system();
+        let temporal_factor = simulation
+            .get_world_scale()
+            .temporal_scale
+            .temporal_factor() as f32;
+        let dt_seconds = water_system
+            ._get_stable_timestep_seconds()
+            .max(f32::EPSILON);
+        water_system.effective_rainfall_rate * temporal_factor / dt_seconds
+    }
+
+    /// Build a plane tilted along y that drops `drop_per_row` (m) per row
+    pub fn tilted_plane_heightmap(width: usize, height: usize, drop_per_row: f32) -> HeightMap {
+        let mut heightmap = HeightMap::new(width, height, 0.0);
+        let drop_per_row = drop_per_row / METERS_PER_ELEVATION_UNIT;
+        let top_elevation = drop_per_row * height as f32;
+        for y in 0..height {
+            for x in 0..width {
+                heightmap.set(x, y, top_elevation - drop_per_row * y as f32);
+            }
+        }
+        heightmap
+    }
+}
+
+/// Manning kinematic-wave steady state (depth m, velocity m/s) for rainfall excess
+/// `rainfall_rate_ms` over flow length `flow_length_m` on slope `slope`
+pub fn manning_steady_state(
+    rainfall_rate_ms: f32,
+    flow_length_m: f32,
+    slope: f32,
+    manning_n: f32,
+) -> (f32, f32) {
+    let unit_discharge = rainfall_rate_ms * flow_length_m; // m²/s
+    if unit_discharge <= 0.0 || slope <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let depth = (manning_n * unit_discharge / slope.sqrt()).powf(3.0 / 5.0);
+    (depth, unit_discharge / depth)
+}
+
+fn relative_error(simulated: f32, analytic: f32) -> f32 {
+    if analytic.abs() > f32::EPSILON {
+        (simulated - analytic).abs() / analytic.abs()
+    } else {
+        simulated.abs()
+    }
+}
+
 /// Convenience function for quick water flow validation during development
 pub fn validate_water_flow_quick(
     world_scale: &WorldScale,
@@ -457,7 +677,10 @@ pub fn validate_water_flow_quick(
 mod tests {
     use super::*;
     use crate::engine::core::heightmap::HeightMap;
+    use crate::engine::core::math::Vec2;
+    use crate::engine::core::physics_grid::StateFloat;
     use crate::engine::core::scale::{DetailLevel, WorldScale};
+    use crate::engine::physics::flow_engine::{FlowAlgorithm, FlowEngine};
     use crate::engine::sim::WaterFlowParameters;
 
     fn create_test_setup() -> (WorldScale, WaterFlowSystem, HeightMap, WaterLayer) {
@@ -559,4 +782,124 @@ mod tests {
         assert!(report.contains("BOUNDARY FLUX"));
         assert!(report.contains("SCALE CONSISTENCY"));
     }
+
+    fn tilted_plane_simulation() -> Simulation {
+        // Gentle 1% slope: 1 m drop per 100 m row
+        let heightmap = WaterFlowValidation::tilted_plane_heightmap(20, 30, 1.0);
+        let scale = WorldScale::new(3.0, (20, 30), DetailLevel::Standard);
+        Simulation::_new_with_scale(heightmap, scale)
+    }
+
+    fn impose_manning_profile(simulation: &mut Simulation) {
+        let rainfall = WaterFlowValidation::steady_state_rainfall_rate(simulation);
+        let dx = simulation.get_world_scale().meters_per_pixel() as f32;
+        let n = FlowParameters::default().roughness;
+        for y in 0..simulation.water.height() {
+            let (depth, velocity) = manning_steady_state(rainfall, (y as f32 + 0.5) * dx, 0.01, n);
+            for x in 0..simulation.water.width() {
+                simulation
+                    .water
+                    .depth
+                    .set(x, y, StateFloat::from_f32(depth));
+                simulation.water.velocity.set(x, y, (0.0, velocity));
+            }
+        }
+    }
+
+    #[test]
+    fn steady_state_report_passes_on_shallow_water_run_on_gentle_slope() {
+        // 0.5% slope over 300 rows of 25 cm cells, under 360 mm/h of rainfall excess
+        let (width, height, rainfall, slope) = (1, 300, 1e-4, 0.005);
+        let scale = WorldScale::new(0.075, (width as u32, height as u32), DetailLevel::Standard);
+        let dx = scale.meters_per_pixel() as f32;
+        let heightmap = WaterFlowValidation::tilted_plane_heightmap(width, height, slope * dx);
+        let mut engine = FlowEngine::new(FlowAlgorithm::ShallowWater, width, height, &scale);
+        engine.parameters.dt = 0.125;
+        let n = engine.parameters.roughness;
+
+        // The flow engine works on elevations in metres
+        let mut terrain = heightmap.clone();
+        for elevation in terrain.data_mut() {
+            *elevation *= METERS_PER_ELEVATION_UNIT;
+        }
+
+        // Rain on every cell with a normal-depth outflow row at the downslope edge,
+        // until storage settles
+        let outlet_length = (height as f32 - 0.5) * dx;
+        let (outlet_depth, outlet_velocity) =
+            manning_steady_state(rainfall, outlet_length, slope, n);
+        let storage = |water: &WaterLayer| water.depth.data().iter().map(|&d| d as f64).sum();
+        let mut water = WaterLayer::new(width, height);
+        let mut previous_storage = -1.0;
+        let mut settled = false;
+        for _ in 0..100 {
+            for _ in 0..500 {
+                for depth in water.depth.data_mut() {
+                    *depth += StateFloat::from_f32(rainfall * engine.parameters.dt);
+                }
+                engine.calculate_flow(&terrain, &mut water, None, &scale);
+                for x in 0..width {
+                    water
+                        .depth
+                        .set(x, height - 1, StateFloat::from_f32(outlet_depth));
+                    water.velocity.set(x, height - 1, (0.0, outlet_velocity));
+                    engine.velocity_field.set_velocity(
+                        x,
+                        height - 1,
+                        Vec2::new(0.0, outlet_velocity),
+                    );
+                }
+            }
+            let current: f64 = storage(&water);
+            if (current - previous_storage).abs() < 1e-6 * current {
+                settled = true;
+                break;
+            }
+            previous_storage = current;
+        }
+        assert!(settled, "flow did not reach steady state");
+
+        let report = WaterFlowValidation::steady_state_report_from_layers(
+            &heightmap,
+            &water,
+            dx,
+            rainfall,
+            n,
+            safety_parameters::STEADY_STATE_TOLERANCE,
+        );
+        assert!(
+            (report.slope - slope).abs() < 1e-6,
+            "slope {}",
+            report.slope
+        );
+        assert!(
+            report.passed,
+            "rows {:?} exceed tolerance; max depth error {}, max velocity error {}",
+            report.failing_rows(),
+            report.max_depth_error,
+            report.max_velocity_error
+        );
+        // The divide layer is reported but not judged
+        assert!(report.rows[0].in_divide_layer && !report.rows[0].exceeds_tolerance);
+        assert!(!report.rows[height / 2].in_divide_layer);
+    }
+
+    #[test]
+    fn steady_state_report_flags_rows_beyond_tolerance() {
+        let mut simulation = tilted_plane_simulation();
+        impose_manning_profile(&mut simulation);
+        for x in 0..simulation.water.width() {
+            let depth = simulation.water.depth.get(x, 10).to_f32();
+            simulation
+                .water
+                .depth
+                .set(x, 10, StateFloat::from_f32(depth * 2.0));
+        }
+
+        let report = WaterFlowValidation::steady_state_report(&simulation);
+
+        assert!(!report.passed);
+        assert_eq!(report.failing_rows(), vec![10]);
+        assert!((report.rows[10].depth_relative_error - 1.0).abs() < 1e-3);
+    }
 }
@@ -13,6 +13,9 @@ pub mod rendering;
 
 // Main simulation struct - keep at engine level
 pub mod sim;
-pub use config::WorkspaceConfig;
+pub use config::{ConfigError, WorkspaceConfig};
 pub use diagnostics::{SimulationDiagnostics, WaterFlowDiagnostics, WaterFlowValidation};
-pub use sim::{RainfallScaling, Simulation, WaterFlowParameters, WaterFlowSystem};
+pub use sim::{
+    AtmosphericUpdateSchedule, CheckpointError, DrainageRecord, MetricsRecorder, RainfallScaling,
+    SimEvent, Simulation, Subdomain, Subsystem, WaterBudget, WaterFlowParameters, WaterFlowSystem,
+};
@@ -5,16 +5,22 @@
 // ABOUTME: Implements geostrophic wind patterns, pressure-driven flows, and rotating reference frame physics
 
 use super::super::core::PhysicsGrid;
+use super::super::core::planet::{
+    EARTH_GRAVITY, EARTH_RADIUS_KM, EARTH_ROTATION_RATE, PlanetConfig,
+};
 use super::super::core::scale::{ScaleAware, WorldScale};
-use super::climate::AtmosphericPressureLayer;
+use super::climate::{AtmosphericPressureLayer, TemperatureLayer};
 use super::water::Vec2;
+use serde::{Deserialize, Serialize};
 
 /// ScaleAware coordinate mapping parameters for atmospheric physics
 /// Replaces hardcoded thresholds with proper scale-derived values
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CoordinateMappingParameters {
     /// Latitude range in degrees that the domain spans
     pub latitude_range_degrees: f64,
+    /// Latitude range the domain spans on an Earth-sized planet, rescaled by `set_planet`
+    pub earth_latitude_range_degrees: f64,
     /// Center latitude in degrees where the domain is positioned
     pub center_latitude_degrees: f64,
     /// Base momentum conservation threshold per cell (m/s)
@@ -31,6 +37,7 @@ impl Default for CoordinateMappingParameters {
             center_latitude_degrees: 45.0, // 45°N center (realistic for most landmasses)
             momentum_threshold_base: 10.0, // 10 m/s base momentum per cell
             momentum_scaling_factor: 1.0,  // Linear scaling baseline
+            earth_latitude_range_degrees: 10.0,
         }
     }
 }
@@ -78,6 +85,7 @@ impl ScaleAware for CoordinateMappingParameters {
 
         Self {
             latitude_range_degrees: latitude_range,
+            earth_latitude_range_degrees: latitude_range,
             center_latitude_degrees: center_lat,
             momentum_threshold_base: self.momentum_threshold_base,
             momentum_scaling_factor: self.momentum_scaling_factor
@@ -88,10 +96,14 @@ impl ScaleAware for CoordinateMappingParameters {
 }
 
 /// Atmospheric dynamics parameters for large-scale flow effects
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AtmosphericParameters {
-    /// Earth's rotation rate in rad/s (Ω = 7.27×10⁻⁵ rad/s)
+    /// Planetary rotation rate in rad/s (Earth: Ω = 7.27×10⁻⁵ rad/s)
     pub earth_rotation_rate: f64,
+    /// Surface gravity in m/s²
+    pub gravity: f32,
+    /// Planetary radius in km, relating domain extent to latitude span
+    pub planet_radius_km: f64,
     /// Air density at sea level in kg/m³
     pub air_density_sea_level: f32,
     /// Minimum domain size for Coriolis effects to activate (meters)
@@ -107,7 +119,9 @@ pub struct AtmosphericParameters {
 impl Default for AtmosphericParameters {
     fn default() -> Self {
         Self {
-            earth_rotation_rate: 7.27e-5, // Earth's rotation rate (rad/s)
+            earth_rotation_rate: EARTH_ROTATION_RATE,
+            gravity: EARTH_GRAVITY,
+            planet_radius_km: EARTH_RADIUS_KM,
             air_density_sea_level: 1.225, // Standard air density at sea level (kg/m³)
             coriolis_activation_threshold_m: 100_000.0, // 100km threshold for Coriolis effects
             geostrophic_strength: 1.0,    // Full geostrophic balance
@@ -124,6 +138,8 @@ impl ScaleAware for AtmosphericParameters {
         Self {
             // Physical constants don't scale
             earth_rotation_rate: self.earth_rotation_rate,
+            gravity: self.gravity,
+            planet_radius_km: self.planet_radius_km,
             air_density_sea_level: self.air_density_sea_level,
 
             // Activation threshold remains constant
@@ -155,7 +171,7 @@ impl ScaleAware for AtmosphericParameters {
 }
 
 /// Wind field data layer
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WindLayer {
     /// Wind velocity vector (u, v) in m/s at each cell - PhysicsGrid for 2-3x performance with vector fields
     pub velocity: PhysicsGrid<Vec2>,
@@ -868,7 +884,7 @@ pub struct AtmosphericValidation {
 }
 
 /// Weather pattern types detected in the simulation
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum WeatherPatternType {
     /// Low pressure system (cyclone/depression)
     LowPressureSystem,
@@ -881,7 +897,7 @@ pub enum WeatherPatternType {
 }
 
 /// Detected weather pattern with location and characteristics
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WeatherPattern {
     /// Type of weather pattern
     pub pattern_type: WeatherPatternType,
@@ -897,11 +913,24 @@ pub struct WeatherPattern {
     pub radius: usize,
 }
 
+/// Frontal zone: a sharp temperature gradient with a wind shift across it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Front {
+    /// Cells along the front, ordered from one end to the other
+    pub points: Vec<(usize, usize)>,
+    /// Strongest temperature gradient on the front (K/km)
+    pub max_gradient: f32,
+    /// Mean change in wind direction across the front (degrees)
+    pub wind_shift_degrees: f32,
+}
+
 /// Weather analysis system for pattern detection
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WeatherAnalysis {
     /// Detected weather patterns
     pub patterns: Vec<WeatherPattern>,
+    /// Detected fronts
+    pub fronts: Vec<Front>,
     /// Vorticity field for the entire domain
     pub vorticity_field: Vec<Vec<f32>>,
     /// Storm detection thresholds
@@ -909,22 +938,177 @@ pub struct WeatherAnalysis {
     pub high_pressure_threshold: f32, // Pa above average for high pressure systems
     pub vorticity_threshold: f32,     // 1/s threshold for significant rotation
     pub wind_speed_threshold: f32,    // m/s threshold for strong winds
+    pub front_gradient_threshold: f32, // K/km temperature gradient marking a frontal zone
+    pub front_wind_shift_threshold: f32, // Degrees of wind veer/back required across a front
 }
 
 impl Default for WeatherAnalysis {
     fn default() -> Self {
         Self {
             patterns: Vec::new(),
+            fronts: Vec::new(),
             vorticity_field: Vec::new(),
             low_pressure_threshold: 200.0, // 2 hPa below average (more realistic)
             high_pressure_threshold: 200.0, // 2 hPa above average (more realistic)
             vorticity_threshold: 5e-5,     // 5×10⁻⁵ s⁻¹ (reduced for stability)
             wind_speed_threshold: 5.0,     // 5 m/s (moderate breeze)
+            front_gradient_threshold: 0.05, // 5 K per 100 km
+            front_wind_shift_threshold: 30.0, // 30° direction change
+        }
+    }
+}
+
+/// Fronts shorter than this many cells are treated as noise
+const MIN_FRONT_CELLS: usize = 3;
+
+/// Roughness length of open grassland, the surface the wind field is calibrated for (m)
+const REFERENCE_ROUGHNESS_LENGTH_M: f32 = 0.03;
+/// Height of the reported surface wind (m)
+const SURFACE_WIND_HEIGHT_M: f32 = 10.0;
+/// Height above which surface roughness no longer affects the flow (m)
+const BLENDING_HEIGHT_M: f32 = 100.0;
+
+impl WeatherAnalysis {
+    /// Find fronts: runs of cells whose temperature gradient exceeds `front_gradient_threshold`
+    /// and whose wind direction changes by at least `front_wind_shift_threshold` across the
+    /// gradient. Connected frontal cells are traced into one polyline per front, keeping the
+    /// strongest-gradient cell at each station along the front.
+    pub fn detect_fronts(
+        &self,
+        temperature: &TemperatureLayer,
+        wind: &WindLayer,
+        scale: &WorldScale,
+    ) -> Vec<Front> {
+        let width = temperature.width().min(wind.width());
+        let height = temperature.height().min(wind.height());
+        if width < 3 || height < 3 {
+            return Vec::new();
+        }
+        let km_x = scale.meters_per_pixel_x() as f32 / 1000.0;
+        let km_y = scale.meters_per_pixel_y() as f32 / 1000.0;
+
+        // Interior cells meeting both criteria, with (gradient, unit gradient, wind shift)
+        let mut candidates = PhysicsGrid::new(width, height, None);
+        for y in 1..height - 1 {
+            for x in 1..width - 1 {
+                let dt_dx = (temperature.get_temperature(x + 1, y)
+                    - temperature.get_temperature(x - 1, y))
+                    / (2.0 * km_x);
+                let dt_dy = (temperature.get_temperature(x, y + 1)
+                    - temperature.get_temperature(x, y - 1))
+                    / (2.0 * km_y);
+                let gradient = dt_dx.hypot(dt_dy);
+                if gradient < self.front_gradient_threshold {
+                    continue;
+                }
+
+                // Compare the wind one cell either side, stepping along the gradient
+                let step_x = (dt_dx / gradient).round() as i32;
+                let step_y = (dt_dy / gradient).round() as i32;
+                let before =
+                    wind.get_velocity((x as i32 - step_x) as usize, (y as i32 - step_y) as usize);
+                let after =
+                    wind.get_velocity((x as i32 + step_x) as usize, (y as i32 + step_y) as usize);
+                if before.magnitude() <= 0.0 || after.magnitude() <= 0.0 {
+                    continue;
+                }
+                let turn = (after.y.atan2(after.x) - before.y.atan2(before.x))
+                    .rem_euclid(std::f32::consts::TAU);
+                let shift = turn.min(std::f32::consts::TAU - turn).to_degrees();
+                if shift >= self.front_wind_shift_threshold {
+                    candidates.set(
+                        x,
+                        y,
+                        Some((gradient, dt_dx / gradient, dt_dy / gradient, shift)),
+                    );
+                }
+            }
+        }
+
+        let mut fronts = Vec::new();
+        let mut visited = PhysicsGrid::new(width, height, false);
+        for start_y in 0..height {
+            for start_x in 0..width {
+                if *visited.get(start_x, start_y) || candidates.get(start_x, start_y).is_none() {
+                    continue;
+                }
+                visited.set(start_x, start_y, true);
+                let mut cells = vec![(start_x, start_y)];
+                let mut cursor = 0;
+                while cursor < cells.len() {
+                    let (x, y) = cells[cursor];
+                    cursor += 1;
+                    for ny in y.saturating_sub(1)..=(y + 1).min(height - 1) {
+                        for nx in x.saturating_sub(1)..=(x + 1).min(width - 1) {
+                            if !*visited.get(nx, ny) && candidates.get(nx, ny).is_some() {
+                                visited.set(nx, ny, true);
+                                cells.push((nx, ny));
+                            }
+                        }
+                    }
+                }
+                if cells.len() < MIN_FRONT_CELLS {
+                    continue;
+                }
+
+                // The front runs perpendicular to the mean gradient direction
+                let (mut sum_x, mut sum_y, mut max_gradient, mut total_shift) =
+                    (0.0, 0.0, 0.0f32, 0.0);
+                for &(x, y) in &cells {
+                    let (gradient, ux, uy, shift) = candidates.get(x, y).unwrap();
+                    sum_x += ux;
+                    sum_y += uy;
+                    max_gradient = max_gradient.max(gradient);
+                    total_shift += shift;
+                }
+                let (along_x, along_y) = (-sum_y, sum_x);
+
+                // One cell per station along the front: the strongest gradient there
+                let mut stations: Vec<(i32, (usize, usize), f32)> = Vec::new();
+                for &(x, y) in &cells {
+                    let station = ((x as f32 * along_x + y as f32 * along_y)
+                        / along_x.hypot(along_y).max(f32::EPSILON))
+                    .round() as i32;
+                    let gradient = candidates.get(x, y).unwrap().0;
+                    match stations.iter_mut().find(|entry| entry.0 == station) {
+                        Some(entry) if gradient > entry.2 => *entry = (station, (x, y), gradient),
+                        Some(_) => {}
+                        None => stations.push((station, (x, y), gradient)),
+                    }
+                }
+                if stations.len() < MIN_FRONT_CELLS {
+                    continue;
+                }
+                stations.sort_by_key(|entry| entry.0);
+
+                fronts.push(Front {
+                    points: stations.into_iter().map(|entry| entry.1).collect(),
+                    max_gradient,
+                    wind_shift_degrees: total_shift / cells.len() as f32,
+                });
+            }
         }
+        fronts
     }
 }
 
+/// How the Coriolis parameter varies across the domain
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum CoriolisModel {
+    /// No rotation: geostrophic winds vanish
+    Off,
+    /// f = 2Ω sin(φ) at each row's mapped latitude
+    #[default]
+    Spherical,
+    /// Constant f evaluated at a fixed latitude (degrees)
+    FPlane(f64),
+    /// f = f₀ + β·y about a reference latitude (degrees)
+    /// `df_dy` is β in 1/(m·s); y is the northward distance from the domain center
+    BetaPlane { lat: f64, df_dy: f64 },
+}
+
 /// Atmospheric dynamics system for large-scale flow effects#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize)]
 pub struct AtmosphericSystem {
     /// Scale-derived atmospheric parameters
     pub parameters: AtmosphericParameters,
@@ -934,6 +1118,11 @@ pub struct AtmosphericSystem {
     pub effective_coriolis_parameter: f64,
     /// World scale context for proper latitude calculations
     pub world_scale: WorldScale,
+    /// Latitude dependence of the Coriolis parameter used for geostrophic winds
+    pub coriolis_model: CoriolisModel,
+    /// Per-cell aerodynamic roughness length z₀ (m) slowing surface wind (None = open terrain)
+    #[serde(default)]
+    pub surface_roughness: Option<PhysicsGrid<f32>>,
 }
 
 impl AtmosphericSystem {
@@ -953,6 +1142,8 @@ impl AtmosphericSystem {
             coriolis_active,
             effective_coriolis_parameter,
             world_scale: scale.clone(),
+            coriolis_model: CoriolisModel::default(),
+            surface_roughness: None,
         }
     }
 
@@ -971,15 +1162,70 @@ impl AtmosphericSystem {
             coriolis_active,
             effective_coriolis_parameter,
             world_scale: scale.clone(),
+            coriolis_model: CoriolisModel::default(),
+            surface_roughness: None,
         }
     }
 
+    /// Adopt a planet's rotation rate, gravity and radius
+    /// A smaller radius widens the latitude band the domain spans, up to pole to pole
+    /// The band is rescaled from its Earth-radius span, so switching planets back restores it.
+    pub fn set_planet(&mut self, planet: &PlanetConfig) {
+        let radius_ratio = EARTH_RADIUS_KM / planet.radius_km.max(f64::EPSILON);
+        let mapping = &mut self.parameters.coordinate_mapping;
+        mapping.latitude_range_degrees =
+            (mapping.earth_latitude_range_degrees * radius_ratio).min(180.0);
+
+        self.parameters.earth_rotation_rate = planet.rotation_rate;
+        self.parameters.gravity = planet.gravity;
+        self.parameters.planet_radius_km = planet.radius_km;
+        self.effective_coriolis_parameter =
+            self.coriolis_parameter_at_latitude(std::f64::consts::PI / 4.0);
+    }
+
     /// Calculate Coriolis parameter at a given latitude
     /// f = 2Ω sin(φ) where φ is latitude in radians
     pub fn coriolis_parameter_at_latitude(&self, latitude_rad: f64) -> f64 {
         2.0 * self.parameters.earth_rotation_rate * latitude_rad.sin()
     }
 
+    /// Coriolis parameter for grid row `y` under the configured model, given the north-south
+    /// cell spacing. Returns (f, latitude) with latitude in radians, used for polar wind limits
+    pub fn coriolis_parameter_at_row(
+        &self,
+        y: usize,
+        height: usize,
+        meters_per_pixel_y: f64,
+    ) -> (f64, f64) {
+        match self.coriolis_model {
+            CoriolisModel::Off => (0.0, self.grid_y_to_latitude(y, height)),
+            CoriolisModel::Spherical => {
+                let latitude_rad = self.grid_y_to_latitude(y, height);
+                (
+                    self.coriolis_parameter_at_latitude(latitude_rad),
+                    latitude_rad,
+                )
+            }
+            CoriolisModel::FPlane(lat) => {
+                let latitude_rad = lat.to_radians();
+                (
+                    self.coriolis_parameter_at_latitude(latitude_rad),
+                    latitude_rad,
+                )
+            }
+            CoriolisModel::BetaPlane { lat, df_dy } => {
+                let latitude_rad = lat.to_radians();
+                // y = 0 is the northern edge, so northward distance decreases with row index
+                let center_row = (height.max(1) - 1) as f64 / 2.0;
+                let northward_m = (center_row - y as f64) * meters_per_pixel_y;
+                (
+                    self.coriolis_parameter_at_latitude(latitude_rad) + df_dy * northward_m,
+                    latitude_rad,
+                )
+            }
+        }
+    }
+
     /// Convert grid coordinates to latitude (ScaleAware - no hardcoded thresholds)
     pub fn grid_y_to_latitude(&self, y: usize, height: usize) -> f64 {
         let coord_params = &self.parameters.coordinate_mapping;
@@ -1015,7 +1261,7 @@ impl AtmosphericSystem {
 
         let mut wind_layer = WindLayer::new(width, height);
 
-        if !self.coriolis_active {
+        if !self.is_coriolis_active() {
             // No Coriolis effects - return zero wind field
             return wind_layer;
         }
@@ -1026,8 +1272,8 @@ impl AtmosphericSystem {
                 let pressure_gradient = pressure_layer.get_pressure_gradient(x, y);
 
                 // Calculate latitude-dependent Coriolis parameter
-                let latitude_rad = self.grid_y_to_latitude(y, height);
-                let f = self.coriolis_parameter_at_latitude(latitude_rad);
+                let (f, latitude_rad) =
+                    self.coriolis_parameter_at_row(y, height, scale.meters_per_pixel_y());
 
                 // Apply F_THRESHOLD safety parameter from SageMath validation
                 const F_THRESHOLD: f64 = 1e-6; // s⁻¹ - numerical stability limit
@@ -1106,7 +1352,8 @@ impl AtmosphericSystem {
                 let scaled_v = geostrophic_v * self.parameters.geostrophic_strength;
 
                 // Apply surface friction (reduces wind speed near surface)
-                let friction_factor = 1.0 - self.parameters.surface_friction;
+                let friction_factor =
+                    (1.0 - self.parameters.surface_friction) * self.surface_wind_factor(x, y);
 
                 wind_layer.velocity.set(
                     x,
@@ -1134,15 +1381,33 @@ impl AtmosphericSystem {
         wind_layer
     }
 
-    /// Check if domain is large enough for Coriolis effects
+    /// Surface wind multiplier from the local roughness length, relative to open grassland
+    ///
+    /// Uses the log wind profile u(z) ∝ ln(z/z₀) below a blending height: rough forest canopy
+    /// slows the 10 m wind, smooth water and sand let it run faster. 1.0 without roughness data.
+    pub fn surface_wind_factor(&self, x: usize, y: usize) -> f32 {
+        let Some(roughness) = &self.surface_roughness else {
+            return 1.0;
+        };
+        if x >= roughness.width() || y >= roughness.height() {
+            return 1.0;
+        }
+        let profile = |z0: f32| {
+            let z0 = z0.clamp(1e-5, SURFACE_WIND_HEIGHT_M * 0.5);
+            (SURFACE_WIND_HEIGHT_M / z0).ln() / (BLENDING_HEIGHT_M / z0).ln()
+        };
+        profile(*roughness.get(x, y)) / profile(REFERENCE_ROUGHNESS_LENGTH_M)
+    }
+
+    /// Check if domain is large enough for Coriolis effects and the model is not `Off`
     pub fn is_coriolis_active(&self) -> bool {
-        self.coriolis_active
+        self.coriolis_active && self.coriolis_model != CoriolisModel::Off
     }
 
     /// Get the Rossby deformation radius for this system
     /// L_R = √(gH)/f where g is gravity, H is scale height, f is Coriolis parameter
     pub fn rossby_deformation_radius(&self) -> f64 {
-        let g: f64 = 9.81; // gravity (m/s²)
+        let g = self.parameters.gravity as f64; // gravity (m/s²)
         let h: f64 = 10000.0; // atmospheric scale height (m)
         let f = self.effective_coriolis_parameter;
 
@@ -1215,7 +1480,7 @@ impl AtmosphericSystem {
     ) -> WeatherAnalysis {
         let mut analysis = WeatherAnalysis::default();
 
-        if !self.coriolis_active {
+        if !self.is_coriolis_active() {
             // No complex weather patterns without Coriolis effects
             return analysis;
         }
@@ -1353,7 +1618,7 @@ impl AtmosphericSystem {
 
         let mut wind_layer = WindLayer::new(width, height);
 
-        if !self.coriolis_active {
+        if !self.is_coriolis_active() {
             // No Coriolis effects - return zero wind field
             return wind_layer;
         }
@@ -1364,8 +1629,7 @@ impl AtmosphericSystem {
                 let pressure_gradient = pressure_layer.get_pressure_gradient(x, y);
 
                 // Calculate latitude-dependent Coriolis parameter
-                let latitude_rad = self.grid_y_to_latitude(y, height);
-                let f = self.coriolis_parameter_at_latitude(latitude_rad);
+                let (f, _) = self.coriolis_parameter_at_row(y, height, scale.meters_per_pixel_y());
 
                 // Apply F_THRESHOLD safety parameter from SageMath validation
                 const F_THRESHOLD: f64 = 1e-6; // s⁻¹ - numerical stability limit
@@ -1407,6 +1671,10 @@ impl AtmosphericSystem {
                 let u = -pressure_gradient.y * scaled_wind_factor;
                 let v = pressure_gradient.x * scaled_wind_factor;
 
+                // Rough ground slows the surface wind, smooth ground lets it run
+                let surface_factor = self.surface_wind_factor(x, y);
+                let (u, v) = (u * surface_factor, v * surface_factor);
+
                 // Apply wind speed limits for numerical stability and realism
                 let wind_speed = (u * u + v * v).sqrt();
                 let max_wind_speed = 50.0; // Maximum reasonable wind speed in m/s (hurricane strength)
@@ -1479,6 +1747,7 @@ impl AtmosphericSystem {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::core::physics_grid::{Float, StateFloat};
     use crate::engine::core::scale::{DetailLevel, WorldScale};
 
     // TDD Tests for WindLayer PhysicsGrid migration - Story 1.1.4
@@ -1575,7 +1844,9 @@ mod tests {
         for y in 0..10 {
             for x in 0..10 {
                 let pressure = 101325.0 - (x as f32 * 100.0); // 100 Pa decrease per cell
-                pressure_layer.pressure.set(x, y, pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(pressure));
             }
         }
         pressure_layer.calculate_pressure_gradients(20000.0); // 20km per pixel
@@ -1768,4 +2039,129 @@ mod tests {
 
         println!("✓ Coordinate mapping transitions are smooth - no hardcoded threshold artifacts");
     }
+
+    #[test]
+    fn beta_plane_geostrophic_wind_varies_with_latitude() {
+        let scale = WorldScale::new(1000.0, (20, 20), DetailLevel::Standard);
+        let mut pressure = AtmosphericPressureLayer::new(20, 20);
+        for y in 0..20 {
+            for x in 0..20 {
+                pressure.pressure_gradient.set(x, y, Vec2::new(0.0, 5e-6));
+            }
+        }
+
+        let mut system = AtmosphericSystem::new_for_scale(&scale);
+        system.coriolis_model = CoriolisModel::FPlane(45.0);
+        let f_plane = system.generate_geostrophic_winds(&pressure, &scale);
+        system.coriolis_model = CoriolisModel::BetaPlane {
+            lat: 45.0,
+            df_dy: 1.6e-11,
+        };
+        let beta_plane = system.generate_geostrophic_winds(&pressure, &scale);
+
+        // Compare a northern and a southern interior row, away from the boundary sponge
+        let (north, south) = (4, 15);
+        let f_ratio = f_plane.get_speed(10, north) / f_plane.get_speed(10, south);
+        assert!((f_ratio - 1.0).abs() < 1e-4);
+        // f grows northward on the beta plane, so the geostrophic wind weakens there
+        assert!(beta_plane.get_speed(10, north) < beta_plane.get_speed(10, south) * 0.99);
+
+        system.coriolis_model = CoriolisModel::Off;
+        assert!(!system.is_coriolis_active());
+        let calm = system.generate_geostrophic_winds(&pressure, &scale);
+        assert_eq!(calm.get_average_wind_speed(), 0.0);
+    }
+
+    #[test]
+    fn rough_surface_slows_wind_and_smooth_surface_speeds_it() {
+        let scale = WorldScale::new(1000.0, (20, 20), DetailLevel::Standard);
+        let mut pressure = AtmosphericPressureLayer::new(20, 20);
+        for y in 0..20 {
+            for x in 0..20 {
+                pressure.pressure_gradient.set(x, y, Vec2::new(0.0, 5e-6));
+            }
+        }
+        let mut system = AtmosphericSystem::new_for_scale(&scale);
+        system.coriolis_model = CoriolisModel::FPlane(45.0);
+        let open = system.generate_geostrophic_winds_scaled(&pressure, &scale, 1.0);
+
+        // Forest canopy on the western half, bare sand on the eastern half
+        let mut roughness = PhysicsGrid::new(20, 20, 0.001);
+        for y in 0..20 {
+            for x in 0..10 {
+                roughness.set(x, y, 1.0);
+            }
+        }
+        system.surface_roughness = Some(roughness);
+        let covered = system.generate_geostrophic_winds_scaled(&pressure, &scale, 1.0);
+
+        let speed = |wind: &WindLayer, x: usize| wind.velocity.get(x, 10).magnitude();
+        assert!(speed(&covered, 4) < speed(&open, 4) * 0.8);
+        assert!(speed(&covered, 15) > speed(&open, 15) * 1.05);
+
+        system.surface_roughness = Some(PhysicsGrid::new(20, 20, REFERENCE_ROUGHNESS_LENGTH_M));
+        assert!((system.surface_wind_factor(4, 10) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn detect_fronts_traces_temperature_step_with_wind_shift() {
+        // 10 K step between columns 9 and 10; southerly wind to the west, westerly to the east
+        let scale = WorldScale::new(200.0, (20, 12), DetailLevel::Standard);
+        let mut temperature = TemperatureLayer::new(20, 12);
+        let mut wind = WindLayer::new(20, 12);
+        for y in 0..12 {
+            for x in 0..20 {
+                let west = x < 10;
+                temperature
+                    .temperature
+                    .set(x, y, if west { 20.0 } else { 10.0 });
+                let velocity = if west {
+                    Vec2::new(0.0, 5.0)
+                } else {
+                    Vec2::new(5.0, 0.0)
+                };
+                wind.velocity.set(x, y, velocity);
+            }
+        }
+
+        let analysis = WeatherAnalysis::default();
+        let fronts = analysis.detect_fronts(&temperature, &wind, &scale);
+        assert_eq!(fronts.len(), 1);
+        let front = &fronts[0];
+        assert!(front.points.iter().all(|&(x, _)| x == 9 || x == 10));
+        let rows: Vec<usize> = front.points.iter().map(|&(_, y)| y).collect();
+        assert_eq!(rows.len(), 10);
+        assert!(rows.windows(2).all(|pair| pair[0].abs_diff(pair[1]) == 1));
+        assert!((front.wind_shift_degrees - 90.0).abs() < 1.0);
+        assert!((front.max_gradient - 0.5).abs() < 1e-3);
+
+        // Same step without the wind shift is just a temperature gradient
+        let steady = WindLayer::new(20, 12);
+        assert!(
+            analysis
+                .detect_fronts(&temperature, &steady, &scale)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn switching_back_to_earth_restores_the_latitude_span() {
+        let scale = WorldScale::new(4000.0, (32, 16), DetailLevel::Standard);
+        let mut system = AtmosphericSystem::new_for_scale(&scale);
+        let earth_span = system.parameters.coordinate_mapping.latitude_range_degrees;
+
+        // A tiny planet clamps the span at pole to pole
+        system.set_planet(&PlanetConfig {
+            radius_km: 100.0,
+            ..PlanetConfig::earth()
+        });
+        assert_eq!(
+            system.parameters.coordinate_mapping.latitude_range_degrees,
+            180.0
+        );
+
+        system.set_planet(&PlanetConfig::earth());
+        let restored = system.parameters.coordinate_mapping.latitude_range_degrees;
+        assert!((restored - earth_span).abs() < 1e-9);
+    }
 }
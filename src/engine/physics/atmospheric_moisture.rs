@@ -4,10 +4,13 @@
 // ABOUTME: Atmospheric moisture and surface humidity system for realistic weather simulation
 // ABOUTME: Separates surface moisture from standing water bodies for proper atmospheric coupling
 
+use super::super::core::PhysicsGrid;
 use super::super::core::heightmap::HeightMap;
 use super::super::core::scale::{ScaleAware, WorldScale};
+use super::atmosphere::WindLayer;
 use super::climate::{ClimateSystem, TemperatureLayer};
 use super::water::WaterLayer;
+use serde::{Deserialize, Serialize};
 
 /// METIS CORRECTION: Physics-compliant surface energy balance calculation
 /// Implements energy conservation for evaporation processes
@@ -101,6 +104,7 @@ const REFERENCE_VAPOR_PRESSURE: f32 = 611.0; // Pa (saturation pressure at 0°C)
 const AIR_DENSITY: f32 = 1.225; // kg/m³ at standard conditions
 const SPECIFIC_HEAT_AIR: f32 = 1004.0; // J/(kg·K)
 const CLAUSIUS_CLAPEYRON_FACTOR: f32 = 5423.0; // L_v/R_v in K (adjusted for better accuracy)
+const WATER_VAPOR_SCALE_HEIGHT: f32 = 2000.0; // m - e-folding height of atmospheric water vapor
 
 /// METIS CORRECTION: Physics-compliant surface energy balance parameters
 #[derive(Clone, Debug)]
@@ -626,6 +630,79 @@ impl AtmosphericMoistureSystem {
         }
     }
 
+    /// Advect a humidity field with the wind using a conservative first-order upwind scheme
+    /// Fluxes are exchanged across cell faces using the face-averaged wind (grid axes: +x east,
+    /// +y toward higher rows); the domain edges are closed, so the field's integral is conserved.
+    /// The step is split into sub-steps keeping the Courant number at or below 0.5.
+    pub fn advect(
+        &self,
+        humidity: &mut PhysicsGrid<f32>,
+        wind: &WindLayer,
+        dt: f32, // Time step in hours
+        scale: &WorldScale,
+    ) {
+        let width = humidity.width().min(wind.width());
+        let height = humidity.height().min(wind.height());
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let dx = scale.meters_per_pixel_x() as f32;
+        let dy = scale.meters_per_pixel_y() as f32;
+        let dt_seconds = dt * 3600.0;
+        let mut courant: f32 = 0.0;
+        for y in 0..height {
+            for x in 0..width {
+                let velocity = wind.velocity.get(x, y);
+                courant = courant
+                    .max(velocity.x.abs() * dt_seconds / dx)
+                    .max(velocity.y.abs() * dt_seconds / dy);
+            }
+        }
+        let substeps = (courant / 0.5).ceil().max(1.0) as usize;
+        let step_x = dt_seconds / dx / substeps as f32;
+        let step_y = dt_seconds / dy / substeps as f32;
+
+        let mut updated = humidity.clone();
+        for _ in 0..substeps {
+            for y in 0..height {
+                for x in 0..width {
+                    let here = *humidity.get(x, y);
+                    let velocity = wind.velocity.get(x, y);
+
+                    // East face
+                    if x + 1 < width {
+                        let neighbor = wind.velocity.get(x + 1, y);
+                        let face_u = 0.5 * (velocity.x + neighbor.x);
+                        let upwind = if face_u > 0.0 {
+                            here
+                        } else {
+                            *humidity.get(x + 1, y)
+                        };
+                        let flux = face_u * step_x * upwind;
+                        *updated.get_mut(x, y) -= flux;
+                        *updated.get_mut(x + 1, y) += flux;
+                    }
+
+                    // South face (next row)
+                    if y + 1 < height {
+                        let neighbor = wind.velocity.get(x, y + 1);
+                        let face_v = 0.5 * (velocity.y + neighbor.y);
+                        let upwind = if face_v > 0.0 {
+                            here
+                        } else {
+                            *humidity.get(x, y + 1)
+                        };
+                        let flux = face_v * step_y * upwind;
+                        *updated.get_mut(x, y) -= flux;
+                        *updated.get_mut(x, y + 1) += flux;
+                    }
+                }
+            }
+            humidity.clone_from(&updated);
+        }
+    }
+
     /// Add precipitation from weather systems
     pub fn add_precipitation(&mut self, x: usize, y: usize, amount: f32) {
         self.surface_moisture
@@ -669,11 +746,162 @@ impl AtmosphericMoistureSystem {
     }
 }
 
+/// Relative humidity (0-1) of a column holding the given precipitable water
+/// Saturation column = saturation humidity × water vapor scale height
+pub fn relative_humidity_from_precipitable_water(
+    precipitable_water_kg_m2: f32,
+    temperature_c: f32,
+) -> f32 {
+    let saturation_column =
+        calculate_saturation_humidity(temperature_c + 273.15) * WATER_VAPOR_SCALE_HEIGHT;
+    if saturation_column > 0.0 {
+        (precipitable_water_kg_m2 / saturation_column).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// Cloud formation parameters linking humidity and low-level convergence to cloud cover
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CloudParameters {
+    /// Relative humidity at which clouds begin to form (0-1)
+    pub critical_relative_humidity: f32,
+    /// Convergence rate (1/s) that produces the full humidity boost from lifting
+    pub reference_convergence: f32,
+    /// Relative humidity added by full convergent lifting (adiabatic cooling proxy)
+    pub convergence_humidity_boost: f32,
+    /// Fraction of insolation reflected by complete cloud cover
+    pub cloud_albedo: f32,
+}
+
+impl Default for CloudParameters {
+    fn default() -> Self {
+        Self {
+            critical_relative_humidity: 0.6, // Sundqvist-style onset threshold
+            reference_convergence: 1e-5,     // Typical synoptic convergence (1/s)
+            convergence_humidity_boost: 0.3, // Lifting can push 70% RH air to saturation
+            cloud_albedo: 0.5,               // Mean cloud shortwave reflectivity
+        }
+    }
+}
+
+/// Cloud cover field produced from column humidity and wind convergence
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CloudLayer {
+    /// Cloud fraction (0-1) at each cell
+    pub cloud_fraction: PhysicsGrid<f32>,
+    /// Cloud formation parameters
+    pub parameters: CloudParameters,
+}
+
+impl CloudLayer {
+    /// Create a clear-sky cloud layer with default parameters
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            cloud_fraction: PhysicsGrid::new(width, height, 0.0),
+            parameters: CloudParameters::default(),
+        }
+    }
+
+    /// Get cloud fraction at specific coordinates
+    pub fn get_cloud_fraction(&self, x: usize, y: usize) -> f32 {
+        if x < self.width() && y < self.height() {
+            *self.cloud_fraction.get(x, y)
+        } else {
+            0.0
+        }
+    }
+
+    /// Multiplier applied to insolation and evaporation: 1 - albedo × cloud_fraction
+    pub fn shading_factor(&self, x: usize, y: usize) -> f32 {
+        1.0 - self.parameters.cloud_albedo * self.get_cloud_fraction(x, y)
+    }
+
+    /// Get average cloud fraction across the layer
+    pub fn get_average_cloud_fraction(&self) -> f32 {
+        self.cloud_fraction.average()
+    }
+
+    /// Recompute cloud cover from relative humidity (0-1) and divergence (1/s) fields
+    /// Negative divergence (convergence) lifts air and raises the effective humidity
+    pub fn update_from_humidity_and_convergence(
+        &mut self,
+        relative_humidity: &PhysicsGrid<f32>,
+        divergence: &PhysicsGrid<f32>,
+    ) {
+        let params = &self.parameters;
+        let width = self.cloud_fraction.width();
+        let height = self.cloud_fraction.height();
+
+        for y in 0..height {
+            for x in 0..width {
+                let humidity = *relative_humidity.get(x, y);
+                let convergence = (-*divergence.get(x, y)).max(0.0);
+                let lifting = (convergence / params.reference_convergence).min(1.0);
+                let effective_humidity =
+                    (humidity + lifting * params.convergence_humidity_boost).min(1.0);
+
+                // Sundqvist cloud fraction: 1 - sqrt((1 - RH) / (1 - RH_c)) above threshold
+                let fraction = if effective_humidity > params.critical_relative_humidity {
+                    1.0 - ((1.0 - effective_humidity) / (1.0 - params.critical_relative_humidity))
+                        .sqrt()
+                } else {
+                    0.0
+                };
+                self.cloud_fraction.set(x, y, fraction.clamp(0.0, 1.0));
+            }
+        }
+    }
+
+    /// Recompute cloud cover from the wind layer's precipitable water and convergence
+    pub fn update_from_wind_layer(
+        &mut self,
+        wind_layer: &WindLayer,
+        temperature_layer: &TemperatureLayer,
+        season: f32,
+        meters_per_pixel: f32,
+    ) {
+        let width = self.cloud_fraction.width().min(wind_layer.width());
+        let height = self.cloud_fraction.height().min(wind_layer.height());
+
+        let mut relative_humidity = PhysicsGrid::new(width, height, 0.0);
+        for y in 0..height {
+            for x in 0..width {
+                let temperature = temperature_layer.get_current_temperature(x, y, season);
+                let precipitable_water = *wind_layer.precipitable_water.get(x, y);
+                relative_humidity.set(
+                    x,
+                    y,
+                    relative_humidity_from_precipitable_water(precipitable_water, temperature),
+                );
+            }
+        }
+
+        let divergence = if width >= 3 && height >= 3 {
+            wind_layer.calculate_divergence_field(meters_per_pixel)
+        } else {
+            PhysicsGrid::new(width, height, 0.0)
+        };
+        self.update_from_humidity_and_convergence(&relative_humidity, &divergence);
+    }
+
+    /// Get width of cloud layer
+    pub fn width(&self) -> usize {
+        self.cloud_fraction.width()
+    }
+
+    /// Get height of cloud layer
+    pub fn height(&self) -> usize {
+        self.cloud_fraction.height()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::engine::core::scale::{DetailLevel, WorldScale};
     use crate::engine::physics::climate::ClimateSystem;
+    use crate::engine::physics::water::Vec2;
 
     fn test_scale(physical_size_km: f64, width: u32, height: u32) -> WorldScale {
         WorldScale::new(physical_size_km, (width, height), DetailLevel::Standard)
@@ -837,4 +1065,145 @@ mod tests {
         let efficiency = test_layer.get_precipitation_efficiency(1, 1);
         assert!(efficiency > 0.0 && efficiency < 1.0);
     }
+
+    #[test]
+    fn clouds_form_over_humid_convergence_and_cool_surface() {
+        let scale = test_scale(200.0, 20, 20);
+        let meters_per_pixel = scale.meters_per_pixel() as f32;
+        let mut temperature_layer = TemperatureLayer::new(20, 20);
+        temperature_layer.temperature.fill(20.0);
+
+        // Flow converging on the map center at ~2e-5 1/s
+        let mut wind_layer = WindLayer::new(20, 20);
+        let saturation_column = calculate_saturation_humidity(293.15) * WATER_VAPOR_SCALE_HEIGHT;
+        for y in 0..20 {
+            for x in 0..20 {
+                let u = -(x as f32 - 10.0) * 1e-5 * meters_per_pixel;
+                let v = -(y as f32 - 10.0) * 1e-5 * meters_per_pixel;
+                wind_layer.velocity.set(x, y, Vec2::new(u, v));
+
+                // Humid core surrounded by dry air
+                let humid = (7..13).contains(&x) && (7..13).contains(&y);
+                let relative_humidity = if humid { 0.75 } else { 0.3 };
+                wind_layer
+                    .precipitable_water
+                    .set(x, y, relative_humidity * saturation_column);
+            }
+        }
+
+        let mut clouds = CloudLayer::new(20, 20);
+        clouds.update_from_wind_layer(&wind_layer, &temperature_layer, 0.5, meters_per_pixel);
+        assert!(
+            clouds.get_cloud_fraction(10, 10) > 0.9,
+            "Humid convergence zone should be overcast, got {}",
+            clouds.get_cloud_fraction(10, 10)
+        );
+        assert_eq!(clouds.get_cloud_fraction(3, 3), 0.0);
+
+        // Without lifting the same humid air only forms scattered cloud
+        let mut calm_wind = wind_layer.clone();
+        calm_wind.velocity.fill(Vec2::zero());
+        let mut calm_clouds = CloudLayer::new(20, 20);
+        calm_clouds.update_from_wind_layer(&calm_wind, &temperature_layer, 0.5, meters_per_pixel);
+        assert!(calm_clouds.get_cloud_fraction(10, 10) < clouds.get_cloud_fraction(10, 10));
+
+        // Cloud shading reduces insolation-driven temperature under the cloud deck
+        let heightmap = vec![vec![0.0; 20]; 20];
+        let mut climate = ClimateSystem::new_for_scale(&scale);
+        climate.parameters.insolation_weight = 1.0;
+        let clear_sky = climate.generate_temperature_layer(&heightmap);
+        climate.cloud_layer = Some(clouds);
+        let cloudy = climate.generate_temperature_layer(&heightmap);
+
+        let center_cooling = clear_sky.get_temperature(10, 10) - cloudy.get_temperature(10, 10);
+        let corner_cooling = clear_sky.get_temperature(1, 1) - cloudy.get_temperature(1, 1);
+        assert!(
+            center_cooling > 5.0,
+            "Expected local cooling under clouds, got {:.2}°C",
+            center_cooling
+        );
+        assert!(corner_cooling < center_cooling * 0.1);
+        assert!(climate.cloud_shading_factor(10, 10) < 0.6);
+    }
+
+    #[test]
+    fn advection_moves_blob_downwind_and_conserves_moisture() {
+        let scale = test_scale(60.0, 60, 10); // 1 km cells
+        let system = AtmosphericMoistureSystem::new_for_scale(&scale, 60, 10);
+        let mut wind = WindLayer::new(60, 10);
+        for y in 0..10 {
+            for x in 0..60 {
+                wind.velocity.set(x, y, Vec2::new(10.0, 0.0));
+            }
+        }
+        let mut humidity = PhysicsGrid::new(60, 10, 0.0);
+        for y in 4..6 {
+            for x in 8..11 {
+                humidity.set(x, y, 5.0);
+            }
+        }
+        let centroid_x = |field: &PhysicsGrid<f32>| {
+            let (mut moment, mut total) = (0.0, 0.0);
+            for y in 0..10 {
+                for x in 0..60 {
+                    moment += x as f32 * *field.get(x, y);
+                    total += *field.get(x, y);
+                }
+            }
+            (moment / total, total)
+        };
+        let (start_x, start_total) = centroid_x(&humidity);
+
+        // 10 m/s for 2500 s carries the blob 25 km, i.e. 25 cells
+        system.advect(&mut humidity, &wind, 2500.0 / 3600.0, &scale);
+
+        let (end_x, end_total) = centroid_x(&humidity);
+        assert!(
+            (end_x - start_x - 25.0).abs() < 0.05,
+            "moved {}",
+            end_x - start_x
+        );
+        assert!((end_total - start_total).abs() < 1e-3 * start_total);
+        assert!(humidity.iter().all(|&value| value >= 0.0));
+    }
+
+    #[test]
+    fn advection_uses_each_axis_cell_spacing() {
+        use crate::engine::core::scale::WorldScaleBuilder;
+
+        // 1 km cells east-west, 2 km cells north-south
+        let scale = WorldScaleBuilder::new((10, 40))
+            .physical_size_km(10.0, 80.0)
+            .build();
+        let system = AtmosphericMoistureSystem::new_for_scale(&scale, 10, 40);
+        let mut wind = WindLayer::new(10, 40);
+        for y in 0..40 {
+            for x in 0..10 {
+                wind.velocity.set(x, y, Vec2::new(0.0, 10.0));
+            }
+        }
+        let mut humidity = PhysicsGrid::new(10, 40, 0.0);
+        for y in 4..6 {
+            for x in 4..6 {
+                humidity.set(x, y, 5.0);
+            }
+        }
+        let centroid_y = |field: &PhysicsGrid<f32>| {
+            let (mut moment, mut total) = (0.0, 0.0);
+            for y in 0..40 {
+                for x in 0..10 {
+                    moment += y as f32 * *field.get(x, y);
+                    total += *field.get(x, y);
+                }
+            }
+            moment / total
+        };
+        let start_y = centroid_y(&humidity);
+
+        // 10 m/s for 2500 s carries the blob 25 km, i.e. 12.5 two-kilometre rows
+        system.advect(&mut humidity, &wind, 2500.0 / 3600.0, &scale);
+
+        let moved = centroid_y(&humidity) - start_y;
+        assert!((moved - 12.5).abs() < 0.05, "moved {}", moved);
+    }
 }
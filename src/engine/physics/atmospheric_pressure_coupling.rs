@@ -6,7 +6,11 @@
 
 use super::flow_engine::FlowEngine;
 use super::water::WaterLayer;
-use crate::engine::core::{heightmap::HeightMap, scale::WorldScale};
+use crate::engine::core::{
+    heightmap::HeightMap,
+    physics_grid::{Float, StateFloat},
+    scale::WorldScale,
+};
 use crate::engine::physics::atmosphere::AtmosphericSystem;
 use crate::engine::physics::climate::TemperatureLayer;
 
@@ -272,7 +276,9 @@ impl PressureAwareWaterFlowSystem {
 
                     // Remove water through evaporation (using existing method)
                     let new_depth = current_depth - evaporated_amount;
-                    water_layer.depth.set(x, y, new_depth.max(0.0));
+                    water_layer
+                        .depth
+                        .set(x, y, StateFloat::from_f32(new_depth.max(0.0)));
                 }
 
                 // 2. Apply pressure gradient acceleration to flow velocity
@@ -300,7 +306,7 @@ impl PressureAwareWaterFlowSystem {
                         * dt
                         * self.pressure_influence;
                     let new_depth = (current_depth + retention_change).max(0.0);
-                    water_layer.depth.set(x, y, new_depth);
+                    water_layer.depth.set(x, y, StateFloat::from_f32(new_depth));
                 }
             }
         }
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Boundary forcing for nested domains - time-varying edge values sampled from a parent run
+// ABOUTME: Relaxes water depth, temperature and pressure in a border band toward interpolated frames
+
+use crate::engine::core::{Float, PhysicsGrid};
+use serde::{Deserialize, Serialize};
+
+/// Forcing layers at one simulation time, on the nested grid; only border cells are used
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BoundaryFrame {
+    /// Simulation time of the frame (hours)
+    pub hours: f32,
+    /// Surface water depth (m)
+    pub water_depth: Option<PhysicsGrid<f32>>,
+    /// Air temperature (°C)
+    pub temperature: Option<PhysicsGrid<f32>>,
+    /// Sea-level equivalent pressure (Pa)
+    pub pressure: Option<PhysicsGrid<f32>>,
+}
+
+impl BoundaryFrame {
+    /// Frame at `hours` forcing no layers
+    pub fn new(hours: f32) -> Self {
+        Self {
+            hours,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_water_depth(mut self, water_depth: PhysicsGrid<f32>) -> Self {
+        self.water_depth = Some(water_depth);
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: PhysicsGrid<f32>) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    pub fn with_pressure(mut self, pressure: PhysicsGrid<f32>) -> Self {
+        self.pressure = Some(pressure);
+        self
+    }
+}
+
+/// A forcing frame layer whose size does not match the simulation grid
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrameSizeMismatch {
+    /// Simulation time of the offending frame (hours)
+    pub hours: f32,
+    /// Name of the offending layer
+    pub layer: &'static str,
+    /// Grid size the simulation expects (width, height)
+    pub expected: (usize, usize),
+    /// Size of the layer in the frame (width, height)
+    pub found: (usize, usize),
+}
+
+impl std::fmt::Display for FrameSizeMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "boundary frame at {} h has a {}x{} {} layer, expected {}x{}",
+            self.hours, self.found.0, self.found.1, self.layer, self.expected.0, self.expected.1
+        )
+    }
+}
+
+impl std::error::Error for FrameSizeMismatch {}
+
+/// Time-varying edge values that a nested simulation relaxes its border cells toward
+///
+/// Layers are linearly interpolated between the frames that carry them and held at the
+/// first or last such frame outside their time span.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BoundaryForcing {
+    /// Fraction of the gap to the forcing closed each tick (0 = free, 1 = clamped)
+    pub relaxation: f32,
+    /// Depth of the forced border band in cells
+    pub width: usize,
+    frames: Vec<BoundaryFrame>,
+}
+
+impl BoundaryForcing {
+    /// Forcing of the outermost ring of cells with the given relaxation
+    pub fn new(relaxation: f32) -> Self {
+        Self {
+            relaxation: relaxation.clamp(0.0, 1.0),
+            width: 1,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    pub fn with_frame(mut self, frame: BoundaryFrame) -> Self {
+        self.add_frame(frame);
+        self
+    }
+
+    /// Insert a frame, keeping frames in time order
+    pub fn add_frame(&mut self, frame: BoundaryFrame) {
+        let index = self.frames.partition_point(|f| f.hours <= frame.hours);
+        self.frames.insert(index, frame);
+    }
+
+    pub fn frames(&self) -> &[BoundaryFrame] {
+        &self.frames
+    }
+
+    /// Check that every layer of every frame covers a `width` x `height` grid
+    pub fn validate(&self, width: usize, height: usize) -> Result<(), FrameSizeMismatch> {
+        for frame in &self.frames {
+            let layers = [
+                ("water depth", &frame.water_depth),
+                ("temperature", &frame.temperature),
+                ("pressure", &frame.pressure),
+            ];
+            for (layer, grid) in layers {
+                if let Some(grid) = grid
+                    && (grid.width(), grid.height()) != (width, height)
+                {
+                    return Err(FrameSizeMismatch {
+                        hours: frame.hours,
+                        layer,
+                        expected: (width, height),
+                        found: (grid.width(), grid.height()),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Frames bracketing `hours` among those carrying a layer, with the weight of the later one
+    fn bracket<'a>(
+        &'a self,
+        hours: f32,
+        layer: impl Fn(&'a BoundaryFrame) -> Option<&'a PhysicsGrid<f32>>,
+    ) -> Option<(&'a PhysicsGrid<f32>, &'a PhysicsGrid<f32>, f32)> {
+        let mut before: Option<(f32, &PhysicsGrid<f32>)> = None;
+        for frame in &self.frames {
+            let Some(grid) = layer(frame) else {
+                continue;
+            };
+            if frame.hours > hours {
+                return Some(match before {
+                    Some((start, previous)) => {
+                        let span = frame.hours - start;
+                        let weight = if span > 0.0 {
+                            (hours - start) / span
+                        } else {
+                            1.0
+                        };
+                        (previous, grid, weight)
+                    }
+                    None => (grid, grid, 0.0),
+                });
+            }
+            before = Some((frame.hours, grid));
+        }
+        before.map(|(_, grid)| (grid, grid, 0.0))
+    }
+
+    /// Relax the border band of a row-major `width` x `height` layer toward the forcing
+    /// Returns the net change summed over the band, or None, leaving the values untouched,
+    /// when no frame carries the layer
+    pub fn relax<'a, T: Float>(
+        &'a self,
+        hours: f32,
+        layer: impl Fn(&'a BoundaryFrame) -> Option<&'a PhysicsGrid<f32>>,
+        values: &mut [T],
+        width: usize,
+        height: usize,
+    ) -> Option<f32> {
+        let (start, end, weight) = self.bracket(hours, layer)?;
+        let mut net_change = 0.0;
+        for y in 0..height {
+            for x in 0..width {
+                let edge_distance = x.min(y).min(width - 1 - x).min(height - 1 - y);
+                if edge_distance >= self.width
+                    || x >= start.width()
+                    || y >= start.height()
+                    || x >= end.width()
+                    || y >= end.height()
+                {
+                    continue;
+                }
+                let target = *start.get(x, y) * (1.0 - weight) + *end.get(x, y) * weight;
+                let value = &mut values[y * width + x];
+                let change = (target - value.to_f32()) * self.relaxation;
+                *value += T::from_f32(change);
+                net_change += change;
+            }
+        }
+        Some(net_change)
+    }
+}
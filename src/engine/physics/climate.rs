@@ -5,8 +5,14 @@
 // ABOUTME: Implements elevation-based temperature gradients with scale-aware parameters
 
 use super::super::core::PhysicsGrid;
+use super::super::core::math::Rng;
+use super::super::core::physics_grid::{Float, StateFloat};
+use super::super::core::planet::EARTH_GRAVITY;
 use super::super::core::scale::{REFERENCE_SCALE, ScaleAware, WorldScale};
-use super::water::Vec2;
+use super::atmospheric_moisture::CloudLayer;
+use super::insolation::{Insolation, row_latitude_degrees};
+use super::water::{Vec2, WaterLayer};
+use serde::{Deserialize, Serialize};
 
 /// Helper function to determine pressure bounds based on domain scale
 /// Continental domains need wider pressure ranges for realistic weather systems
@@ -63,7 +69,7 @@ fn get_pressure_bounds(scale: &WorldScale) -> (f32, f32) {
 }
 
 /// Core temperature data layer
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TemperatureLayer {
     /// Temperature in Celsius at each cell - PhysicsGrid for 2-3x performance while preserving energy conservation
     pub temperature: PhysicsGrid<f32>,
@@ -73,14 +79,32 @@ pub struct TemperatureLayer {
 
 /// Atmospheric pressure data layer
 /// Pressure drives wind patterns through horizontal pressure gradients
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AtmosphericPressureLayer {
     /// Pressure in Pascals at each cell (sea level equivalent) - PhysicsGrid for 2-3x performance
-    pub pressure: PhysicsGrid<f32>,
+    pub pressure: PhysicsGrid<StateFloat>,
     /// Pressure gradient vector (∇P) in Pa/m at each cell - PhysicsGrid for cache efficiency
     pub pressure_gradient: PhysicsGrid<Vec2>,
 }
 
+/// Local pressure minimum - a depression or storm center
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Low {
+    pub x: usize,
+    pub y: usize,
+    /// Central pressure (Pa)
+    pub pressure: f32,
+}
+
+/// Local pressure maximum - an anticyclone center
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct High {
+    pub x: usize,
+    pub y: usize,
+    /// Central pressure (Pa)
+    pub pressure: f32,
+}
+
 impl TemperatureLayer {
     /// Create a new temperature layer with the given dimensions
     pub fn new(width: usize, height: usize) -> Self {
@@ -122,6 +146,15 @@ impl TemperatureLayer {
         }
     }
 
+    /// Set the temperature seen at `season_factor`, keeping the cell's seasonal variation
+    pub fn set_current_temperature(&mut self, x: usize, y: usize, season_factor: f32, value: f32) {
+        if x < self.temperature.width() && y < self.temperature.height() {
+            let variation = *self.seasonal_variation.get(x, y);
+            let seasonal_offset = variation * (season_factor - 0.5) * 2.0;
+            self.temperature.set(x, y, value - seasonal_offset);
+        }
+    }
+
     /// Get average temperature across the entire map
     pub fn get_average_temperature(&self) -> f32 {
         // PhysicsGrid provides an optimized average() method - maintains energy conservation accuracy
@@ -151,7 +184,7 @@ impl AtmosphericPressureLayer {
     /// Get pressure at a specific location (with bounds checking)
     pub fn get_pressure(&self, x: usize, y: usize) -> f32 {
         if x < self.pressure.width() && y < self.pressure.height() {
-            *self.pressure.get(x, y)
+            self.pressure.get(x, y).to_f32()
         } else {
             101325.0 // Standard sea level pressure if out of bounds
         }
@@ -169,6 +202,12 @@ impl AtmosphericPressureLayer {
     /// Calculate pressure gradients using finite differences
     /// ∇P = (∂P/∂x, ∂P/∂y) computed using central differences where possible
     pub fn calculate_pressure_gradients(&mut self, meters_per_pixel: f32) {
+        self.calculate_pressure_gradients_xy(meters_per_pixel, meters_per_pixel);
+    }
+
+    /// Calculate pressure gradients on cells spaced `dx` (east-west) by `dy` (north-south) meters
+    /// Central differences in the interior, one-sided differences at the edges
+    pub fn calculate_pressure_gradients_xy(&mut self, dx: f32, dy: f32) {
         let width = self.pressure.width();
         let height = self.pressure.height();
 
@@ -179,36 +218,42 @@ impl AtmosphericPressureLayer {
                 // Calculate ∂P/∂x using central differences (or forward/backward at boundaries)
                 if x > 0 && x < width - 1 {
                     // Central difference: (P[x+1] - P[x-1]) / (2 * dx)
-                    let dp_dx = (*self.pressure.get(x + 1, y) - *self.pressure.get(x - 1, y))
-                        / (2.0 * meters_per_pixel);
+                    let dp_dx = (self.pressure.get(x + 1, y).to_f32()
+                        - self.pressure.get(x - 1, y).to_f32())
+                        / (2.0 * dx);
                     gradient.x = dp_dx;
                 } else if x == 0 && width > 1 {
                     // Forward difference: (P[x+1] - P[x]) / dx
-                    let dp_dx = (*self.pressure.get(x + 1, y) - *self.pressure.get(x, y))
-                        / meters_per_pixel;
+                    let dp_dx = (self.pressure.get(x + 1, y).to_f32()
+                        - self.pressure.get(x, y).to_f32())
+                        / dx;
                     gradient.x = dp_dx;
                 } else if x == width - 1 && width > 1 {
                     // Backward difference: (P[x] - P[x-1]) / dx
-                    let dp_dx = (*self.pressure.get(x, y) - *self.pressure.get(x - 1, y))
-                        / meters_per_pixel;
+                    let dp_dx = (self.pressure.get(x, y).to_f32()
+                        - self.pressure.get(x - 1, y).to_f32())
+                        / dx;
                     gradient.x = dp_dx;
                 }
 
                 // Calculate ∂P/∂y using central differences (or forward/backward at boundaries)
                 if y > 0 && y < height - 1 {
                     // Central difference: (P[y+1] - P[y-1]) / (2 * dy)
-                    let dp_dy = (*self.pressure.get(x, y + 1) - *self.pressure.get(x, y - 1))
-                        / (2.0 * meters_per_pixel);
+                    let dp_dy = (self.pressure.get(x, y + 1).to_f32()
+                        - self.pressure.get(x, y - 1).to_f32())
+                        / (2.0 * dy);
                     gradient.y = dp_dy;
                 } else if y == 0 && height > 1 {
                     // Forward difference: (P[y+1] - P[y]) / dy
-                    let dp_dy = (*self.pressure.get(x, y + 1) - *self.pressure.get(x, y))
-                        / meters_per_pixel;
+                    let dp_dy = (self.pressure.get(x, y + 1).to_f32()
+                        - self.pressure.get(x, y).to_f32())
+                        / dy;
                     gradient.y = dp_dy;
                 } else if y == height - 1 && height > 1 {
                     // Backward difference: (P[y] - P[y-1]) / dy
-                    let dp_dy = (*self.pressure.get(x, y) - *self.pressure.get(x, y - 1))
-                        / meters_per_pixel;
+                    let dp_dy = (self.pressure.get(x, y).to_f32()
+                        - self.pressure.get(x, y - 1).to_f32())
+                        / dy;
                     gradient.y = dp_dy;
                 }
 
@@ -217,10 +262,36 @@ impl AtmosphericPressureLayer {
         }
     }
 
+    /// Height (m above sea level) at which each column reaches `target_pressure_pa`
+    /// Hypsometric equation z = (R_d·T/g)·ln(p_surface/p_target), treating each column as isothermal
+    /// at its surface temperature; the pressure field is sea-level equivalent, so z is referenced to sea level
+    pub fn geopotential_height(
+        &self,
+        target_pressure_pa: f32,
+        temperature: &TemperatureLayer,
+        parameters: &ClimateParameters,
+    ) -> PhysicsGrid<f32> {
+        let width = self.pressure.width();
+        let height = self.pressure.height();
+        let target = target_pressure_pa.max(f32::EPSILON);
+        let mut heights = PhysicsGrid::new(width, height, 0.0);
+        for y in 0..height {
+            for x in 0..width {
+                let scale_height = parameters.scale_height_at(temperature.get_temperature(x, y));
+                heights.set(
+                    x,
+                    y,
+                    scale_height * (self.pressure.get(x, y).to_f32() / target).ln(),
+                );
+            }
+        }
+        heights
+    }
+
     /// Get average pressure across the entire map
     pub fn get_average_pressure(&self) -> f32 {
         // PhysicsGrid provides an optimized average() method
-        self.pressure.average()
+        self.pressure.average().to_f32()
     }
 
     /// Get maximum pressure gradient magnitude for stability analysis
@@ -229,6 +300,55 @@ impl AtmosphericPressureLayer {
         self.pressure_gradient.max_magnitude()
     }
 
+    /// Cell with the steepest pressure gradient as (x, y, magnitude in Pa/m)
+    /// Uses the stored gradient field, so call `calculate_pressure_gradients` first
+    pub fn max_gradient_cell(&self) -> (usize, usize, f32) {
+        let mut strongest = (0, 0, 0.0);
+        for y in 0..self.pressure_gradient.height() {
+            for x in 0..self.pressure_gradient.width() {
+                let magnitude = self.pressure_gradient.get(x, y).magnitude();
+                if magnitude > strongest.2 {
+                    strongest = (x, y, magnitude);
+                }
+            }
+        }
+        strongest
+    }
+
+    /// Interior cells strictly below (lows) or above (highs) all eight neighbors
+    /// Lows are ordered deepest first and highs strongest first
+    pub fn pressure_extrema(&self) -> (Vec<Low>, Vec<High>) {
+        let mut lows = Vec::new();
+        let mut highs = Vec::new();
+        let width = self.pressure.width();
+        let height = self.pressure.height();
+        for y in 1..height.saturating_sub(1) {
+            for x in 1..width.saturating_sub(1) {
+                let pressure = self.pressure.get(x, y).to_f32();
+                let mut below_all = true;
+                let mut above_all = true;
+                for ny in y - 1..=y + 1 {
+                    for nx in x - 1..=x + 1 {
+                        if (nx, ny) == (x, y) {
+                            continue;
+                        }
+                        let neighbor = self.pressure.get(nx, ny).to_f32();
+                        below_all &= pressure < neighbor;
+                        above_all &= pressure > neighbor;
+                    }
+                }
+                if below_all {
+                    lows.push(Low { x, y, pressure });
+                } else if above_all {
+                    highs.push(High { x, y, pressure });
+                }
+            }
+        }
+        lows.sort_by(|a, b| a.pressure.total_cmp(&b.pressure));
+        highs.sort_by(|a, b| b.pressure.total_cmp(&a.pressure));
+        (lows, highs)
+    }
+
     /// Get width of pressure layer
     pub fn width(&self) -> usize {
         self.pressure.width()
@@ -240,8 +360,40 @@ impl AtmosphericPressureLayer {
     }
 }
 
+/// Formula relating surface evaporation to local conditions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvaporationModel {
+    /// Doubles every 10°C from the 20°C reference; ignores radiation, wind and humidity
+    #[default]
+    ArrheniusLike,
+    /// FAO-56 Penman-Monteith reference evapotranspiration from net radiation, wind and humidity
+    PenmanMonteith,
+}
+
+/// Which pressure signals the pressure generators combine
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PressureStrategy {
+    /// Hydrostatic pressure plus the temperature-coupled thermal lows and highs
+    ThermalOnly,
+    /// Hydrostatic pressure plus seeded synoptic highs and lows, ignoring thermal coupling
+    SynopticOnly,
+    /// Thermal coupling with synoptic systems on top
+    #[default]
+    Hybrid,
+}
+
+impl PressureStrategy {
+    pub fn includes_thermal(self) -> bool {
+        self != PressureStrategy::SynopticOnly
+    }
+
+    pub fn includes_synoptic(self) -> bool {
+        self != PressureStrategy::ThermalOnly
+    }
+}
+
 /// Raw climate parameters before scale adjustment
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ClimateParameters {
     /// Base temperature at sea level in Celsius
     pub base_temperature_c: f32,
@@ -265,8 +417,21 @@ pub struct ClimateParameters {
     pub seasonal_pressure_amplitude: f32,
     /// Random pressure perturbation strength for weather systems (Pa)
     pub pressure_noise_amplitude: f32,
+
+    /// Blend weight of insolation-driven temperature against the gradient model (0.0-1.0)
+    /// 0.0 reproduces the prescribed lapse/gradient temperatures exactly
+    pub insolation_weight: f32,
+
+    /// Formula used for evaporation rate multipliers
+    pub evaporation_model: EvaporationModel,
+
+    /// Surface gravity (m/s²); sets the barometric scale height
+    pub gravity: f32,
 }
 
+/// Specific gas constant of dry air (J/(kg·K)), i.e. R/M in the scale height H = RT/(Mg)
+pub const DRY_AIR_GAS_CONSTANT: f32 = 287.0;
+
 impl Default for ClimateParameters {
     fn default() -> Self {
         Self {
@@ -283,6 +448,12 @@ impl Default for ClimateParameters {
             pressure_temperature_coupling: 500.0, // ~5 hPa pressure change per 10°C temperature difference
             seasonal_pressure_amplitude: 300.0,   // ~3 hPa seasonal pressure variation
             pressure_noise_amplitude: 200.0,      // ~2 hPa random weather perturbations
+
+            insolation_weight: 0.0, // Prescribed gradients only
+
+            evaporation_model: EvaporationModel::ArrheniusLike,
+
+            gravity: EARTH_GRAVITY,
         }
     }
 }
@@ -347,12 +518,61 @@ impl ScaleAware for ClimateParameters {
                 let calculated_noise = self.pressure_noise_amplitude * base_scaling;
                 calculated_noise.max(weather_minimum) // Ensure minimum weather-scale variations
             },
+
+            // Blend weight is dimensionless - doesn't scale
+            insolation_weight: self.insolation_weight,
+
+            evaporation_model: self.evaporation_model,
+
+            // Gravity is a planetary constant - doesn't scale
+            gravity: self.gravity,
         }
     }
 }
 
+impl ClimateParameters {
+    /// Barometric scale height (m) of a column at `temperature_c`
+    /// H = RT/(Mg): cold columns are shallower, so their pressure falls faster with height
+    pub fn scale_height_at(&self, temperature_c: f32) -> f32 {
+        let temperature_k = (temperature_c + 273.15).max(1.0);
+        DRY_AIR_GAS_CONSTANT * temperature_k / self.gravity.max(f32::EPSILON)
+    }
+
+    /// Barometric scale height (m) at the base sea-level temperature
+    pub fn scale_height_m(&self) -> f32 {
+        self.scale_height_at(self.base_temperature_c)
+    }
+}
+
+/// Domain-wide energy ledger for the surface thermal system
+/// Totals are in joules (stocks) or watts (fluxes) over the whole map
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnergyBudget {
+    /// Sensible heat stored in the surface thermal layer, relative to 0 K (J)
+    pub sensible_heat_j: f64,
+    /// Latent heat term of surface liquid water relative to vapor (J, ≤ 0)
+    /// Evaporation raises this by exactly the sensible heat it removes
+    pub latent_heat_j: f64,
+    /// Absorbed insolation minus outgoing longwave radiation (W)
+    pub net_radiative_forcing_w: f64,
+    /// Number of cells included in the budget
+    pub cell_count: usize,
+}
+
+impl EnergyBudget {
+    /// Total conserved energy (sensible + latent) in joules
+    pub fn total_energy_j(&self) -> f64 {
+        self.sensible_heat_j + self.latent_heat_j
+    }
+
+    /// Energy change from a previous budget, in joules
+    pub fn drift_from(&self, previous: &EnergyBudget) -> f64 {
+        self.total_energy_j() - previous.total_energy_j()
+    }
+}
+
 /// Climate system with effective parameters
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ClimateSystem {
     /// Scale-derived climate parameters
     pub parameters: ClimateParameters,
@@ -362,6 +582,20 @@ pub struct ClimateSystem {
     pub seasonal_rate: f32,
     /// Random seed for pressure perturbations (for reproducible weather)
     pub pressure_seed: u64,
+    /// Solar forcing used when insolation_weight is non-zero
+    pub insolation: Insolation,
+    /// Cloud cover shading insolation and evaporation (None = clear sky)
+    pub cloud_layer: Option<CloudLayer>,
+    /// Per-cell surface albedo for insolation (None = planetary mean everywhere)
+    #[serde(default)]
+    pub surface_albedo: Option<PhysicsGrid<f32>>,
+    /// Thermal and synoptic signals combined by the pressure generators
+    pub pressure_strategy: PressureStrategy,
+    /// Fully regenerate pressure on temperature updates, re-seeding the synoptic systems
+    /// False evolves the existing field so highs and lows persist; initialization always generates
+    pub regenerate_pressure: bool,
+    /// Sub-f32 seasonal progress carried between ticks so tiny scaled steps don't stall
+    season_carry: f64,
 }
 
 impl ClimateSystem {
@@ -374,6 +608,12 @@ impl ClimateSystem {
             current_season: 0.5, // Start in late spring/early summer for reasonable temperatures
             seasonal_rate: 1.0 / 3650.0, // One year = ~3650 ticks (10 ticks per day)
             pressure_seed: 12345, // Default seed for reproducible weather
+            insolation: Insolation::for_scale(scale),
+            cloud_layer: None,
+            surface_albedo: None,
+            pressure_strategy: PressureStrategy::default(),
+            regenerate_pressure: false,
+            season_carry: 0.0,
         }
     }
 
@@ -386,29 +626,74 @@ impl ClimateSystem {
             current_season: 0.5, // Start in late spring/early summer for reasonable temperatures
             seasonal_rate: 1.0 / 3650.0,
             pressure_seed: 12345,
+            insolation: Insolation::for_scale(scale),
+            cloud_layer: None,
+            surface_albedo: None,
+            pressure_strategy: PressureStrategy::default(),
+            regenerate_pressure: false,
+            season_carry: 0.0,
         }
     }
 
-    /// Advance seasonal cycle
+    /// Advance seasonal cycle at the unscaled rate
     pub fn tick(&mut self) {
-        self.current_season += self.seasonal_rate;
-        // Keep season in 0.0-1.0 range
-        if self.current_season >= 1.0 {
-            self.current_season -= 1.0;
-        }
+        self.tick_scaled(1.0);
     }
 
     /// Update seasonal cycle with temporal scaling for unified physics consistency
     pub fn tick_scaled(&mut self, temporal_factor: f32) {
         // CRITICAL: Scale seasonal progression rate with temporal factor
-        let scaled_seasonal_rate = self.seasonal_rate * temporal_factor;
-        self.current_season += scaled_seasonal_rate;
-        // Keep season in 0.0-1.0 range
-        if self.current_season >= 1.0 {
-            self.current_season -= 1.0;
+        let scaled_seasonal_rate = self.seasonal_rate as f64 * temporal_factor as f64;
+
+        // Accumulate in f64: realistic-mode increments are below f32 resolution near 0.5
+        // Wrap to keep the season in the 0.0-1.0 range
+        let advanced =
+            (self.current_season as f64 + self.season_carry + scaled_seasonal_rate).rem_euclid(1.0);
+        self.current_season = if advanced as f32 >= 1.0 {
+            0.0
+        } else {
+            advanced as f32
+        };
+        self.season_carry = advanced - self.current_season as f64;
+    }
+
+    /// Cloud shading multiplier (1 - albedo × cloud_fraction) for insolation and evaporation
+    pub fn cloud_shading_factor(&self, x: usize, y: usize) -> f32 {
+        match &self.cloud_layer {
+            Some(clouds) => clouds.shading_factor(x, y),
+            None => 1.0,
         }
     }
 
+    /// Blend a gradient-model temperature with the radiative equilibrium temperature
+    /// for this cell's insolation at the current season (no-op when insolation_weight is 0)
+    fn blend_insolation_temperature(
+        &self,
+        temperature: f32,
+        elevation_cooling: f32,
+        x: usize,
+        y: usize,
+        height: usize,
+    ) -> f32 {
+        let weight = self.parameters.insolation_weight.clamp(0.0, 1.0);
+        if weight <= 0.0 {
+            return temperature;
+        }
+
+        let flux = self.insolation.flux_for_row(y, height, self.current_season)
+            * self.cloud_shading_factor(x, y);
+        let albedo = self
+            .surface_albedo
+            .as_ref()
+            .filter(|albedo| x < albedo.width() && y < albedo.height())
+            .map_or(self.insolation.albedo, |albedo| *albedo.get(x, y));
+        let radiative_temperature = self
+            .insolation
+            .surface_equilibrium_temperature_c(flux, albedo)
+            - elevation_cooling;
+        temperature * (1.0 - weight) + radiative_temperature * weight
+    }
+
     /// Generate temperature layer from heightmap with scale-aware continental climate
     /// This version uses the climate system's pre-scaled parameters
     pub fn generate_temperature_layer(&self, heightmap: &[Vec<f32>]) -> TemperatureLayer {
@@ -426,7 +711,9 @@ impl ClimateSystem {
                 let mut temperature = self.parameters.base_temperature_c;
 
                 // Apply elevation-based cooling (higher = colder)
-                temperature -= elevation.max(0.0) * self.parameters.elevation_lapse_rate * 1000.0;
+                let elevation_cooling =
+                    elevation.max(0.0) * self.parameters.elevation_lapse_rate * 1000.0;
+                temperature -= elevation_cooling;
 
                 // Apply continental-scale north-south temperature gradient
                 // Use normalized position within domain (0.0 = north edge, 1.0 = south edge)
@@ -436,6 +723,10 @@ impl ClimateSystem {
                 // Scale by domain-appropriate gradient (already scaled by ScaleAware)
                 temperature -= distance_from_center * self.parameters.latitude_gradient;
 
+                // Blend in solar-driven temperature when insolation forcing is enabled
+                temperature =
+                    self.blend_insolation_temperature(temperature, elevation_cooling, x, y, height);
+
                 // Clamp to reasonable limits
                 temperature = temperature
                     .max(self.parameters.min_temperature)
@@ -490,6 +781,10 @@ impl ClimateSystem {
                 temperature -=
                     distance_from_center * self.parameters.latitude_gradient * domain_scale_factor;
 
+                // Blend in solar-driven temperature when insolation forcing is enabled
+                temperature =
+                    self.blend_insolation_temperature(temperature, elevation_cooling, x, y, height);
+
                 // Clamp to reasonable limits
                 temperature = temperature
                     .max(self.parameters.min_temperature)
@@ -627,6 +922,57 @@ impl ClimateSystem {
         multiplier.max(0.1).min(10.0)
     }
 
+    /// FAO-56 Penman-Monteith reference evapotranspiration (mm/day)
+    /// Net radiation in W/m², wind speed at 2 m in m/s, relative humidity 0-1;
+    /// soil heat flux is neglected and the psychrometric constant is taken at sea level
+    pub fn penman_monteith_rate(
+        temperature_c: f32,
+        net_radiation_w_m2: f32,
+        wind_speed_ms: f32,
+        relative_humidity: f32,
+    ) -> f32 {
+        const PSYCHROMETRIC_CONSTANT: f32 = 0.0665; // kPa/°C at 101.3 kPa
+        const W_M2_TO_MJ_M2_DAY: f32 = 0.0864;
+
+        let saturation_kpa = 0.6108 * (17.27 * temperature_c / (temperature_c + 237.3)).exp();
+        let actual_kpa = saturation_kpa * relative_humidity.clamp(0.0, 1.0);
+        let slope = 4098.0 * saturation_kpa / (temperature_c + 237.3).powi(2);
+        let net_radiation = net_radiation_w_m2 * W_M2_TO_MJ_M2_DAY;
+        let wind = wind_speed_ms.max(0.0);
+
+        let radiative = 0.408 * slope * net_radiation;
+        let aerodynamic = PSYCHROMETRIC_CONSTANT * 900.0 / (temperature_c + 273.0)
+            * wind
+            * (saturation_kpa - actual_kpa);
+        ((radiative + aerodynamic) / (slope + PSYCHROMETRIC_CONSTANT * (1.0 + 0.34 * wind)))
+            .max(0.0)
+    }
+
+    /// Evaporation multiplier under the configured model, relative to reference conditions
+    /// ArrheniusLike uses temperature alone; PenmanMonteith is normalized so 20°C, 150 W/m²
+    /// net radiation, 2 m/s wind and 50% humidity give 1.0
+    pub fn evaporation_multiplier_for_conditions(
+        &self,
+        temperature_c: f32,
+        net_radiation_w_m2: f32,
+        wind_speed_ms: f32,
+        relative_humidity: f32,
+    ) -> f32 {
+        match self.parameters.evaporation_model {
+            EvaporationModel::ArrheniusLike => self.get_evaporation_multiplier(temperature_c),
+            EvaporationModel::PenmanMonteith => {
+                let reference = Self::penman_monteith_rate(20.0, 150.0, 2.0, 0.5);
+                let rate = Self::penman_monteith_rate(
+                    temperature_c,
+                    net_radiation_w_m2,
+                    wind_speed_ms,
+                    relative_humidity,
+                );
+                (rate / reference).min(10.0)
+            }
+        }
+    }
+
     /// Apply energy-conserving evaporation that removes latent heat from temperature
     /// Fixes the thermodynamic violation identified by Metis mathematical validation
     /// Implementation formula: ΔT = -(evap_depth / water_depth) × 540.0
@@ -752,6 +1098,103 @@ impl ClimateSystem {
         numerator / denominator
     }
 
+    /// Sum the domain-wide energy ledger for the current thermal and water state
+    /// Uses the same thermal mass and latent heat constants as the evaporation cooling step,
+    /// so evaporation moves energy between the sensible and latent terms without changing the total
+    /// Temperature regeneration resets the field to the prescribed climate, so the total is
+    /// conserved between regenerations rather than across them.
+    pub fn energy_budget(
+        &self,
+        temperature_layer: &TemperatureLayer,
+        water: &WaterLayer,
+        scale: &WorldScale,
+    ) -> EnergyBudget {
+        const SURFACE_THERMAL_CAPACITY: f64 = 4_180_000.0; // J/(m³·K) over ~1m thermal depth
+        const LATENT_HEAT_PER_METER: f64 = 2_450_000.0; // J/m³ of liquid water
+        const SOLAR_CONSTANT: f64 = 1361.0; // W/m²
+        const PLANETARY_ALBEDO: f64 = 0.3;
+        const EFFECTIVE_EMISSIVITY: f64 = 0.61; // Greenhouse-adjusted emissivity
+        const STEFAN_BOLTZMANN: f64 = 5.670_374e-8; // W/(m²·K⁴)
+
+        let width = temperature_layer.width().min(water.width());
+        let height = temperature_layer.height().min(water.height());
+        let cell_size_m = scale.meters_per_pixel();
+        let cell_area_m2 = cell_size_m * cell_size_m;
+
+        let mut sensible_heat_j = 0.0;
+        let mut latent_heat_j = 0.0;
+        let mut net_radiative_forcing_w = 0.0;
+
+        for y in 0..height {
+            // Same latitude mapping as the insolation forcing: equator at map center
+            let latitude_rad =
+                (row_latitude_degrees(y, height, scale.physical_size_km) as f64).to_radians();
+            let insolation_w_m2 = SOLAR_CONSTANT / std::f64::consts::PI * latitude_rad.cos();
+            let absorbed_w_m2 = insolation_w_m2 * (1.0 - PLANETARY_ALBEDO);
+
+            for x in 0..width {
+                let temperature_k = temperature_layer.get_temperature(x, y) as f64 + 273.15;
+                let depth_m = water.depth.get(x, y).to_f64();
+
+                sensible_heat_j += SURFACE_THERMAL_CAPACITY * temperature_k * cell_area_m2;
+                latent_heat_j -= LATENT_HEAT_PER_METER * depth_m * cell_area_m2;
+
+                let emitted_w_m2 = EFFECTIVE_EMISSIVITY * STEFAN_BOLTZMANN * temperature_k.powi(4);
+                net_radiative_forcing_w += (absorbed_w_m2 - emitted_w_m2) * cell_area_m2;
+            }
+        }
+
+        EnergyBudget {
+            sensible_heat_j,
+            latent_heat_j,
+            net_radiative_forcing_w,
+            cell_count: width * height,
+        }
+    }
+
+    /// Static stability per cell: dry adiabatic lapse rate minus environmental lapse rate (°C/km)
+    /// The environmental lapse rate is a least-squares fit of temperature against elevation over
+    /// the cell's 3x3 neighborhood; flat neighborhoods fall back to the configured lapse rate.
+    /// Negative values are superadiabatic (unstable, convection-prone); positive values are stable.
+    pub fn static_stability(
+        &self,
+        temperature_layer: &TemperatureLayer,
+        heightmap: &super::super::core::heightmap::HeightMap,
+    ) -> PhysicsGrid<f32> {
+        const DRY_ADIABATIC_LAPSE_RATE: f32 = 9.8; // °C/km
+        const MIN_RELIEF_M2: f32 = 1.0; // Below this Σ(Δz²) the fit is meaningless
+
+        let width = temperature_layer.width().min(heightmap.width());
+        let height = temperature_layer.height().min(heightmap.height());
+        let elevation_m = |x: usize, y: usize| heightmap.get(x, y).max(0.0) * 1000.0;
+        let mut stability = PhysicsGrid::new(width, height, 0.0);
+
+        for y in 0..height {
+            for x in 0..width {
+                let center_z = elevation_m(x, y);
+                let center_t = temperature_layer.get_temperature(x, y);
+                let mut covariance = 0.0;
+                let mut relief = 0.0;
+                for ny in y.saturating_sub(1)..(y + 2).min(height) {
+                    for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                        let dz = elevation_m(nx, ny) - center_z;
+                        let dt = temperature_layer.get_temperature(nx, ny) - center_t;
+                        covariance += dz * dt;
+                        relief += dz * dz;
+                    }
+                }
+
+                let environmental_lapse_rate = if relief >= MIN_RELIEF_M2 {
+                    -covariance / relief * 1000.0
+                } else {
+                    self.parameters.elevation_lapse_rate * 1000.0
+                };
+                stability.set(x, y, DRY_ADIABATIC_LAPSE_RATE - environmental_lapse_rate);
+            }
+        }
+        stability
+    }
+
     /// Generate atmospheric pressure layer from temperature field
     /// Pressure is coupled to temperature through the ideal gas law and hydrostatic balance
     pub fn generate_pressure_layer(
@@ -782,16 +1225,15 @@ impl ClimateSystem {
                 let mut pressure = self.parameters.base_pressure_pa;
 
                 // Apply elevation-based pressure reduction (hydrostatic balance)
-                // Using simplified barometric formula: P = P₀ × exp(-h/H) where H ≈ 8400m (scale height)
-                let scale_height = 8400.0; // meters
+                // Barometric formula P = P₀ × exp(-h/H) with the column's scale height H = RT/(Mg)
+                let scale_height = self.parameters.scale_height_at(temperature_c); // meters
                 let elevation_meters = elevation.max(0.0) * 1000.0; // Convert to meters (assuming elevation is in km)
                 pressure *= (-elevation_meters / scale_height).exp();
 
                 // Apply temperature-pressure coupling (warmer air = lower pressure)
                 // This creates thermal low/high pressure systems
                 let temp_deviation = temperature_c - self.parameters.base_temperature_c;
-                let thermal_pressure_change =
-                    -temp_deviation * self.parameters.pressure_temperature_coupling / 10.0;
+                let thermal_pressure_change = -temp_deviation * self.thermal_coupling() / 10.0;
                 pressure += thermal_pressure_change;
 
                 // Apply seasonal pressure variation
@@ -807,28 +1249,43 @@ impl ClimateSystem {
 
                 // Thermal pressure perturbation: warm areas = lower pressure
                 // Physical basis: warmer air is less dense, creates lower surface pressure
-                let thermal_pressure_perturbation =
-                    -temp_deviation * self.parameters.pressure_temperature_coupling * 0.3;
+                let thermal_pressure_perturbation = -temp_deviation * self.thermal_coupling() * 0.3;
                 pressure += thermal_pressure_perturbation;
 
                 // Apply scale-aware pressure bounds (continental vs regional domains)
                 let (min_pressure, max_pressure) = get_pressure_bounds(scale);
                 pressure = pressure.max(min_pressure).min(max_pressure);
 
-                pressure_layer.pressure.set(x, y, pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(pressure));
             }
         }
 
         // PHASE 2 FIX: Apply realistic synoptic-scale pressure generation
         // This replaces the problematic thermal-only approach with proper atmospheric patterns
-        self.generate_realistic_synoptic_pressure(&mut pressure_layer, scale);
+        if self.pressure_strategy.includes_synoptic() {
+            self.generate_realistic_synoptic_pressure(&mut pressure_layer, scale);
+        }
 
         // Calculate pressure gradients
-        pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        pressure_layer.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
 
         pressure_layer
     }
 
+    /// Temperature-pressure coupling in effect, zero when the strategy excludes thermal pressure
+    fn thermal_coupling(&self) -> f32 {
+        if self.pressure_strategy.includes_thermal() {
+            self.parameters.pressure_temperature_coupling
+        } else {
+            0.0
+        }
+    }
+
     /// PHASE 2: Generate realistic synoptic-scale pressure patterns
     /// Creates organized weather systems with proper gradients for geostrophic balance
     /// Based on SageMath validation: pressure gradients should be 0.0006-0.0032 Pa/m
@@ -856,19 +1313,18 @@ impl ClimateSystem {
         // This creates the large-scale patterns needed for geostrophic balance
         let mut synoptic_pressure = vec![vec![0.0; width]; height];
 
-        for system_idx in 0..num_pressure_systems {
-            // Position systems across domain with some randomization based on pressure_seed
-            let rng_state = self.pressure_seed.wrapping_add(system_idx as u64 * 12345);
-
-            // Create pseudo-random but deterministic positions
-            let center_x_norm = 0.2 + 0.6 * ((rng_state % 1000) as f32 / 999.0);
-            let center_y_norm = 0.2 + 0.6 * (((rng_state / 1000) % 1000) as f32 / 999.0);
+        // Position systems across domain with some randomization based on pressure_seed
+        let mut rng = Rng::new(self.pressure_seed);
+        for _ in 0..num_pressure_systems {
+            // Uniform deterministic positions away from the domain edges
+            let center_x_norm = rng.next_f32_in(0.2, 0.8);
+            let center_y_norm = rng.next_f32_in(0.2, 0.8);
 
             let center_x = (center_x_norm * width as f32) as usize;
             let center_y = (center_y_norm * height as f32) as usize;
 
             // Determine system type (high vs low pressure) based on seed
-            let is_high_pressure = (rng_state % 2) == 0;
+            let is_high_pressure = rng.next_bool();
             // Scale pressure amplitude based on domain size to create appropriate gradients
             // Use larger amplitudes to create synoptic-scale gradients
             let base_amplitude = 25.0f32; // ±0.25 hPa base amplitude for realistic gradients (was 2500.0 - too strong by 100x)
@@ -908,14 +1364,16 @@ impl ClimateSystem {
         // Add synoptic patterns to existing pressure field
         for y in 0..height {
             for x in 0..width {
-                let current_pressure = *pressure_layer.pressure.get(x, y);
+                let current_pressure = pressure_layer.pressure.get(x, y).to_f32();
                 let new_pressure = current_pressure + synoptic_pressure[y][x];
 
                 // Apply scale-aware pressure bounds
                 let (min_pressure, max_pressure) = get_pressure_bounds(scale);
                 let bounded_pressure = new_pressure.max(min_pressure).min(max_pressure);
 
-                pressure_layer.pressure.set(x, y, bounded_pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(bounded_pressure));
             }
         }
 
@@ -978,7 +1436,8 @@ impl ClimateSystem {
         let virtual_grid_size = 320; // High resolution for realistic patterns
         
         // Generate realistic synoptic patterns on virtual domain
-        let virtual_pressure = self.generate_virtual_synoptic_field(virtual_grid_size);
+        let mut rng = Rng::new(self.pressure_seed);
+        let virtual_pressure = self.generate_virtual_synoptic_field(virtual_grid_size, &mut rng);
         
         // Calculate crop parameters to extract small domain section
         let pixels_per_km = virtual_grid_size as f32 / VIRTUAL_DOMAIN_KM;
@@ -990,16 +1449,10 @@ impl ClimateSystem {
         let crop_size_y = crop_size_y.min(virtual_grid_size);
         
         // Randomly select crop position using deterministic seed
-        let crop_rng = self.pressure_seed.wrapping_mul(7919); // Prime number for mixing
         let max_crop_x = virtual_grid_size.saturating_sub(crop_size_x);
         let max_crop_y = virtual_grid_size.saturating_sub(crop_size_y);
-        
-        let crop_start_x = if max_crop_x > 0 { 
-            (crop_rng % max_crop_x as u64) as usize 
-        } else { 0 };
-        let crop_start_y = if max_crop_y > 0 { 
-            ((crop_rng / 1000) % max_crop_y as u64) as usize 
-        } else { 0 };
+        let crop_start_x = rng.next_usize_below(max_crop_x);
+        let crop_start_y = rng.next_usize_below(max_crop_y);
         
         // Apply cropped synoptic patterns to pressure layer
         for y in 0..height {
@@ -1009,43 +1462,49 @@ impl ClimateSystem {
                 let virtual_y = crop_start_y + (y * crop_size_y / height).min(crop_size_y - 1);
                 
                 let synoptic_pressure = virtual_pressure[virtual_y][virtual_x];
-                let current_pressure = *pressure_layer.pressure.get(x, y);
+                let current_pressure = pressure_layer.pressure.get(x, y).to_f32();
                 let new_pressure = current_pressure + synoptic_pressure;
                 
                 // Apply scale-aware pressure bounds
                 let (min_pressure, max_pressure) = get_pressure_bounds(scale);
                 let bounded_pressure = new_pressure.max(min_pressure).min(max_pressure);
                 
-                pressure_layer.pressure.set(x, y, bounded_pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(bounded_pressure));
             }
         }
         
         // Validate that the fix produces realistic gradients
-        pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        pressure_layer.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
         self.validate_pressure_gradients(pressure_layer, scale);
     }
     
     /// Generate synoptic pressure field on virtual domain for cropping
     /// Creates multiple realistic pressure systems (highs/lows) with proper spatial scale
-    fn generate_virtual_synoptic_field(&self, virtual_grid_size: usize) -> Vec<Vec<f32>> {
+    fn generate_virtual_synoptic_field(
+        &self,
+        virtual_grid_size: usize,
+        rng: &mut Rng,
+    ) -> Vec<Vec<f32>> {
         let mut virtual_pressure = vec![vec![0.0; virtual_grid_size]; virtual_grid_size];
         
         // Generate 3 pressure systems across virtual domain (realistic for 500km)
         let num_systems = 3;
         
-        for system_idx in 0..num_systems {
-            // Use pressure_seed for deterministic but varied system placement
-            let rng_state = self.pressure_seed.wrapping_add(system_idx as u64 * 12345);
-            
+        for _ in 0..num_systems {
             // Position systems with good separation
-            let center_x_norm = 0.2 + 0.6 * ((rng_state % 1000) as f32 / 999.0);
-            let center_y_norm = 0.2 + 0.6 * (((rng_state / 1000) % 1000) as f32 / 999.0);
+            let center_x_norm = rng.next_f32_in(0.2, 0.8);
+            let center_y_norm = rng.next_f32_in(0.2, 0.8);
             
             let center_x = (center_x_norm * virtual_grid_size as f32) as usize;
             let center_y = (center_y_norm * virtual_grid_size as f32) as usize;
             
-            // Alternate between high and low pressure systems
-            let is_high_pressure = (rng_state % 2) == 0;
+            // Mix of high and low pressure systems
+            let is_high_pressure = rng.next_bool();
             let pressure_amplitude = if is_high_pressure { 25.0 } else { -25.0 }; // ±0.25 hPa (was 2500.0 - too strong by 100x)
             
             // Typical synoptic system radius (~200km for 500km domain)
@@ -1135,30 +1594,36 @@ impl ClimateSystem {
                 let mut pressure = self.parameters.base_pressure_pa;
 
                 // Apply elevation-based pressure reduction (hydrostatic balance)
-                let scale_height = 8400.0; // meters
+                let scale_height = self.parameters.scale_height_at(temperature_c); // meters
                 let elevation_meters = elevation.max(0.0) * 1000.0; // Convert to meters
                 pressure *= (-elevation_meters / scale_height).exp();
 
                 // Apply thermal circulation physics (warm areas = low pressure, cool areas = high pressure)
                 let temp_deviation = temperature_c - self.parameters.base_temperature_c;
-                let thermal_pressure_change =
-                    -temp_deviation * self.parameters.pressure_temperature_coupling / 10.0;
+                let thermal_pressure_change = -temp_deviation * self.thermal_coupling() / 10.0;
                 pressure += thermal_pressure_change;
 
                 // Apply scale-aware pressure bounds (continental vs regional domains)
                 let (min_pressure, max_pressure) = get_pressure_bounds(scale);
                 pressure = pressure.max(min_pressure).min(max_pressure);
 
-                pressure_layer.pressure.set(x, y, pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(pressure));
             }
         }
 
         // PHASE 2 FIX: Apply realistic synoptic-scale pressure generation
         // This replaces the problematic thermal-only approach with proper atmospheric patterns
-        self.generate_realistic_synoptic_pressure(&mut pressure_layer, scale);
+        if self.pressure_strategy.includes_synoptic() {
+            self.generate_realistic_synoptic_pressure(&mut pressure_layer, scale);
+        }
 
         // Calculate pressure gradients
-        pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        pressure_layer.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
 
         pressure_layer
     }
@@ -1254,11 +1719,10 @@ impl ClimateSystem {
         let base_pressure = self.parameters.base_pressure_pa;
         let base_temp_c = self.parameters.base_temperature_c;
         // Removed: noise_amplitude - no longer using random pressure noise
-        let scale_height_inv = 1.0 / 8400.0; // Pre-calculate reciprocal for faster division
         // Removed: rng_base - no longer needed without pressure noise
 
         // Process rows in parallel and collect results
-        let pressure_rows: Vec<Vec<f32>> = (0..height)
+        let pressure_rows: Vec<Vec<StateFloat>> = (0..height)
             .into_par_iter()
             .map(|y| {
                 // Removed: rng_state - no longer using random pressure generation
@@ -1276,12 +1740,15 @@ impl ClimateSystem {
 
                     // Apply elevation-based pressure reduction (vectorizable exp operation)
                     let elevation_meters = elevation.max(0.0) * 1000.0;
-                    pressure *= (-elevation_meters * scale_height_inv).exp();
+                    let scale_height = self.parameters.scale_height_at(temperature_c);
+                    pressure *= (-elevation_meters / scale_height).exp();
 
                     // Apply temperature-induced pressure variation (vectorizable)
                     let temp_deviation = temperature_c - base_temp_c;
-                    let thermal_pressure_factor = 1.0 - (temp_deviation * 0.002);
-                    pressure *= thermal_pressure_factor;
+                    if self.pressure_strategy.includes_thermal() {
+                        let thermal_pressure_factor = 1.0 - (temp_deviation * 0.002);
+                        pressure *= thermal_pressure_factor;
+                    }
 
                     // Apply thermal circulation: warmer areas get lower pressure
                     // This replaces random noise with physics-based pressure patterns
@@ -1290,15 +1757,14 @@ impl ClimateSystem {
 
                     // Thermal pressure effect: ΔP = -ρg(ΔT/T₀) × scale_height
                     // Simplified: warm areas (positive ΔT) get negative pressure perturbation
-                    let thermal_pressure_effect =
-                        -temp_deviation * self.parameters.pressure_temperature_coupling * 0.2;
+                    let thermal_pressure_effect = -temp_deviation * self.thermal_coupling() * 0.2;
                     pressure += thermal_pressure_effect;
 
                     // Apply scale-aware pressure bounds (continental vs regional domains)
                     let (min_pressure, max_pressure) = get_pressure_bounds(scale);
                     pressure = pressure.max(min_pressure).min(max_pressure);
 
-                    row_pressures.push(pressure);
+                    row_pressures.push(StateFloat::from_f32(pressure));
                 }
 
                 row_pressures
@@ -1308,9 +1774,15 @@ impl ClimateSystem {
         // Assemble the pressure layer
         let mut pressure_layer = AtmosphericPressureLayer::new(width, height);
         pressure_layer.pressure = PhysicsGrid::from_nested(pressure_rows);
+        if self.pressure_strategy.includes_synoptic() {
+            self.generate_realistic_synoptic_pressure(&mut pressure_layer, scale);
+        }
 
         // Calculate pressure gradients
-        pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        pressure_layer.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
 
         pressure_layer
     }
@@ -1424,14 +1896,13 @@ impl ClimateSystem {
                 let mut target_pressure = self.parameters.base_pressure_pa;
 
                 // Apply elevation-based pressure reduction (hydrostatic balance)
-                let scale_height = 8400.0; // meters
+                let scale_height = self.parameters.scale_height_at(temperature_c); // meters
                 let elevation_meters = elevation.max(0.0) * 1000.0; // Convert to meters
                 target_pressure *= (-elevation_meters / scale_height).exp();
 
                 // Apply temperature-pressure coupling (warmer air = lower pressure)
                 let temp_deviation = temperature_c - self.parameters.base_temperature_c;
-                let thermal_pressure_change =
-                    -temp_deviation * self.parameters.pressure_temperature_coupling / 10.0;
+                let thermal_pressure_change = -temp_deviation * self.thermal_coupling() / 10.0;
                 target_pressure += thermal_pressure_change;
 
                 // Apply seasonal pressure variation
@@ -1440,7 +1911,7 @@ impl ClimateSystem {
 
                 // Add spatial pressure smoothing for realistic circulation patterns
                 // This replaces random noise evolution with physically-motivated dynamics
-                let current_pressure_val = *current_pressure.pressure.get(x, y);
+                let current_pressure_val = current_pressure.pressure.get(x, y).to_f32();
 
                 // Calculate pressure gradient from neighboring cells for circulation effects
                 let mut neighbor_pressure_sum = 0.0;
@@ -1454,7 +1925,7 @@ impl ClimateSystem {
                         }
                         let nx = (x as i32 + dx).max(0).min(width as i32 - 1) as usize;
                         let ny = (y as i32 + dy).max(0).min(height as i32 - 1) as usize;
-                        neighbor_pressure_sum += *current_pressure.pressure.get(nx, ny);
+                        neighbor_pressure_sum += current_pressure.pressure.get(nx, ny).to_f32();
                         neighbor_count += 1;
                     }
                 }
@@ -1473,13 +1944,16 @@ impl ClimateSystem {
                 current_pressure.pressure.set(
                     x,
                     y,
-                    new_pressure.max(min_pressure).min(max_pressure),
+                    StateFloat::from_f32(new_pressure.max(min_pressure).min(max_pressure)),
                 );
             }
         }
 
         // Recalculate pressure gradients after evolution
-        current_pressure.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        current_pressure.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
     }
 
     /// SIMD-optimized pressure evolution for better performance
@@ -1501,12 +1975,11 @@ impl ClimateSystem {
         let base_pressure = self.parameters.base_pressure_pa;
         let base_temp_c = self.parameters.base_temperature_c;
         // Removed: noise_amplitude - replaced with thermal circulation physics
-        let scale_height_inv = 1.0 / 8400.0; // Pre-calculate reciprocal
         let (min_pressure, max_pressure) = get_pressure_bounds(scale); // Pre-calculate pressure bounds
         // Removed: rng_base for pressure evolution - using thermal circulation
         let seasonal_factor = (self.current_season * 2.0 * std::f32::consts::PI).sin()
             * self.parameters.seasonal_pressure_amplitude;
-        let thermal_coupling = self.parameters.pressure_temperature_coupling / 10.0;
+        let thermal_coupling = self.thermal_coupling() / 10.0;
 
         // Process in parallel using PhysicsGrid data_mut() for SIMD access
         current_pressure
@@ -1525,7 +1998,8 @@ impl ClimateSystem {
 
                 // Calculate target pressure
                 let elevation_meters = elevation.max(0.0) * 1000.0;
-                let elevation_factor = (-elevation_meters * scale_height_inv).exp();
+                let scale_height = self.parameters.scale_height_at(temperature_c);
+                let elevation_factor = (-elevation_meters / scale_height).exp();
                 let temp_deviation = temperature_c - base_temp_c;
                 let thermal_change = -temp_deviation * thermal_coupling;
 
@@ -1533,16 +2007,20 @@ impl ClimateSystem {
                     base_pressure * elevation_factor + thermal_change + seasonal_factor;
 
                 // Gradually evolve toward target pressure (no noise - using thermal circulation)
-                let current_pressure_val = *pressure_cell;
+                let current_pressure_val = pressure_cell.to_f32();
                 let pressure_change = (target_pressure - current_pressure_val) * evolution_rate;
                 let new_pressure = current_pressure_val + pressure_change;
 
                 // Apply scale-aware pressure bounds (continental vs regional domains)
-                *pressure_cell = new_pressure.max(min_pressure).min(max_pressure);
+                *pressure_cell =
+                    StateFloat::from_f32(new_pressure.max(min_pressure).min(max_pressure));
             });
 
         // Recalculate pressure gradients after evolution
-        current_pressure.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        current_pressure.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
     }
 
     /// Generate temperature layer with temporal scaling for unified physics consistency
@@ -1579,6 +2057,10 @@ impl ClimateSystem {
                 temperature -=
                     distance_from_center * self.parameters.latitude_gradient * domain_scale_factor;
 
+                // Blend in solar-driven temperature when insolation forcing is enabled
+                temperature =
+                    self.blend_insolation_temperature(temperature, elevation_cooling, x, y, height);
+
                 // TEMPORAL SCALING: Scale seasonal variations with temporal factor
                 // Faster time = stronger seasonal effects become apparent faster
                 let scaled_seasonal_amplitude = self.parameters.seasonal_amplitude * temporal_factor.sqrt();
@@ -1633,14 +2115,13 @@ impl ClimateSystem {
                 let mut target_pressure = self.parameters.base_pressure_pa;
 
                 // Apply elevation-based pressure reduction (hydrostatic balance)
-                let scale_height = 8400.0; // meters
+                let scale_height = self.parameters.scale_height_at(temperature_c); // meters
                 let elevation_meters = elevation.max(0.0) * 1000.0; // Convert to meters
                 target_pressure *= (-elevation_meters / scale_height).exp();
 
                 // Apply temperature-pressure coupling (warmer air = lower pressure)
                 let temp_deviation = temperature_c - self.parameters.base_temperature_c;
-                let thermal_pressure_change =
-                    -temp_deviation * self.parameters.pressure_temperature_coupling / 10.0;
+                let thermal_pressure_change = -temp_deviation * self.thermal_coupling() / 10.0;
                 target_pressure += thermal_pressure_change;
 
                 // Apply seasonal pressure variation
@@ -1648,7 +2129,7 @@ impl ClimateSystem {
                 target_pressure += seasonal_factor * self.parameters.seasonal_pressure_amplitude;
 
                 // Add spatial pressure smoothing for realistic circulation patterns
-                let current_pressure_val = *current_pressure.pressure.get(x, y);
+                let current_pressure_val = current_pressure.pressure.get(x, y).to_f32();
 
                 // Calculate pressure gradient from neighboring cells for circulation effects
                 let mut neighbor_pressure_sum = 0.0;
@@ -1664,7 +2145,7 @@ impl ClimateSystem {
                         let nx = (x as i32 + dx).max(0).min(width as i32 - 1) as usize;
                         let ny = (y as i32 + dy).max(0).min(height as i32 - 1) as usize;
 
-                        neighbor_pressure_sum += *current_pressure.pressure.get(nx, ny);
+                        neighbor_pressure_sum += current_pressure.pressure.get(nx, ny).to_f32();
                         neighbor_count += 1;
                     }
                 }
@@ -1691,12 +2172,17 @@ impl ClimateSystem {
                     .max(min_pressure)
                     .min(max_pressure);
 
-                current_pressure.pressure.set(x, y, new_pressure);
+                current_pressure
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(new_pressure));
             }
         }
 
         // Recalculate pressure gradients after evolution
-        current_pressure.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
+        current_pressure.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
     }
 }
 
@@ -1737,6 +2223,68 @@ mod tests {
         assert!(large_scaled.latitude_gradient > small_scaled.latitude_gradient);
     }
 
+    #[test]
+    fn insolation_weight_drives_polar_winter_cold() {
+        let scale = WorldScale::new(10000.0, (40, 40), DetailLevel::Standard);
+        let heightmap = vec![vec![0.0; 40]; 40];
+
+        let mut gradient_only = ClimateSystem::new_for_scale(&scale);
+        gradient_only.current_season = 0.0; // Northern winter
+        let mut solar_driven = gradient_only.clone();
+        solar_driven.parameters.insolation_weight = 1.0;
+
+        // Zero weight must reproduce the prescribed gradient model exactly
+        let mut zero_weight = gradient_only.clone();
+        zero_weight.parameters.insolation_weight = 0.0;
+        let baseline = gradient_only.generate_temperature_layer(&heightmap);
+        let unchanged = zero_weight.generate_temperature_layer(&heightmap);
+        for y in 0..40 {
+            for x in 0..40 {
+                assert_eq!(
+                    baseline.get_temperature(x, y),
+                    unchanged.get_temperature(x, y)
+                );
+            }
+        }
+
+        // Top row is the winter pole: polar night should pin it to the cold limit
+        let solar = solar_driven.generate_temperature_layer(&heightmap);
+        let north_edge = solar.get_temperature(20, 0);
+        let south_edge = solar.get_temperature(20, 39);
+        assert!(
+            north_edge < baseline.get_temperature(20, 0) - 20.0,
+            "Winter pole should be much colder under insolation forcing: {:.1}°C",
+            north_edge
+        );
+        assert!(
+            south_edge > north_edge + 20.0,
+            "Summer hemisphere should be warmer than the winter pole"
+        );
+    }
+
+    #[test]
+    fn surface_albedo_cools_bright_ground_under_insolation() {
+        let scale = WorldScale::new(1000.0, (20, 20), DetailLevel::Standard);
+        let heightmap = vec![vec![0.0; 20]; 20];
+        let mut climate = ClimateSystem::new_for_scale(&scale);
+        climate.parameters.insolation_weight = 1.0;
+        let planetary = climate.generate_temperature_layer(&heightmap);
+
+        // Dark forest on the western half, bright desert on the eastern half
+        let mut albedo = PhysicsGrid::new(20, 20, 0.35);
+        for y in 0..20 {
+            for x in 0..10 {
+                albedo.set(x, y, 0.12);
+            }
+        }
+        climate.surface_albedo = Some(albedo);
+        let surface = climate.generate_temperature_layer(&heightmap);
+
+        // Both surfaces differ from the 0.3 planetary mean in the expected direction
+        assert!(surface.get_temperature(5, 10) > planetary.get_temperature(5, 10) + 1.0);
+        assert!(surface.get_temperature(15, 10) < planetary.get_temperature(15, 10));
+    }
+
     #[test]
     fn temperature_generation_from_heightmap() {
         let heightmap = vec![
@@ -1923,6 +2471,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cold_column_has_lower_pressure_aloft_than_warm_column() {
+        let heightmap = vec![vec![0.5, 0.5]];
+        let scale = WorldScale::new(10.0, (2, 1), DetailLevel::Standard);
+        let mut climate = ClimateSystem::new_for_scale(&scale);
+        // Isolate hydrostatics: no thermal low/high coupling
+        climate.parameters.pressure_temperature_coupling = 0.0;
+        let mut temperature = TemperatureLayer::new(2, 1);
+        temperature.temperature.set(0, 0, -30.0);
+        temperature.temperature.set(1, 0, 30.0);
+
+        let pressure = climate.generate_pressure_layer(&temperature, &heightmap, &scale);
+        let (cold, warm) = (pressure.get_pressure(0, 0), pressure.get_pressure(1, 0));
+        assert!(
+            cold < warm,
+            "cold {cold:.0} Pa should be below warm {warm:.0} Pa"
+        );
+
+        // Both follow the barometric formula with their own scale height
+        let base = climate.parameters.base_pressure_pa;
+        let expected_cold = base * (-500.0 / climate.parameters.scale_height_at(-30.0)).exp();
+        assert!((cold - expected_cold).abs() < 1.0);
+        assert!(
+            climate.parameters.scale_height_at(-30.0) < climate.parameters.scale_height_at(30.0)
+        );
+    }
+
+    #[test]
+    fn thermal_only_pressure_has_no_synoptic_systems() {
+        // Flat 400 km domain warming gently eastward
+        let heightmap =
+            crate::engine::core::heightmap::HeightMap::from_nested(vec![vec![0.0; 20]; 20]);
+        let scale = WorldScale::new(400.0, (20, 20), DetailLevel::Standard);
+        let mut temperature = TemperatureLayer::new(20, 20);
+        for y in 0..20 {
+            for x in 0..20 {
+                temperature.temperature.set(x, y, 15.0 + 0.001 * x as f32);
+            }
+        }
+        let mut climate = ClimateSystem::new_for_scale(&scale);
+        let pressure_with = |climate: &ClimateSystem, temperature: &TemperatureLayer| {
+            climate.generate_pressure_layer_optimized(temperature, &heightmap, &scale)
+        };
+        // Pressure falls strictly as temperature rises along every row
+        let monotonic = |layer: &AtmosphericPressureLayer| {
+            (0..20)
+                .all(|y| (1..20).all(|x| layer.get_pressure(x, y) < layer.get_pressure(x - 1, y)))
+        };
+
+        climate.pressure_strategy = PressureStrategy::ThermalOnly;
+        let thermal = pressure_with(&climate, &temperature);
+        assert!(monotonic(&thermal));
+
+        climate.pressure_strategy = PressureStrategy::Hybrid;
+        let hybrid = pressure_with(&climate, &temperature);
+        assert!(!monotonic(&hybrid));
+
+        // Synoptic-only pressure is the hybrid field without the thermal gradient
+        climate.pressure_strategy = PressureStrategy::SynopticOnly;
+        let synoptic = pressure_with(&climate, &temperature);
+        temperature.temperature.fill(15.0);
+        assert_eq!(
+            pressure_with(&climate, &temperature).pressure.data(),
+            synoptic.pressure.data()
+        );
+    }
+
     #[test]
     fn pressure_parameters_scaling() {
         let base_params = ClimateParameters::default();
@@ -2191,4 +2806,170 @@ mod tests {
         println!("✓ TemperatureLayer energy conservation functionality verified");
         println!("Ready for PhysicsGrid migration while preserving thermodynamic accuracy");
     }
+
+    #[test]
+    fn geopotential_height_matches_isothermal_hypsometric_equation() {
+        let mut temperature = TemperatureLayer::new(4, 3);
+        let mut pressure = AtmosphericPressureLayer::new(4, 3);
+        for y in 0..3 {
+            for x in 0..4 {
+                temperature.temperature.set(x, y, -23.15); // 250 K isothermal column
+            }
+        }
+        pressure.pressure.set(2, 1, 100000.0);
+
+        let parameters = ClimateParameters::default();
+        let heights = pressure.geopotential_height(50000.0, &temperature, &parameters);
+
+        // z = (R_d·T/g)·ln(p0/p) for an isothermal atmosphere
+        let scale_height = 287.0 * 250.0 / 9.81;
+        let expected = scale_height * (101325.0f32 / 50000.0).ln();
+        assert!((*heights.get(0, 0) - expected).abs() < 1.0);
+        let expected_low = scale_height * 2.0f32.ln();
+        assert!((*heights.get(2, 1) - expected_low).abs() < 1.0);
+        // Surface pressure level sits at sea level
+        let surface = pressure.geopotential_height(101325.0, &temperature, &parameters);
+        assert!(surface.get(0, 0).abs() < 1e-3);
+
+        // Lower gravity gives a deeper column
+        let mars_like = ClimateParameters {
+            gravity: 3.71,
+            ..ClimateParameters::default()
+        };
+        let deeper = pressure.geopotential_height(50000.0, &temperature, &mars_like);
+        assert!((*deeper.get(0, 0) - expected * 9.81 / 3.71).abs() < 5.0);
+    }
+
+    #[test]
+    fn pressure_extrema_locate_synthetic_low() {
+        // Bowl-shaped low centered on (7, 5), 10 hPa deep
+        let mut pressure = AtmosphericPressureLayer::new(16, 12);
+        for y in 0..12 {
+            for x in 0..16 {
+                let r2 = (x as f32 - 7.0).powi(2) + (y as f32 - 5.0).powi(2);
+                pressure.pressure.set(
+                    x,
+                    y,
+                    StateFloat::from_f32(101325.0 - 1000.0 * (-r2 / 18.0).exp()),
+                );
+            }
+        }
+        pressure.calculate_pressure_gradients(1000.0);
+
+        let (lows, highs) = pressure.pressure_extrema();
+        assert_eq!(lows.len(), 1);
+        assert_eq!((lows[0].x, lows[0].y), (7, 5));
+        assert!((lows[0].pressure - 100325.0).abs() < 1.0);
+        assert!(highs.is_empty());
+
+        // Steepest gradient sits on the flank of the bowl, not at its center
+        let (x, y, magnitude) = pressure.max_gradient_cell();
+        assert_eq!(magnitude, pressure.get_max_pressure_gradient_magnitude());
+        let r = ((x as f32 - 7.0).powi(2) + (y as f32 - 5.0).powi(2)).sqrt();
+        assert!((1.5..=5.0).contains(&r), "gradient peak at radius {}", r);
+    }
+
+    #[test]
+    fn static_stability_flags_superadiabatic_columns() {
+        use crate::engine::core::heightmap::HeightMap;
+
+        // Terrain rises 100 m per cell eastward
+        let heightmap = HeightMap::from_nested(
+            (0..5)
+                .map(|_| (0..6).map(|x| x as f32 * 0.1).collect())
+                .collect(),
+        );
+        let scale = WorldScale::new(10.0, (6, 5), DetailLevel::Standard);
+        let climate = ClimateSystem::new_for_scale(&scale);
+
+        let profile = |cooling_per_cell: f32| {
+            let mut layer = TemperatureLayer::new(6, 5);
+            for y in 0..5 {
+                for x in 0..6 {
+                    layer
+                        .temperature
+                        .set(x, y, 20.0 - cooling_per_cell * x as f32);
+                }
+            }
+            layer
+        };
+
+        // 15 °C/km exceeds the dry adiabatic rate
+        let unstable = climate.static_stability(&profile(1.5), &heightmap);
+        // 5 °C/km is below it
+        let stable = climate.static_stability(&profile(0.5), &heightmap);
+        for y in 0..5 {
+            for x in 0..6 {
+                assert!((*unstable.get(x, y) - (9.8 - 15.0)).abs() < 1e-2);
+                assert!((*stable.get(x, y) - (9.8 - 5.0)).abs() < 1e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn penman_evaporation_responds_to_wind_but_arrhenius_does_not() {
+        let scale = WorldScale::new(10.0, (10, 10), DetailLevel::Standard);
+        let mut climate = ClimateSystem::new_for_scale(&scale);
+        assert_eq!(
+            climate.parameters.evaporation_model,
+            EvaporationModel::ArrheniusLike
+        );
+        let calm = climate.evaporation_multiplier_for_conditions(25.0, 150.0, 0.5, 0.5);
+        let windy = climate.evaporation_multiplier_for_conditions(25.0, 150.0, 8.0, 0.5);
+        assert_eq!(calm, windy);
+        assert_eq!(calm, climate.get_evaporation_multiplier(25.0));
+
+        climate.parameters.evaporation_model = EvaporationModel::PenmanMonteith;
+        let reference = climate.evaporation_multiplier_for_conditions(20.0, 150.0, 2.0, 0.5);
+        assert!((reference - 1.0).abs() < 1e-5);
+        let calm = climate.evaporation_multiplier_for_conditions(25.0, 150.0, 0.5, 0.5);
+        let windy = climate.evaporation_multiplier_for_conditions(25.0, 150.0, 8.0, 0.5);
+        assert!(windy > calm * 1.2, "calm {calm}, windy {windy}");
+        // Saturated air leaves only the radiative term
+        let humid = climate.evaporation_multiplier_for_conditions(25.0, 150.0, 8.0, 1.0);
+        assert!(humid < windy);
+    }
+
+    #[test]
+    fn pressure_gradients_use_per_axis_spacing() {
+        use crate::engine::core::scale::WorldScaleBuilder;
+
+        // 2 km wide, 0.5 km tall cells
+        let scale = WorldScaleBuilder::new((20, 20))
+            .physical_size_km(40.0, 10.0)
+            .build();
+        let mut pressure = AtmosphericPressureLayer::new(20, 20);
+        for y in 0..20 {
+            for x in 0..20 {
+                pressure.pressure.set(
+                    x,
+                    y,
+                    StateFloat::from_f32(100000.0 + 100.0 * x as f32 + 100.0 * y as f32),
+                );
+            }
+        }
+
+        pressure.calculate_pressure_gradients_xy(
+            scale.meters_per_pixel_x() as f32,
+            scale.meters_per_pixel_y() as f32,
+        );
+        for (x, y) in [(0, 0), (10, 10), (19, 19)] {
+            let gradient = pressure.get_pressure_gradient(x, y);
+            assert!((gradient.x - 0.05).abs() < 1e-4, "dP/dx {}", gradient.x);
+            assert!((gradient.y - 0.2).abs() < 1e-4, "dP/dy {}", gradient.y);
+        }
+
+        // Square cells match the single-spacing form
+        let mut square = pressure.clone();
+        square.calculate_pressure_gradients(1000.0);
+        pressure.calculate_pressure_gradients_xy(1000.0, 1000.0);
+        for y in 0..20 {
+            for x in 0..20 {
+                assert_eq!(
+                    square.get_pressure_gradient(x, y),
+                    pressure.get_pressure_gradient(x, y)
+                );
+            }
+        }
+    }
 }
@@ -4,6 +4,7 @@
 // ABOUTME: Grid convergence testing framework for validating simulation scaling behavior
 // ABOUTME: Provides tools to test that simulations converge as grid resolution increases
 
+use super::super::core::physics_grid::Float;
 use super::super::core::scale::{DetailLevel, WorldScale};
 use super::climate::ClimateSystem;
 use super::{DiamondSquareConfig, DiamondSquareGenerator, TerrainGenerator};
@@ -218,7 +219,7 @@ impl ConvergenceStudy {
             .water
             .depth
             .iter()
-            .fold(0.0f32, |max, depth| max.max(depth)) as f64
+            .fold(0.0, |max: f64, depth| max.max(depth.to_f64()))
     }
 
     /// Compute water distribution entropy (measure of how spread out water is)
@@ -230,6 +231,7 @@ impl ConvergenceStudy {
 
         let mut entropy = 0.0;
         for depth in simulation.water.depth.iter() {
+            let depth = depth.to_f32();
             if depth > 0.0 {
                 let probability = depth / total_water;
                 entropy -= (probability as f64) * (probability as f64).ln();
@@ -5,6 +5,7 @@
 // ABOUTME: Migrated to use unified FlowEngine with conservation-based shallow water physics
 
 use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::physics_grid::{Float, StateFloat};
 use crate::engine::core::scale::WorldScale;
 use crate::engine::diagnostics::water_flow_validation::safety_parameters;
 use crate::engine::physics::drainage::DrainageNetwork;
@@ -204,7 +205,7 @@ impl CorrectedWaterFlowSystem {
     /// Add rainfall using base system (already correct)
     fn add_rainfall(&self, water: &mut WaterLayer) {
         for depth in water.depth.iter_mut() {
-            *depth += self.base_system.effective_rainfall_rate;
+            *depth += StateFloat::from_f32(self.base_system.effective_rainfall_rate);
         }
     }
 
@@ -215,7 +216,7 @@ impl CorrectedWaterFlowSystem {
             for x in 0..water.width() {
                 let velocity = water.velocity.get(x, y);
                 let flow_speed = (velocity.0 * velocity.0 + velocity.1 * velocity.1).sqrt();
-                let water_depth = water.depth.get(x, y);
+                let water_depth = water.depth.get(x, y).to_f32();
 
                 let erosion_flow_threshold = self.base_system.evaporation_threshold * 20.0;
                 let erosion_depth_threshold = self.base_system.evaporation_threshold * 5.0;
@@ -249,8 +250,8 @@ impl CorrectedWaterFlowSystem {
     /// Apply evaporation using base system (already correct)
     fn apply_evaporation(&self, water: &mut WaterLayer) {
         for depth in water.depth.iter_mut() {
-            *depth *= 1.0 - self.base_system.parameters.evaporation_rate;
-            if *depth < self.base_system.evaporation_threshold {
+            *depth *= StateFloat::from_f32(1.0 - self.base_system.parameters.evaporation_rate);
+            if depth.to_f32() < self.base_system.evaporation_threshold {
                 *depth = 0.0;
             }
         }
@@ -258,7 +259,7 @@ impl CorrectedWaterFlowSystem {
         // Handle sediment settling
         for y in 0..water.height() {
             for x in 0..water.width() {
-                if water.depth.get(x, y) < self.base_system.evaporation_threshold {
+                if water.depth.get(x, y).to_f32() < self.base_system.evaporation_threshold {
                     let current_sediment = water.sediment.get(x, y);
                     water.sediment.set(x, y, current_sediment * 0.5);
                 }
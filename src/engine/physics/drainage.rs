@@ -4,13 +4,16 @@
 // ABOUTME: Drainage network calculation for realistic water body formation using watershed analysis
 // ABOUTME: Implements D8 flow direction, flow accumulation, and water concentration algorithms
 
-use super::super::core::heightmap::HeightMap;
+use super::super::core::heightmap::{HeightMap, PitMethod};
+use super::super::core::physics_grid::{Float, PhysicsGrid, StateFloat};
 use super::super::core::scale::{ScaleAware, WorldScale};
 use super::water::WaterLayer;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
 
 /// Eight-direction flow direction encoding for D8 algorithm
 /// Uses bit flags for efficient storage and processing
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum FlowDirection {
     East = 1,        // →
@@ -72,7 +75,7 @@ impl FlowDirection {
 }
 
 /// Flow direction map for efficient drainage network calculation
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FlowDirectionMap {
     directions: Vec<FlowDirection>,
     width: usize,
@@ -82,6 +85,17 @@ pub struct FlowDirectionMap {
 impl FlowDirectionMap {
     /// Create flow direction map from heightmap using D8 algorithm
     pub fn from_heightmap(heightmap: &HeightMap) -> Self {
+        Self::from_heightmap_with_spacing(heightmap, 1.0, 1.0)
+    }
+
+    /// D8 flow directions over rectangular cells `spacing_x` by `spacing_y` apart
+    /// Slopes are measured against the true neighbor distance, so only the aspect ratio matters.
+    pub fn from_heightmap_with_spacing(
+        heightmap: &HeightMap,
+        spacing_x: f32,
+        spacing_y: f32,
+    ) -> Self {
+        let aspect = spacing_y / spacing_x;
         let width = heightmap.width();
         let height = heightmap.height();
         let mut directions = vec![FlowDirection::NoFlow; width * height];
@@ -106,12 +120,8 @@ impl FlowDirectionMap {
                             let neighbor_elevation = heightmap.get(nx as usize, ny as usize);
                             let elevation_diff = current_elevation - neighbor_elevation;
 
-                            // Calculate slope considering distance (diagonal vs cardinal)
-                            let distance = if dx.abs() + dy.abs() == 2 {
-                                1.414213562
-                            } else {
-                                1.0
-                            };
+                            // Distance in east-west cell widths (diagonal vs cardinal)
+                            let distance = (dx as f32).hypot(dy as f32 * aspect);
                             let slope = elevation_diff / distance;
 
                             if slope > steepest_slope {
@@ -161,7 +171,7 @@ impl FlowDirectionMap {
 }
 
 /// Flow accumulation map storing upstream drainage area
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FlowAccumulationMap {
     accumulation: Vec<f32>,
     width: usize,
@@ -171,6 +181,17 @@ pub struct FlowAccumulationMap {
 impl FlowAccumulationMap {
     /// Calculate flow accumulation from flow directions using optimized O(n) topological sorting
     pub fn from_flow_directions(flow_directions: &FlowDirectionMap) -> Self {
+        Self::accumulate(flow_directions, false)
+    }
+
+    /// Total upstream drainage area of every cell, not only the sinks
+    pub fn upstream_area(flow_directions: &FlowDirectionMap) -> Self {
+        Self::accumulate(flow_directions, true)
+    }
+
+    /// Pass each cell's area downstream in topological order
+    /// With `keep_upstream` false, cells are cleared once their area has moved on
+    fn accumulate(flow_directions: &FlowDirectionMap, keep_upstream: bool) -> Self {
         let width = flow_directions.width();
         let height = flow_directions.height();
         let total_cells = width * height;
@@ -244,7 +265,9 @@ impl FlowAccumulationMap {
 
                     // Transfer current cell's accumulation to target
                     accumulation[target_idx] += accumulation[current_idx];
-                    accumulation[current_idx] = 0.0; // Clear source after transfer
+                    if !keep_upstream {
+                        accumulation[current_idx] = 0.0; // Clear source after transfer
+                    }
 
                     // Decrease in-degree and add to queue if ready
                     in_degree[target_idx] -= 1;
@@ -316,7 +339,7 @@ impl FlowAccumulationMap {
 }
 
 /// Drainage network analysis and water body classification
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DrainageNetworkParameters {
     /// Minimum accumulation threshold for rivers
     pub river_accumulation_threshold: f32,
@@ -332,6 +355,12 @@ pub struct DrainageNetworkParameters {
 
     /// Minimum water depth for permanent water bodies
     pub permanent_water_threshold: f32,
+
+    /// Condition a copy of the terrain before routing so spurious pits don't trap flow
+    pub pit_method: Option<PitMethod>,
+
+    /// Cell spacing (x, y) in meters for D8 slope distances
+    pub cell_spacing_m: (f32, f32),
 }
 
 impl Default for DrainageNetworkParameters {
@@ -342,6 +371,8 @@ impl Default for DrainageNetworkParameters {
             lake_accumulation_threshold: 50.0,   // 50+ cells in depression = lake
             concentration_factor: 10.0,          // Concentrate water 10x into channels
             permanent_water_threshold: 0.01,     // 1% depth minimum for permanent water
+            pit_method: None,                    // Route over the terrain as given
+            cell_spacing_m: (1.0, 1.0),          // Square cells
         }
     }
 }
@@ -371,16 +402,27 @@ impl ScaleAware for DrainageNetworkParameters {
                 let resolution_factor = (meters_per_pixel / 100.0).max(0.1).min(10.0); // 0.1-10.0
                 self.permanent_water_threshold * resolution_factor
             },
+
+            pit_method: self.pit_method,
+
+            // Rectangular cells change which neighbor is steepest
+            cell_spacing_m: (
+                scale.meters_per_pixel_x() as f32,
+                scale.meters_per_pixel_y() as f32,
+            ),
         }
     }
 }
 
 /// Complete drainage network analysis system
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DrainageNetwork {
     flow_directions: FlowDirectionMap,
     flow_accumulation: FlowAccumulationMap,
     parameters: DrainageNetworkParameters,
+    /// Divides derived from the flow directions on first use
+    #[serde(skip)]
+    divides: OnceLock<PhysicsGrid<bool>>,
 }
 
 impl DrainageNetwork {
@@ -396,16 +438,30 @@ impl DrainageNetwork {
         heightmap: &HeightMap,
         parameters: DrainageNetworkParameters,
     ) -> Self {
-        let flow_directions = FlowDirectionMap::from_heightmap(heightmap);
+        let (spacing_x, spacing_y) = parameters.cell_spacing_m;
+        let flow_directions = match parameters.pit_method {
+            Some(method) => {
+                let mut conditioned = heightmap.clone();
+                conditioned.fill_pits(method);
+                FlowDirectionMap::from_heightmap_with_spacing(&conditioned, spacing_x, spacing_y)
+            }
+            None => FlowDirectionMap::from_heightmap_with_spacing(heightmap, spacing_x, spacing_y),
+        };
         let flow_accumulation = FlowAccumulationMap::from_flow_directions(&flow_directions);
 
         Self {
             flow_directions,
             flow_accumulation,
             parameters,
+            divides: OnceLock::new(),
         }
     }
 
+    /// Grid dimensions as (width, height)
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.flow_directions.width(), self.flow_directions.height())
+    }
+
     /// Get flow direction at coordinates
     #[inline]
     pub fn get_flow_direction(&self, x: usize, y: usize) -> FlowDirection {
@@ -418,6 +474,11 @@ impl DrainageNetwork {
         self.flow_accumulation.get(x, y)
     }
 
+    /// Total upstream drainage area of every cell; the stored map only keeps totals at sinks
+    pub fn upstream_area(&self) -> FlowAccumulationMap {
+        FlowAccumulationMap::upstream_area(&self.flow_directions)
+    }
+
     /// Check if location is a river based on accumulation threshold
     pub fn is_river(&self, x: usize, y: usize) -> bool {
         self.flow_accumulation.get(x, y) >= self.parameters.river_accumulation_threshold
@@ -480,7 +541,9 @@ impl DrainageNetwork {
                     0.0 // Clear very small amounts
                 };
 
-                water_layer.depth.set(x, y, final_depth);
+                water_layer
+                    .depth
+                    .set(x, y, StateFloat::from_f32(final_depth));
             }
         }
 
@@ -490,13 +553,166 @@ impl DrainageNetwork {
             let conservation_factor = total_water / new_total_water;
             for y in 0..height {
                 for x in 0..width {
-                    let current_depth = water_layer.depth.get(x, y);
-                    water_layer
-                        .depth
-                        .set(x, y, current_depth * conservation_factor);
+                    let current_depth = water_layer.depth.get(x, y).to_f32();
+                    water_layer.depth.set(
+                        x,
+                        y,
+                        StateFloat::from_f32(current_depth * conservation_factor),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Longitudinal profile of the main stem above an outlet as (distance km, elevation) pairs
+    /// Starting at the outlet, follows the highest-accumulation tributary upstream
+    /// until reaching a cell with no inflow. Accumulation is recounted as total upstream
+    /// area, since the stored map only keeps totals at sinks
+    pub fn longitudinal_profile(
+        &self,
+        heightmap: &HeightMap,
+        outlet: (usize, usize),
+        scale: &WorldScale,
+    ) -> Vec<(f32, f32)> {
+        let width = self.flow_directions.width().min(heightmap.width());
+        let height = self.flow_directions.height().min(heightmap.height());
+        let (mut x, mut y) = outlet;
+        if x >= width || y >= height {
+            return Vec::new();
+        }
+
+        // D8 flow always descends, so passing area downhill in elevation order visits
+        // every cell before the cell it drains into
+        let mut order: Vec<(usize, usize)> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .collect();
+        order.sort_by(|a, b| heightmap.get(b.0, b.1).total_cmp(&heightmap.get(a.0, a.1)));
+        let mut upstream_area = vec![1.0f32; width * height];
+        for (cx, cy) in order {
+            let (ox, oy) = self.flow_directions.get(cx, cy).get_offset();
+            let (tx, ty) = (cx as i32 + ox, cy as i32 + oy);
+            if (ox, oy) != (0, 0) && tx >= 0 && ty >= 0 && tx < width as i32 && ty < height as i32 {
+                upstream_area[ty as usize * width + tx as usize] += upstream_area[cy * width + cx];
+            }
+        }
+
+        let dx_km = (scale.meters_per_pixel_x() / 1000.0) as f32;
+        let dy_km = (scale.meters_per_pixel_y() / 1000.0) as f32;
+        let mut distance_km = 0.0;
+        let mut profile = vec![(distance_km, heightmap.get(x, y))];
+
+        loop {
+            // Neighbor draining into the current cell with the largest upstream area
+            let mut upstream: Option<(usize, usize, f32)> = None;
+            for dy in -1i32..=1 {
+                for dx in -1i32..=1 {
+                    let nx = x as i32 + dx;
+                    let ny = y as i32 + dy;
+                    if (dx == 0 && dy == 0)
+                        || nx < 0
+                        || ny < 0
+                        || nx >= width as i32
+                        || ny >= height as i32
+                    {
+                        continue;
+                    }
+                    let (nx, ny) = (nx as usize, ny as usize);
+                    if self.flow_directions.get(nx, ny).get_offset() != (-dx, -dy) {
+                        continue;
+                    }
+                    let accumulation = upstream_area[ny * width + nx];
+                    if upstream.is_none_or(|(_, _, best)| accumulation > best) {
+                        upstream = Some((nx, ny, accumulation));
+                    }
                 }
             }
+
+            let Some((nx, ny, _)) = upstream else {
+                break;
+            };
+            let step_x = if nx != x { dx_km } else { 0.0 };
+            let step_y = if ny != y { dy_km } else { 0.0 };
+            distance_km += (step_x * step_x + step_y * step_y).sqrt();
+            (x, y) = (nx, ny);
+            profile.push((distance_km, heightmap.get(x, y)));
         }
+
+        profile
+    }
+
+    /// Watershed label per cell: the index (y × width + x) of the cell its flow path ends at
+    /// Paths end at a sink or where flow would leave the map, so every basin draining
+    /// to the same outlet shares a label
+    pub fn watershed_labels(&self) -> PhysicsGrid<usize> {
+        let width = self.flow_directions.width();
+        let height = self.flow_directions.height();
+        let mut labels: Vec<Option<usize>> = vec![None; width * height];
+        // Start cell + 1 of the walk that last passed through each cell
+        let mut visited_by = vec![0; width * height];
+        let mut path = Vec::new();
+
+        for start in 0..width * height {
+            let (mut x, mut y) = (start % width, start / width);
+            path.clear();
+            let label = loop {
+                let idx = y * width + x;
+                if let Some(label) = labels[idx] {
+                    break label;
+                }
+                // A revisited cell means a flow loop; treat it as the outlet
+                if visited_by[idx] == start + 1 {
+                    break idx;
+                }
+                visited_by[idx] = start + 1;
+                path.push(idx);
+
+                let (dx, dy) = self.flow_directions.get(x, y).get_offset();
+                let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                if (dx, dy) == (0, 0)
+                    || nx < 0
+                    || ny < 0
+                    || nx >= width as i32
+                    || ny >= height as i32
+                {
+                    break idx;
+                }
+                (x, y) = (nx as usize, ny as usize);
+            };
+            for &idx in &path {
+                labels[idx] = Some(label);
+            }
+        }
+
+        let mut grid = PhysicsGrid::new(width, height, 0);
+        for (cell, label) in grid.iter_mut().zip(labels) {
+            *cell = label.unwrap_or_default();
+        }
+        grid
+    }
+
+    /// Drainage divides: cells whose cardinal neighbors drain to different outlets
+    /// Ridgelines separating watersheds come out about two cells wide, one on each side;
+    /// computed once per network and cached
+    pub fn divides(&self) -> &PhysicsGrid<bool> {
+        self.divides.get_or_init(|| self.compute_divides())
+    }
+
+    fn compute_divides(&self) -> PhysicsGrid<bool> {
+        let labels = self.watershed_labels();
+        let (width, height) = (labels.width(), labels.height());
+        let mut divides = PhysicsGrid::new(width, height, false);
+        for y in 0..height {
+            for x in 0..width {
+                let label = *labels.get(x, y);
+                let differs = |nx: usize, ny: usize| *labels.get(nx, ny) != label;
+                let divide = (x > 0 && differs(x - 1, y))
+                    || (x + 1 < width && differs(x + 1, y))
+                    || (y > 0 && differs(x, y - 1))
+                    || (y + 1 < height && differs(x, y + 1));
+                divides.set(x, y, divide);
+            }
+        }
+        divides
     }
 
     /// Get drainage network statistics for analysis
@@ -625,6 +841,21 @@ mod tests {
         assert_eq!(flow_map.get(2, 1), FlowDirection::NoFlow);
     }
 
+    #[test]
+    fn flow_direction_follows_steepest_slope_on_rectangular_cells() {
+        // The south neighbor drops further, but north-south cells are twice as long
+        let heightmap = HeightMap::from_nested(vec![
+            vec![1.0, 1.0, 1.0],
+            vec![1.0, 1.0, 0.5],
+            vec![1.0, 0.4, 1.0],
+        ]);
+        let square = FlowDirectionMap::from_heightmap(&heightmap);
+        assert_eq!(square.get(1, 1), FlowDirection::South);
+
+        let tall = FlowDirectionMap::from_heightmap_with_spacing(&heightmap, 100.0, 200.0);
+        assert_eq!(tall.get(1, 1), FlowDirection::East);
+    }
+
     #[test]
     fn flow_accumulation_simple_channel() {
         // Create heightmap with central channel
@@ -713,8 +944,8 @@ mod tests {
         let corner_accumulation = drainage.get_flow_accumulation(0, 0);
 
         // Center cells should have more water than edges
-        let center_water = water_layer.depth.get(1, 1);
-        let corner_water = water_layer.depth.get(0, 0);
+        let center_water = water_layer.depth.get(1, 1).to_f32();
+        let corner_water = water_layer.depth.get(0, 0).to_f32();
 
         println!(
             "Center accumulation: {}, Corner accumulation: {}",
@@ -893,4 +1124,67 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn longitudinal_profile_climbs_concave_valley() {
+        // Valley draining to (16, 0): concave-up floor along y, steep side walls along x
+        let heightmap = HeightMap::from_nested(
+            (0..32)
+                .map(|y| {
+                    (0..33)
+                        .map(|x| {
+                            0.05 + 0.5 * (y as f32 / 31.0).powi(2) + 0.1 * (x as f32 - 16.0).abs()
+                        })
+                        .collect()
+                })
+                .collect(),
+        );
+        let scale = WorldScale::new(66.0, (33, 32), DetailLevel::Standard);
+        let network = DrainageNetwork::from_heightmap(&heightmap, &scale);
+
+        let profile = network.longitudinal_profile(&heightmap, (16, 0), &scale);
+        // Main stem runs up the valley floor, then along the head-wall gully
+        assert!(profile.len() >= 32);
+        assert_eq!(profile[0], (0.0, heightmap.get(16, 0)));
+        for pair in profile.windows(2) {
+            assert!(pair[1].0 > pair[0].0);
+            assert!(pair[1].1 > pair[0].1);
+        }
+        // 2 km cells along the straight valley floor
+        assert!((profile[31].0 - 62.0).abs() < 1e-3);
+        assert_eq!(profile[31].1, heightmap.get(16, 31));
+    }
+
+    #[test]
+    fn divides_follow_ridge_between_twin_valleys() {
+        // Valleys along x = 5 and x = 15 falling south, separated by a ridge at x = 10
+        let heightmap = HeightMap::from_nested(
+            (0..16)
+                .map(|y| {
+                    (0..21)
+                        .map(|x| {
+                            let valley_distance =
+                                (x as f32 - 5.0).abs().min((x as f32 - 15.0).abs());
+                            0.2 + 0.05 * valley_distance + 0.01 * (15 - y) as f32
+                        })
+                        .collect()
+                })
+                .collect(),
+        );
+        let scale = WorldScale::new(21.0, (21, 16), DetailLevel::Standard);
+        let network = DrainageNetwork::from_heightmap(&heightmap, &scale);
+
+        let labels = network.watershed_labels();
+        assert_ne!(labels.get(5, 0), labels.get(15, 0));
+        assert_eq!(labels.get(2, 3), labels.get(8, 12));
+
+        let divides = network.divides();
+        for y in 0..15 {
+            assert!(*divides.get(10, y), "ridge not marked at row {y}");
+            assert!(!*divides.get(5, y) && !*divides.get(15, y));
+            assert!(!*divides.get(0, y) && !*divides.get(20, y));
+        }
+        // Later frames reuse the cached grid
+        assert!(std::ptr::eq(divides, network.divides()));
+    }
 }
@@ -4,15 +4,20 @@
 // ABOUTME: Ecosystem feedback loops coupling - biome effects on climate and hydrology
 // ABOUTME: Models how vegetation and biomes influence temperature, humidity, evapotranspiration, and water cycles
 
+use super::super::agents::biome::{
+    self, SuccessionEnvironment, SurfaceProperties, VegetationState, VegetationStateClassifier,
+};
+use super::super::core::physics_grid::PhysicsGrid;
 use super::super::core::scale::WorldScale;
 use super::super::core::temporal_scaling::TemporalScalingService;
 use super::{
     atmospheric_moisture::SurfaceMoistureLayer, flow_engine::FlowEngine,
     temperature::TemperatureField, water::WaterLayer,
 };
+use serde::{Deserialize, Serialize};
 
 /// Biome types affecting ecosystem feedback
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum BiomeType {
     /// Desert - low vegetation, high albedo, minimal evapotranspiration
     Desert,
@@ -29,28 +34,50 @@ pub enum BiomeType {
 }
 
 impl BiomeType {
-    /// Get albedo (surface reflectivity) for this biome type
-    pub fn albedo(&self) -> f32 {
+    /// Classified biome whose surface properties this feedback biome uses
+    pub fn classified(&self) -> biome::BiomeType {
         match self {
-            BiomeType::Desert => 0.35,    // High reflectivity, light surfaces
-            BiomeType::Grassland => 0.20, // Moderate reflectivity
-            BiomeType::Forest => 0.12,    // Low reflectivity, dark canopy
-            BiomeType::Wetland => 0.15,   // Low-moderate, water and vegetation
-            BiomeType::Tundra => 0.25,    // Moderate-high, sparse vegetation
-            BiomeType::Tropical => 0.10,  // Very low, dense dark vegetation
+            BiomeType::Desert => biome::BiomeType::Desert,
+            BiomeType::Grassland => biome::BiomeType::Grassland,
+            BiomeType::Forest => biome::BiomeType::TemperateForest,
+            BiomeType::Wetland => biome::BiomeType::Wetland,
+            BiomeType::Tundra => biome::BiomeType::Tundra,
+            BiomeType::Tropical => biome::BiomeType::RainForest,
         }
     }
 
+    /// Feedback biome standing in for a classified biome
+    pub fn from_classified(biome: biome::BiomeType) -> Self {
+        match biome {
+            biome::BiomeType::Ocean
+            | biome::BiomeType::Lake
+            | biome::BiomeType::River
+            | biome::BiomeType::Wetland => BiomeType::Wetland,
+            biome::BiomeType::Grassland
+            | biome::BiomeType::Savanna
+            | biome::BiomeType::Shrubland => BiomeType::Grassland,
+            biome::BiomeType::TemperateForest | biome::BiomeType::BorealForest => BiomeType::Forest,
+            biome::BiomeType::RainForest => BiomeType::Tropical,
+            biome::BiomeType::Desert => BiomeType::Desert,
+            biome::BiomeType::Tundra | biome::BiomeType::Alpine | biome::BiomeType::Ice => {
+                BiomeType::Tundra
+            }
+        }
+    }
+
+    /// Albedo, evapotranspiration and roughness from the shared biome surface table
+    pub fn surface_properties(&self) -> SurfaceProperties {
+        self.classified().surface_properties()
+    }
+
+    /// Get albedo (surface reflectivity) for this biome type
+    pub fn albedo(&self) -> f32 {
+        self.surface_properties().albedo
+    }
+
     /// Get evapotranspiration coefficient for this biome type
     pub fn evapotranspiration_coefficient(&self) -> f32 {
-        match self {
-            BiomeType::Desert => 0.1,    // Minimal water loss
-            BiomeType::Grassland => 0.4, // Moderate transpiration
-            BiomeType::Forest => 0.8,    // High transpiration, large leaf area
-            BiomeType::Wetland => 0.9,   // Very high, water available
-            BiomeType::Tundra => 0.2,    // Low, cold adaptation
-            BiomeType::Tropical => 1.0,  // Maximum transpiration
-        }
+        self.surface_properties().evapotranspiration_coefficient
     }
 
     /// Get thermal regulation capacity (heat absorption/cooling)
@@ -79,7 +106,7 @@ impl BiomeType {
 }
 
 /// Biome distribution map
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BiomeMap {
     /// Grid of biome types
     biomes: Vec<Vec<BiomeType>>,
@@ -142,6 +169,11 @@ impl BiomeMap {
         }
     }
 
+    /// Set vegetation density from a successional vegetation state
+    pub fn set_vegetation_state(&mut self, x: usize, y: usize, state: VegetationState) {
+        self.set_vegetation_density(x, y, state.cover_fraction());
+    }
+
     /// Get biomass at position
     pub fn get_biomass(&self, x: usize, y: usize) -> f32 {
         if x < self.width && y < self.height {
@@ -158,7 +190,7 @@ impl BiomeMap {
 }
 
 /// Ecosystem feedback effects data
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EcosystemFeedbackEffects {
     /// Temperature modification due to vegetation (°C/s)
     pub temperature_modification: Vec<Vec<f32>>,
@@ -172,6 +204,14 @@ pub struct EcosystemFeedbackEffects {
     pub albedo_modification: Vec<Vec<f32>>,
     /// Water retention enhancement from vegetation
     pub water_retention_enhancement: Vec<Vec<f32>>,
+    /// Surface evaporation multiplier from canopy shading (1.0 = bare ground)
+    pub surface_evaporation_factor: Vec<Vec<f32>>,
+    /// Fraction of ponded water infiltrating into the soil per tick
+    pub infiltration_fraction: Vec<Vec<f32>>,
+    /// Water the soil can hold before infiltration stops (m depth)
+    pub soil_water_capacity: Vec<Vec<f32>>,
+    /// Flow resistance multiplier slowing overland flow (1.0 = bare ground)
+    pub flow_roughness: Vec<Vec<f32>>,
 }
 
 impl EcosystemFeedbackEffects {
@@ -184,6 +224,10 @@ impl EcosystemFeedbackEffects {
             soil_moisture_change: vec![vec![0.0; height]; width],
             albedo_modification: vec![vec![0.0; height]; width],
             water_retention_enhancement: vec![vec![0.0; height]; width],
+            surface_evaporation_factor: vec![vec![1.0; height]; width],
+            infiltration_fraction: vec![vec![0.0; height]; width],
+            soil_water_capacity: vec![vec![0.0; height]; width],
+            flow_roughness: vec![vec![1.0; height]; width],
         }
     }
 
@@ -242,10 +286,47 @@ impl EcosystemFeedbackEffects {
             0.0
         }
     }
+
+    /// Get surface evaporation multiplier at position (1.0 outside the grid)
+    pub fn get_surface_evaporation_factor(&self, x: usize, y: usize) -> f32 {
+        if x < self.surface_evaporation_factor.len() && y < self.surface_evaporation_factor[0].len()
+        {
+            self.surface_evaporation_factor[x][y]
+        } else {
+            1.0
+        }
+    }
+
+    /// Get infiltration fraction at position with bounds checking
+    pub fn get_infiltration_fraction(&self, x: usize, y: usize) -> f32 {
+        if x < self.infiltration_fraction.len() && y < self.infiltration_fraction[0].len() {
+            self.infiltration_fraction[x][y]
+        } else {
+            0.0
+        }
+    }
+
+    /// Get soil water capacity at position with bounds checking
+    pub fn get_soil_water_capacity(&self, x: usize, y: usize) -> f32 {
+        if x < self.soil_water_capacity.len() && y < self.soil_water_capacity[0].len() {
+            self.soil_water_capacity[x][y]
+        } else {
+            0.0
+        }
+    }
+
+    /// Get flow roughness multiplier at position (1.0 outside the grid)
+    pub fn get_flow_roughness(&self, x: usize, y: usize) -> f32 {
+        if x < self.flow_roughness.len() && y < self.flow_roughness[0].len() {
+            self.flow_roughness[x][y]
+        } else {
+            1.0
+        }
+    }
 }
 
 /// Configuration parameters for ecosystem feedback physics
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EcosystemFeedbackParameters {
     /// Base evapotranspiration rate (mm/day per unit vegetation)
     pub base_evapotranspiration: f32,
@@ -263,24 +344,37 @@ pub struct EcosystemFeedbackParameters {
     pub water_stress_threshold: f32,
     /// Temperature stress range (optimal temperature range width)
     pub temperature_stress_range: f32,
+    /// Fractional reduction of surface evaporation under full canopy
+    pub canopy_evaporation_reduction: f32,
+    /// Fraction of ponded water infiltrating per tick under full cover
+    pub max_infiltration_fraction: f32,
+    /// Soil water holding capacity before vegetation enhancement (m depth)
+    pub soil_water_capacity: f32,
+    /// Additional flow resistance under full cover (roughness = 1 + this)
+    pub max_roughness_increase: f32,
 }
 
 impl Default for EcosystemFeedbackParameters {
     fn default() -> Self {
         Self {
-            base_evapotranspiration: 5.0,   // 5 mm/day base rate
-            temperature_moderation: 2.0,    // 2°C cooling per full vegetation
-            humidity_coefficient: 0.1,      // 0.1 kg/m³/s per mm/day evapotranspiration
-            albedo_variation: 0.25,         // 25% albedo variation range
-            moisture_enhancement: 2.0,      // 2x water retention with full vegetation
-            growth_rate: 10.0,              // 10 kg/m²/day growth under optimal conditions
-            water_stress_threshold: 0.3,    // Stress below 30% water availability
-            temperature_stress_range: 20.0, // 20°C optimal temperature range
+            base_evapotranspiration: 5.0,      // 5 mm/day base rate
+            temperature_moderation: 2.0,       // 2°C cooling per full vegetation
+            humidity_coefficient: 0.1,         // 0.1 kg/m³/s per mm/day evapotranspiration
+            albedo_variation: 0.25,            // 25% albedo variation range
+            moisture_enhancement: 2.0,         // 2x water retention with full vegetation
+            growth_rate: 10.0,                 // 10 kg/m²/day growth under optimal conditions
+            water_stress_threshold: 0.3,       // Stress below 30% water availability
+            temperature_stress_range: 20.0,    // 20°C optimal temperature range
+            canopy_evaporation_reduction: 0.6, // Shaded litter layer cuts evaporation by 60%
+            max_infiltration_fraction: 0.3,    // Root channels absorb 30% of ponded water per tick
+            soil_water_capacity: 0.05,         // 50 mm plant-available water
+            max_roughness_increase: 3.0,       // Stems and litter quadruple flow resistance
         }
     }
 }
 
 /// Ecosystem feedback loops coupling system
+#[derive(Serialize, Deserialize)]
 pub struct EcosystemFeedbackSystem {
     /// Physics parameters
     pub parameters: EcosystemFeedbackParameters,
@@ -290,6 +384,12 @@ pub struct EcosystemFeedbackSystem {
     effects: Option<EcosystemFeedbackEffects>,
     /// Temporal scaling service for realistic ecological timescales
     temporal_scaling: TemporalScalingService,
+    /// Successional vegetation stage per cell, setting vegetation density in the biome map
+    vegetation: PhysicsGrid<VegetationState>,
+    /// Favorable time (s) each cell has accumulated toward its next stage
+    succession_progress: PhysicsGrid<f64>,
+    /// Classified biomes the map was last synced from; None until the first sync
+    classified_biomes: Option<PhysicsGrid<biome::BiomeType>>,
 }
 
 impl EcosystemFeedbackSystem {
@@ -297,15 +397,15 @@ impl EcosystemFeedbackSystem {
     pub fn new(parameters: EcosystemFeedbackParameters, width: usize, height: usize) -> Self {
         use super::super::core::temporal_scaling::{TemporalMode, TemporalScalingConfig};
 
-        Self {
+        Self::new_with_temporal_scaling(
             parameters,
-            biome_map: BiomeMap::new(width, height),
-            effects: None,
-            temporal_scaling: TemporalScalingService::new(TemporalScalingConfig {
+            width,
+            height,
+            TemporalScalingService::new(TemporalScalingConfig {
                 mode: TemporalMode::Demo, // Default to Demo mode for backward compatibility
                 ..Default::default()
             }),
-        }
+        )
     }
 
     /// Create new ecosystem feedback system with explicit temporal scaling configuration
@@ -320,6 +420,9 @@ impl EcosystemFeedbackSystem {
             biome_map: BiomeMap::new(width, height),
             effects: None,
             temporal_scaling,
+            vegetation: PhysicsGrid::new(width, height, VegetationState::Grassland),
+            succession_progress: PhysicsGrid::new(width, height, 0.0),
+            classified_biomes: None,
         }
     }
 
@@ -343,6 +446,83 @@ impl EcosystemFeedbackSystem {
         &self.biome_map
     }
 
+    /// Successional vegetation stage at position
+    pub fn vegetation_state(&self, x: usize, y: usize) -> VegetationState {
+        *self.vegetation.get(x, y)
+    }
+
+    /// Set the successional stage at position, restarting its progress toward the next stage
+    pub fn set_vegetation_state(&mut self, x: usize, y: usize, state: VegetationState) {
+        let (width, height) = self.biome_map.dimensions();
+        if x < width && y < height {
+            self.vegetation.set(x, y, state);
+            self.succession_progress.set(x, y, 0.0);
+            self.biome_map.set_vegetation_state(x, y, state);
+        }
+    }
+
+    /// Take biome types and vegetation from a classified biome map
+    /// The first sync seeds every cell at its biome's climax vegetation. Later syncs only
+    /// touch reclassified cells, cutting their vegetation back to the new climax, so
+    /// succession and manual edits persist while the classification holds.
+    pub fn sync_classified_biomes(&mut self, classified: &biome::BiomeMap) {
+        let (width, height) = self.biome_map.dimensions();
+        if (classified.width(), classified.height()) != (width, height) {
+            return;
+        }
+        let seeding = self.classified_biomes.is_none();
+        let previous = self
+            .classified_biomes
+            .get_or_insert_with(|| PhysicsGrid::new(width, height, biome::BiomeType::Grassland));
+        let mut reclassified = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let current = classified.get(x, y);
+                if seeding || *previous.get(x, y) != current {
+                    previous.set(x, y, current);
+                    reclassified.push((x, y, current));
+                }
+            }
+        }
+
+        for (x, y, current) in reclassified {
+            self.biome_map
+                .set_biome(x, y, BiomeType::from_classified(current));
+            let climax = current.climax_vegetation();
+            let state = if seeding {
+                climax
+            } else {
+                self.vegetation_state(x, y).min(climax)
+            };
+            self.set_vegetation_state(x, y, state);
+        }
+    }
+
+    /// Advance vegetation succession by dt seconds, holding each cell at its biome's climax
+    pub fn advance_succession(
+        &mut self,
+        classifier: &VegetationStateClassifier,
+        env: &SuccessionEnvironment,
+        dt: f64,
+    ) {
+        classifier.step(&mut self.vegetation, &mut self.succession_progress, env, dt);
+        let (width, height) = self.biome_map.dimensions();
+        for y in 0..height {
+            for x in 0..width {
+                let mut state = *self.vegetation.get(x, y);
+                if let Some(classified) = &self.classified_biomes {
+                    let climax = classified.get(x, y).climax_vegetation();
+                    if state > climax {
+                        state = climax;
+                        self.vegetation.set(x, y, state);
+                        self.succession_progress.set(x, y, 0.0);
+                    }
+                }
+                self.biome_map.set_vegetation_state(x, y, state);
+            }
+        }
+    }
+
     /// Get current temporal scaling mode
     pub fn get_temporal_mode(&self) -> super::super::core::temporal_scaling::TemporalMode {
         self.temporal_scaling.mode()
@@ -366,6 +546,41 @@ impl EcosystemFeedbackSystem {
         self.temporal_scaling.scaling_description()
     }
 
+    /// Recompute the surface hydrology effects from current vegetation cover
+    /// Lightweight per-tick path that leaves climate and biomass untouched
+    pub fn update_surface_effects(&mut self) {
+        let (width, height) = self.biome_map.dimensions();
+        let mut effects = self
+            .effects
+            .take()
+            .unwrap_or_else(|| EcosystemFeedbackEffects::new(width, height));
+        self.compute_surface_effects(&mut effects);
+        self.effects = Some(effects);
+    }
+
+    /// Fill evaporation, infiltration and roughness effects from biome and vegetation density
+    /// Writes into caller-owned effects, so consumers can refresh theirs without a copy
+    pub fn compute_surface_effects(&self, effects: &mut EcosystemFeedbackEffects) {
+        let (width, height) = self.biome_map.dimensions();
+        for x in 0..width {
+            for y in 0..height {
+                let biome = self.biome_map.get_biome(x, y);
+                let cover = self.biome_map.get_vegetation_density(x, y);
+                let retention = biome.moisture_retention();
+
+                effects.surface_evaporation_factor[x][y] = 1.0
+                    - self.parameters.canopy_evaporation_reduction
+                        * biome.thermal_regulation()
+                        * cover;
+                effects.infiltration_fraction[x][y] =
+                    self.parameters.max_infiltration_fraction * retention * cover;
+                effects.soil_water_capacity[x][y] = self.parameters.soil_water_capacity
+                    * (1.0 + (self.parameters.moisture_enhancement - 1.0) * retention * cover);
+                effects.flow_roughness[x][y] = 1.0 + self.parameters.max_roughness_increase * cover;
+            }
+        }
+    }
+
     /// Update ecosystem feedback effects
     pub fn update(
         &mut self,
@@ -378,6 +593,7 @@ impl EcosystemFeedbackSystem {
     ) {
         let (width, height) = self.biome_map.dimensions();
         let mut effects = EcosystemFeedbackEffects::new(width, height);
+        self.compute_surface_effects(&mut effects);
 
         // Physical constants
         let cell_size_m = scale.meters_per_pixel() as f32;
@@ -639,6 +855,62 @@ mod tests {
         assert_eq!(system.biome_map.dimensions(), (6, 6));
     }
 
+    #[test]
+    fn classified_biomes_seed_cover_and_cap_succession() {
+        use crate::engine::agents::biome::SuccessionParameters;
+        use crate::engine::physics::climate::TemperatureLayer;
+        use crate::engine::physics::hydro_biome_coupling::WaterAvailability;
+
+        let mut classified = biome::BiomeMap::new(2, 1, biome::BiomeType::TemperateForest);
+        classified.set(1, 0, biome::BiomeType::Grassland);
+        let mut system = EcosystemFeedbackSystem::new(EcosystemFeedbackParameters::default(), 2, 1);
+
+        // The first sync seeds every cell at its biome's climax cover
+        system.sync_classified_biomes(&classified);
+        assert_eq!(system.biome_map.get_biome(0, 0), BiomeType::Forest);
+        assert_eq!(system.vegetation_state(0, 0), VegetationState::Forest);
+        assert_eq!(system.vegetation_state(1, 0), VegetationState::Grassland);
+        assert_eq!(system.biome_map.get_vegetation_density(0, 0), 1.0);
+
+        // Unchanged cells keep edits; a reclassified cell is cut back to the new climax
+        system.set_vegetation_state(1, 0, VegetationState::Bare);
+        classified.set(0, 0, biome::BiomeType::Shrubland);
+        system.sync_classified_biomes(&classified);
+        assert_eq!(system.biome_map.get_biome(0, 0), BiomeType::Grassland);
+        assert_eq!(system.vegetation_state(0, 0), VegetationState::Shrubland);
+        assert_eq!(system.vegetation_state(1, 0), VegetationState::Bare);
+
+        // Well-watered succession regrows the bare cell but stops at the grassland climax
+        let scale = WorldScale::new(10.0, (2, 1), DetailLevel::Standard);
+        let classifier = VegetationStateClassifier::new_for_scale(&scale).with_succession(
+            SuccessionParameters {
+                stage_interval_seconds: 100.0,
+                ..Default::default()
+            },
+        );
+        let water_availability = WaterAvailability {
+            residence_time: vec![vec![0.0]; 2],
+            upstream_watershed_km2: vec![vec![0.0]; 2],
+            flow_intensity_ms: vec![vec![0.0]; 2],
+            availability_index: vec![vec![0.9]; 2],
+            width: 2,
+            height: 1,
+        };
+        let mut temperature = TemperatureLayer::new(2, 1);
+        temperature.temperature.fill(20.0);
+        let env = SuccessionEnvironment {
+            water_availability: &water_availability,
+            temperature: &temperature,
+        };
+        system.advance_succession(&classifier, &env, 1000.0);
+        assert_eq!(system.vegetation_state(0, 0), VegetationState::Shrubland);
+        assert_eq!(system.vegetation_state(1, 0), VegetationState::Grassland);
+        assert_eq!(
+            system.biome_map.get_vegetation_density(1, 0),
+            VegetationState::Grassland.cover_fraction()
+        );
+    }
+
     #[test]
     fn test_biome_classification() {
         // Test various environmental conditions
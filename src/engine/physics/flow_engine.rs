@@ -4,11 +4,18 @@
 // ABOUTME: Unified flow calculation engine consolidating 5 duplicate implementations
 // ABOUTME: Provides consistent physics algorithms with pluggable approaches for different contexts
 
-use crate::engine::core::{heightmap::HeightMap, math::Vec2, scale::WorldScale};
+use crate::engine::core::{
+    heightmap::HeightMap,
+    math::Vec2,
+    physics_grid::{Float, StateFloat},
+    planet::EARTH_GRAVITY,
+    scale::WorldScale,
+};
 use crate::engine::physics::{drainage::DrainageNetwork, water::WaterLayer};
+use serde::{Deserialize, Serialize};
 
 /// Flow calculation algorithms optimized for different physics contexts
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum FlowAlgorithm {
     /// Gradient-based steepest descent for interactive/real-time simulation
     /// Fast approximation: v = gradient × flow_rate
@@ -25,10 +32,49 @@ pub enum FlowAlgorithm {
     /// Static topological analysis using flow accumulation
     /// Network analysis: Kahn's algorithm for drainage patterns
     Drainage,
+
+    /// Finite-volume Saint-Venant equations with depth and momentum fluxes
+    /// Conservative: ∂h/∂t + ∇·(hv) = 0, ∂(hv)/∂t + ∇·(hvv) + g h∇(h + z) = friction
+    ShallowWater,
+
+    /// Gradient velocities with semi-Lagrangian depth transport along backtraced paths
+    /// Unconditionally stable: water may cross several cells per step, total depth preserved
+    SemiLagrangian,
+}
+
+impl FlowAlgorithm {
+    /// Built-in solver implementing this algorithm
+    pub fn solver(self) -> &'static dyn FlowSolver {
+        match self {
+            FlowAlgorithm::Gradient => &GradientSolver,
+            FlowAlgorithm::Conservation => &ConservationSolver,
+            FlowAlgorithm::Spatial => &SpatialSolver,
+            FlowAlgorithm::Drainage => &DrainageSolver,
+            FlowAlgorithm::ShallowWater => &ShallowWaterSolver,
+            FlowAlgorithm::SemiLagrangian => &SemiLagrangianSolver,
+        }
+    }
+}
+
+/// Pluggable flow solver producing a velocity field from terrain and water state
+/// Implement this to drop in new schemes without extending `FlowAlgorithm`; solvers are
+/// `Send + Sync` so an engine holding one can move with its simulation across threads
+pub trait FlowSolver: std::fmt::Debug + Send + Sync {
+    /// Write per-cell velocities into `velocity_field` (scaled by the temporal factor)
+    /// Solvers that transport mass may also update `water` depths in place
+    fn calculate_flow(
+        &self,
+        heightmap: &HeightMap,
+        water: &mut WaterLayer,
+        drainage: Option<&DrainageNetwork>,
+        scale: &WorldScale,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
+    );
 }
 
 /// Unified velocity field representation using Phase 2.1 Vec2 foundation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VelocityField {
     /// Velocity vectors at each grid cell
     pub velocities: Vec<Vec<Vec2>>,
@@ -90,10 +136,75 @@ impl VelocityField {
         }
         total * 0.5 // Factor of 1/2 for kinetic energy
     }
+
+    /// Sample velocity at a fractional grid position using bilinear interpolation
+    /// Cell centers sit at integer coordinates; positions are clamped to the grid
+    pub fn sample_velocity(&self, position: Vec2) -> Vec2 {
+        if self.width == 0 || self.height == 0 {
+            return Vec2::zero();
+        }
+
+        let x = position.x.clamp(0.0, (self.width - 1) as f32);
+        let y = position.y.clamp(0.0, (self.height - 1) as f32);
+        let x0 = x.floor() as usize;
+        let y0 = y.floor() as usize;
+        let x1 = (x0 + 1).min(self.width - 1);
+        let y1 = (y0 + 1).min(self.height - 1);
+        let fx = x - x0 as f32;
+        let fy = y - y0 as f32;
+
+        let top = self.velocities[x0][y0] * (1.0 - fx) + self.velocities[x1][y0] * fx;
+        let bottom = self.velocities[x0][y1] * (1.0 - fx) + self.velocities[x1][y1] * fx;
+        top * (1.0 - fy) + bottom * fy
+    }
+
+    /// Trace a streamline from a start position in grid coordinates
+    ///
+    /// Uses midpoint (RK2) integration along the normalized flow direction, so each step
+    /// advances `step_len` cells. Stops early when velocity drops to near zero or the
+    /// path leaves the domain. The returned path includes the start position.
+    pub fn trace_streamline(&self, start: Vec2, steps: usize, step_len: f32) -> Vec<Vec2> {
+        const MIN_SPEED: f32 = 1e-6;
+
+        let mut path = vec![start];
+        if !self.contains(start) {
+            return path;
+        }
+
+        let mut position = start;
+        for _ in 0..steps {
+            let v1 = self.sample_velocity(position);
+            if v1.magnitude() < MIN_SPEED {
+                break;
+            }
+
+            let midpoint = position + v1.normalize() * (step_len * 0.5);
+            let v2 = self.sample_velocity(midpoint);
+            if v2.magnitude() < MIN_SPEED {
+                break;
+            }
+
+            position = position + v2.normalize() * step_len;
+            if !self.contains(position) {
+                break;
+            }
+            path.push(position);
+        }
+
+        path
+    }
+
+    /// Check whether a fractional grid position lies inside the field
+    fn contains(&self, position: Vec2) -> bool {
+        position.x >= 0.0
+            && position.y >= 0.0
+            && position.x <= (self.width as f32 - 1.0)
+            && position.y <= (self.height as f32 - 1.0)
+    }
 }
 
 /// Flow calculation parameters for different physics contexts
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FlowParameters {
     /// Gravitational acceleration (m/s²)
     pub gravity: f32,
@@ -112,17 +223,21 @@ pub struct FlowParameters {
 
     /// Time step for explicit integration (seconds)  
     pub dt: f32,
+
+    /// Shrink dt below the nominal value to hold the max Courant number under cfl_safety
+    pub adaptive_dt: bool,
 }
 
 impl Default for FlowParameters {
     fn default() -> Self {
         Self {
-            gravity: 9.81,                // Standard Earth gravity
+            gravity: EARTH_GRAVITY,       // Standard Earth gravity
             roughness: 0.03,              // Typical natural channel
             min_depth: 1e-6,              // 1 micrometer minimum
             concentration_factor: 5000.0, // From Phase 1 continental drainage solution
             cfl_safety: 0.5,              // Conservative stability
             dt: 1.0,                      // 1 second timestep
+            adaptive_dt: false,           // Fixed timestep unless requested
         }
     }
 }
@@ -174,7 +289,7 @@ impl FlowParameters {
 }
 
 /// Core unified flow calculation engine
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FlowEngine {
     /// Selected flow algorithm
     pub algorithm: FlowAlgorithm,
@@ -184,8 +299,18 @@ pub struct FlowEngine {
 
     /// Current velocity field state
     pub velocity_field: VelocityField,
+
+    /// Custom solver overriding `algorithm` when set; not saved in checkpoints
+    #[serde(skip)]
+    custom_solver: Option<Box<dyn FlowSolver>>,
+
+    /// Timestep chosen by adaptive stepping; None = nominal `parameters.dt`
+    adapted_dt: Option<f32>,
 }
 
+/// Largest factor by which the adaptive timestep may grow back per calculation
+const MAX_DT_GROWTH: f32 = 2.0;
+
 impl FlowEngine {
     /// Create flow engine with specified algorithm and scale
     pub fn new(algorithm: FlowAlgorithm, width: usize, height: usize, scale: &WorldScale) -> Self {
@@ -194,12 +319,16 @@ impl FlowEngine {
             FlowAlgorithm::Conservation => FlowParameters::default(),
             FlowAlgorithm::Spatial => FlowParameters::for_large_scale(width * height),
             FlowAlgorithm::Drainage => FlowParameters::for_geological(),
+            FlowAlgorithm::ShallowWater => FlowParameters::default(),
+            FlowAlgorithm::SemiLagrangian => FlowParameters::default(),
         };
 
         Self {
             algorithm,
             parameters,
             velocity_field: VelocityField::new(width, height, scale),
+            custom_solver: None,
+            adapted_dt: None,
         }
     }
 
@@ -209,6 +338,8 @@ impl FlowEngine {
             algorithm: FlowAlgorithm::Conservation, // Conservation physics for climate coupling
             parameters: FlowParameters::for_climate(),
             velocity_field: VelocityField::new(width, height, scale),
+            custom_solver: None,
+            adapted_dt: None,
         }
     }
 
@@ -218,6 +349,8 @@ impl FlowEngine {
             algorithm: FlowAlgorithm::Drainage, // Network analysis for geological evolution
             parameters: FlowParameters::for_geological(),
             velocity_field: VelocityField::new(width, height, scale),
+            custom_solver: None,
+            adapted_dt: None,
         }
     }
 
@@ -227,10 +360,23 @@ impl FlowEngine {
             algorithm: FlowAlgorithm::Spatial, // Change-tracking optimization
             parameters: FlowParameters::for_large_scale(width * height),
             velocity_field: VelocityField::new(width, height, scale),
+            custom_solver: None,
+            adapted_dt: None,
         }
     }
 
-    /// Main flow calculation dispatch to appropriate algorithm
+    /// Replace the built-in algorithm with a custom solver
+    pub fn with_solver(mut self, solver: Box<dyn FlowSolver>) -> Self {
+        self.custom_solver = Some(solver);
+        self
+    }
+
+    /// Whether a custom solver is overriding the built-in algorithm
+    pub fn has_custom_solver(&self) -> bool {
+        self.custom_solver.is_some()
+    }
+
+    /// Main flow calculation dispatch to the custom solver or the selected algorithm
     pub fn calculate_flow(
         &mut self,
         heightmap: &HeightMap,
@@ -238,30 +384,90 @@ impl FlowEngine {
         drainage: Option<&DrainageNetwork>,
         scale: &WorldScale,
     ) {
-        // Extract temporal scaling factor for unified physics scaling
-        let temporal_factor = scale.temporal_scale.temporal_factor() as f32;
-
         // Ensure velocity field matches current scale
         self.update_scale_if_needed(scale);
 
-        match self.algorithm {
-            FlowAlgorithm::Gradient => self.calculate_gradient_flow_scaled(heightmap, water, scale, temporal_factor),
-            FlowAlgorithm::Conservation => {
-                self.calculate_conservation_flow_scaled(heightmap, water, scale, temporal_factor)
-            }
-            FlowAlgorithm::Spatial => self.calculate_spatial_flow_scaled(heightmap, water, scale, temporal_factor),
-            FlowAlgorithm::Drainage => {
-                if let Some(drainage_net) = drainage {
-                    self.calculate_drainage_flow_scaled(heightmap, water, drainage_net, scale, temporal_factor)
-                } else {
-                    // Fallback to gradient method if no drainage network provided
-                    self.calculate_gradient_flow_scaled(heightmap, water, scale, temporal_factor)
+        // Pick the timestep from the incoming velocities when adaptive stepping is on,
+        // then split the nominal interval into equal substeps no longer than it
+        let nominal = self.parameters.dt;
+        let substeps = if self.parameters.adaptive_dt {
+            (nominal / self.adaptive_timestep(water, scale))
+                .ceil()
+                .max(1.0) as usize
+        } else {
+            1
+        };
+        self.adapted_dt = self
+            .parameters
+            .adaptive_dt
+            .then(|| nominal / substeps as f32);
+        let adapted_parameters = self.adapted_dt.map(|dt| FlowParameters {
+            dt,
+            ..self.parameters.clone()
+        });
+        let parameters = adapted_parameters.as_ref().unwrap_or(&self.parameters);
+
+        let solver: &dyn FlowSolver = match &self.custom_solver {
+            Some(solver) => solver.as_ref(),
+            None => self.algorithm.solver(),
+        };
+        for _ in 0..substeps {
+            solver.calculate_flow(
+                heightmap,
+                water,
+                drainage,
+                scale,
+                parameters,
+                &mut self.velocity_field,
+            );
+
+            // Update water layer velocities from unified field
+            self.update_water_layer_velocities(water);
+        }
+    }
+
+    /// Substep length used by the most recent calculation (s)
+    pub fn current_dt(&self) -> f32 {
+        self.adapted_dt.unwrap_or(self.parameters.dt)
+    }
+
+    /// Substeps the most recent calculation took to cover the nominal dt
+    pub fn current_substeps(&self) -> usize {
+        (self.parameters.dt / self.current_dt()).round() as usize
+    }
+
+    /// Largest Courant number |v|·dt/dx over wet cells at the current timestep
+    pub fn max_courant_number(&self, water: &WaterLayer, scale: &WorldScale) -> f32 {
+        let dx = scale.meters_per_pixel() as f32;
+        Self::max_wet_speed(water, self.parameters.min_depth) * self.current_dt() / dx
+    }
+
+    /// Fastest flow speed (m/s) among cells deeper than min_depth
+    fn max_wet_speed(water: &WaterLayer, min_depth: f32) -> f32 {
+        let mut max_speed = 0.0f32;
+        for y in 0..water.height() {
+            for x in 0..water.width() {
+                if water.get_water_depth(x, y) > min_depth {
+                    let (vx, vy) = water.velocity.get(x, y);
+                    max_speed = max_speed.max((vx * vx + vy * vy).sqrt());
                 }
             }
         }
+        max_speed
+    }
 
-        // Update water layer velocities from unified field
-        self.update_water_layer_velocities(water);
+    /// Timestep holding the Courant number at cfl_safety, capped at the nominal dt and
+    /// growing back by at most MAX_DT_GROWTH per calculation as flow calms
+    fn adaptive_timestep(&self, water: &WaterLayer, scale: &WorldScale) -> f32 {
+        let nominal = self.parameters.dt;
+        let max_speed = Self::max_wet_speed(water, self.parameters.min_depth);
+        let stable = if max_speed > 0.0 {
+            self.parameters.cfl_safety * scale.meters_per_pixel() as f32 / max_speed
+        } else {
+            nominal
+        };
+        let grown = self.adapted_dt.map_or(nominal, |dt| dt * MAX_DT_GROWTH);
+        stable.min(grown).min(nominal)
     }
 
     /// Update scale parameters if WorldScale has changed
@@ -277,373 +483,698 @@ impl FlowEngine {
         }
     }
 
-    /// Gradient-based flow calculation (from sim.rs implementation)
-    fn calculate_gradient_flow(
-        &mut self,
-        heightmap: &HeightMap,
-        water: &WaterLayer,
-        scale: &WorldScale,
-    ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
-
-        for x in 0..heightmap.width() {
-            for y in 0..heightmap.height() {
-                let velocity =
-                    self.compute_gradient_velocity(heightmap, water, x, y, grid_spacing_m);
-                self.velocity_field.set_velocity(x, y, velocity);
+    /// Update WaterLayer velocity field from unified representation
+    fn update_water_layer_velocities(&self, water: &mut WaterLayer) {
+        for x in 0..self.velocity_field.width {
+            for y in 0..self.velocity_field.height {
+                let velocity = self.velocity_field.get_velocity(x, y);
+                water.velocity.set(x, y, (velocity.x, velocity.y));
             }
         }
     }
+}
 
-    /// Gradient-based flow calculation with temporal scaling for unified physics consistency
-    fn calculate_gradient_flow_scaled(
-        &mut self,
+/// Built-in gradient solver: steepest descent over the water surface
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GradientSolver;
+
+impl FlowSolver for GradientSolver {
+    fn calculate_flow(
+        &self,
         heightmap: &HeightMap,
-        water: &WaterLayer,
+        water: &mut WaterLayer,
+        _drainage: Option<&DrainageNetwork>,
         scale: &WorldScale,
-        temporal_factor: f32,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
     ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+        let spacing = cell_spacing(scale);
+        let temporal_factor = scale.temporal_scale.temporal_factor() as f32;
 
         for x in 0..heightmap.width() {
             for y in 0..heightmap.height() {
-                let mut velocity =
-                    self.compute_gradient_velocity(heightmap, water, x, y, grid_spacing_m);
-                
+                let velocity =
+                    compute_gradient_velocity(parameters, heightmap, water, x, y, spacing);
+
                 // CRITICAL: Scale velocity with temporal factor
-                velocity = velocity * temporal_factor;
-                
-                self.velocity_field.set_velocity(x, y, velocity);
+                velocity_field.set_velocity(x, y, velocity * temporal_factor);
             }
         }
     }
+}
 
-    /// Conservation-based flow calculation (from corrected_water_flow.rs)
-    fn calculate_conservation_flow(
-        &mut self,
+/// Built-in conservation solver: explicit momentum update with Manning friction
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConservationSolver;
+
+impl FlowSolver for ConservationSolver {
+    fn calculate_flow(
+        &self,
         heightmap: &HeightMap,
-        water: &WaterLayer,
+        water: &mut WaterLayer,
+        _drainage: Option<&DrainageNetwork>,
         scale: &WorldScale,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
     ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+        let dx = scale.meters_per_pixel_x() as f32;
+        let dy = scale.meters_per_pixel_y() as f32;
+        let temporal_factor = scale.temporal_scale.temporal_factor() as f32;
 
         for x in 0..heightmap.width() {
             for y in 0..heightmap.height() {
                 let velocity =
-                    self.compute_conservation_velocity(heightmap, water, x, y, grid_spacing_m);
-                self.velocity_field.set_velocity(x, y, velocity);
-            }
-        }
-    }
-
-    /// Conservation-based flow calculation with temporal scaling for unified physics consistency
-    fn calculate_conservation_flow_scaled(
-        &mut self,
-        heightmap: &HeightMap,
-        water: &WaterLayer,
-        scale: &WorldScale,
-        temporal_factor: f32,
-    ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+                    compute_conservation_velocity(parameters, heightmap, water, x, y, dx, dy);
 
-        for x in 0..heightmap.width() {
-            for y in 0..heightmap.height() {
-                let mut velocity = 
-                    self.compute_conservation_velocity(heightmap, water, x, y, grid_spacing_m);
-                
                 // CRITICAL: Scale velocity with temporal factor
-                velocity = velocity * temporal_factor;
-                
-                self.velocity_field.set_velocity(x, y, velocity);
+                velocity_field.set_velocity(x, y, velocity * temporal_factor);
             }
         }
     }
+}
 
-    /// Spatial optimization flow calculation (from spatial_partitioning.rs)
-    fn calculate_spatial_flow(
-        &mut self,
+/// Built-in spatial solver: gradient flow updated only in cells holding water
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SpatialSolver;
+
+impl FlowSolver for SpatialSolver {
+    fn calculate_flow(
+        &self,
         heightmap: &HeightMap,
-        water: &WaterLayer,
+        water: &mut WaterLayer,
+        _drainage: Option<&DrainageNetwork>,
         scale: &WorldScale,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
     ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+        let spacing = cell_spacing(scale);
+        let temporal_factor = scale.temporal_scale.temporal_factor() as f32;
 
         // Only process cells that have changed since last update
         for x in 0..heightmap.width() {
             for y in 0..heightmap.height() {
-                if self.should_update_cell(water, x, y) {
+                if should_update_cell(parameters, water, x, y) {
                     let velocity =
-                        self.compute_gradient_velocity(heightmap, water, x, y, grid_spacing_m);
-                    self.velocity_field.set_velocity(x, y, velocity);
-                }
-            }
-        }
-    }
-
-    /// Spatial optimization flow calculation with temporal scaling for unified physics consistency
-    fn calculate_spatial_flow_scaled(
-        &mut self,
-        heightmap: &HeightMap,
-        water: &WaterLayer,
-        scale: &WorldScale,
-        temporal_factor: f32,
-    ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+                        compute_gradient_velocity(parameters, heightmap, water, x, y, spacing);
 
-        // Only process cells that have changed since last update
-        for x in 0..heightmap.width() {
-            for y in 0..heightmap.height() {
-                if self.should_update_cell(water, x, y) {
-                    let mut velocity =
-                        self.compute_gradient_velocity(heightmap, water, x, y, grid_spacing_m);
-                    
                     // CRITICAL: Scale velocity with temporal factor
-                    velocity = velocity * temporal_factor;
-                    
-                    self.velocity_field.set_velocity(x, y, velocity);
+                    velocity_field.set_velocity(x, y, velocity * temporal_factor);
                 }
             }
         }
     }
+}
 
-    /// Drainage network flow calculation (from drainage.rs)
-    fn calculate_drainage_flow(
-        &mut self,
+/// Built-in drainage solver: gradient flow concentrated by flow accumulation
+/// Falls back to the gradient solver when no drainage network is supplied
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DrainageSolver;
+
+impl FlowSolver for DrainageSolver {
+    fn calculate_flow(
+        &self,
         heightmap: &HeightMap,
-        water: &WaterLayer,
-        drainage: &DrainageNetwork,
+        water: &mut WaterLayer,
+        drainage: Option<&DrainageNetwork>,
         scale: &WorldScale,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
     ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+        let Some(drainage) = drainage else {
+            GradientSolver.calculate_flow(
+                heightmap,
+                water,
+                None,
+                scale,
+                parameters,
+                velocity_field,
+            );
+            return;
+        };
+
+        let spacing = cell_spacing(scale);
+        let temporal_factor = scale.temporal_scale.temporal_factor() as f32;
 
         for x in 0..heightmap.width() {
             for y in 0..heightmap.height() {
                 let flow_accumulation = drainage.get_flow_accumulation(x, y);
-                let velocity = self.compute_drainage_enhanced_velocity(
+                let velocity = compute_drainage_enhanced_velocity(
+                    parameters,
                     heightmap,
                     water,
                     x,
                     y,
-                    grid_spacing_m,
+                    spacing,
                     flow_accumulation,
                 );
-                self.velocity_field.set_velocity(x, y, velocity);
+
+                // CRITICAL: Scale velocity with temporal factor
+                velocity_field.set_velocity(x, y, velocity * temporal_factor);
             }
         }
     }
+}
 
-    /// Drainage network flow calculation with temporal scaling for unified physics consistency
-    fn calculate_drainage_flow_scaled(
-        &mut self,
+/// Built-in shallow-water solver: finite-volume Saint-Venant equations
+/// HLL fluxes with hydrostatic reconstruction over terrain, reflective domain edges and
+/// semi-implicit Manning friction. Advances depths in place over `parameters.dt` (times the
+/// temporal factor) in CFL-limited substeps, conserving mass on the closed domain.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShallowWaterSolver;
+
+impl FlowSolver for ShallowWaterSolver {
+    fn calculate_flow(
+        &self,
         heightmap: &HeightMap,
-        water: &WaterLayer,
-        drainage: &DrainageNetwork,
+        water: &mut WaterLayer,
+        _drainage: Option<&DrainageNetwork>,
         scale: &WorldScale,
-        temporal_factor: f32,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
     ) {
-        let grid_spacing_m = scale.meters_per_pixel() as f32;
+        let mut grid = ShallowWaterGrid::from_layers(heightmap, water, velocity_field, parameters);
+        let (dx, dy) = (scale.meters_per_pixel_x(), scale.meters_per_pixel_y());
+        let duration = parameters.dt as f64 * scale.temporal_scale.temporal_factor();
+
+        let mut elapsed = 0.0;
+        while elapsed < duration {
+            let max_speed = grid.max_wave_speed();
+            if max_speed <= 0.0 {
+                break; // Dry, motionless domain
+            }
 
-        for x in 0..heightmap.width() {
-            for y in 0..heightmap.height() {
-                let flow_accumulation = drainage.get_flow_accumulation(x, y);
-                let mut velocity = self.compute_drainage_enhanced_velocity(
-                    heightmap,
-                    water,
-                    x,
-                    y,
-                    grid_spacing_m,
-                    flow_accumulation,
-                );
-                
-                // CRITICAL: Scale velocity with temporal factor
-                velocity = velocity * temporal_factor;
-                
-                self.velocity_field.set_velocity(x, y, velocity);
+            let dt =
+                (parameters.cfl_safety as f64 * dx.min(dy) / max_speed).min(duration - elapsed);
+            grid.step(dt, dx, dy);
+            grid.apply_friction(dt, parameters.roughness as f64);
+            elapsed += dt;
+        }
+
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                let cell = grid.index(x, y);
+                let (u, v) = grid.velocity(cell);
+                water
+                    .depth
+                    .set(x, y, StateFloat::from_f64(grid.state[cell][0].max(0.0)));
+                velocity_field.set_velocity(x, y, Vec2::new(u as f32, v as f32));
             }
         }
     }
+}
 
-    /// Compute gradient-based velocity for a single cell
-    fn compute_gradient_velocity(
+/// Built-in semi-Lagrangian solver: gradient velocities with backtraced depth transport
+///
+/// Each cell takes the depth found at its departure point x - v·dt, bilinearly interpolated,
+/// so fast channel flow can cross several cells in one step without the Courant limit that
+/// caps the Eulerian update. Interpolation neither conserves nor bounds mass on its own;
+/// a global correction rescales the new depths to the previous total less the water
+/// carried across the domain edge.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SemiLagrangianSolver;
+
+impl FlowSolver for SemiLagrangianSolver {
+    fn calculate_flow(
         &self,
         heightmap: &HeightMap,
-        water: &WaterLayer,
-        x: usize,
-        y: usize,
-        grid_spacing_m: f32,
-    ) -> Vec2 {
-        let water_surface_elevation = heightmap.get(x, y) + water.get_water_depth(x, y);
-
-        // Calculate gradients to 8 neighbors
-        let mut best_velocity = Vec2::zero();
-        let mut steepest_gradient = 0.0f32;
-
-        for dx in -1i32..=1 {
-            for dy in -1i32..=1 {
-                if dx == 0 && dy == 0 {
-                    continue;
-                }
+        water: &mut WaterLayer,
+        _drainage: Option<&DrainageNetwork>,
+        scale: &WorldScale,
+        parameters: &FlowParameters,
+        velocity_field: &mut VelocityField,
+    ) {
+        let width = heightmap.width().min(water.width());
+        let height = heightmap.height().min(water.height());
+        if width == 0 || height == 0 {
+            return;
+        }
+        let (dx, dy) = cell_spacing(scale);
+        let temporal_factor = scale.temporal_scale.temporal_factor() as f32;
 
-                let nx = (x as i32 + dx) as usize;
-                let ny = (y as i32 + dy) as usize;
-
-                if nx < heightmap.width() && ny < heightmap.height() {
-                    let neighbor_elevation = heightmap.get(nx, ny) + water.get_water_depth(nx, ny);
-                    let elevation_diff = water_surface_elevation - neighbor_elevation;
-
-                    if elevation_diff > 0.0 {
-                        let distance = if dx.abs() + dy.abs() == 2 {
-                            grid_spacing_m * 1.414213 // Diagonal distance
-                        } else {
-                            grid_spacing_m
-                        };
-
-                        let gradient = elevation_diff / distance;
-                        if gradient > steepest_gradient {
-                            steepest_gradient = gradient;
-                            let flow_speed = (self.parameters.gravity * gradient).sqrt();
-                            best_velocity =
-                                Vec2::new(dx as f32 * flow_speed, dy as f32 * flow_speed);
-                        }
-                    }
-                }
+        for x in 0..width {
+            for y in 0..height {
+                let velocity =
+                    compute_gradient_velocity(parameters, heightmap, water, x, y, (dx, dy));
+                velocity_field.set_velocity(x, y, velocity * temporal_factor);
             }
         }
 
-        best_velocity
+        let old_depth: Vec<f32> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| water.depth.get(x, y).to_f32())
+            .collect();
+        let depth_at = |x: i32, y: i32| {
+            if x < 0 || y < 0 || x >= width as i32 || y >= height as i32 {
+                0.0 // Nothing flows in from beyond the domain
+            } else {
+                old_depth[y as usize * width + x as usize]
+            }
+        };
+
+        let mut new_depth = vec![0.0f32; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let velocity = velocity_field.get_velocity(x, y);
+                let departure_x = x as f32 - velocity.x * parameters.dt / dx;
+                let departure_y = y as f32 - velocity.y * parameters.dt / dy;
+
+                let (x0, y0) = (departure_x.floor(), departure_y.floor());
+                let (fx, fy) = (departure_x - x0, departure_y - y0);
+                let (x0, y0) = (x0 as i32, y0 as i32);
+                let top = depth_at(x0, y0) * (1.0 - fx) + depth_at(x0 + 1, y0) * fx;
+                let bottom = depth_at(x0, y0 + 1) * (1.0 - fx) + depth_at(x0 + 1, y0 + 1) * fx;
+                new_depth[y * width + x] = (top * (1.0 - fy) + bottom * fy).max(0.0);
+            }
+        }
+
+        // Water whose forward trajectory ends beyond the edge leaves the domain; each cell's
+        // depth is spread over a one-cell footprint around its arrival point
+        let inside = |arrival: f32, extent: usize| {
+            let low = (arrival - 0.5).max(-0.5);
+            let high = (arrival + 0.5).min(extent as f32 - 0.5);
+            (high - low).clamp(0.0, 1.0) as f64
+        };
+        let mut outflow = 0.0f64;
+        for y in 0..height {
+            for x in 0..width {
+                let velocity = velocity_field.get_velocity(x, y);
+                let arrival_x = x as f32 + velocity.x * parameters.dt / dx;
+                let arrival_y = y as f32 + velocity.y * parameters.dt / dy;
+                let retained = inside(arrival_x, width) * inside(arrival_y, height);
+                outflow += old_depth[y * width + x] as f64 * (1.0 - retained);
+            }
+        }
+
+        // Global mass fixer: restore the total lost or gained by interpolation, after
+        // taking out what crossed the boundary
+        let old_total: f64 = old_depth.iter().map(|&d| d as f64).sum();
+        let new_total: f64 = new_depth.iter().map(|&d| d as f64).sum();
+        let target_total = (old_total - outflow).max(0.0);
+        let correction = if new_total > 0.0 {
+            (target_total / new_total) as f32
+        } else {
+            0.0 // Dry, or every path left the map
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                water.depth.set(
+                    x,
+                    y,
+                    StateFloat::from_f32(new_depth[y * width + x] * correction),
+                );
+            }
+        }
     }
+}
 
-    /// Compute conservation-based velocity using shallow water equations  
-    fn compute_conservation_velocity(
-        &self,
-        heightmap: &HeightMap,
-        water: &WaterLayer,
-        x: usize,
-        y: usize,
-        grid_spacing_m: f32,
-    ) -> Vec2 {
-        let depth = water.get_water_depth(x, y).max(self.parameters.min_depth);
-        let _elevation = heightmap.get(x, y); // Available for future conservation equations
-
-        // Pressure gradient force: -g * ∇h
-        let grad_x = self.compute_surface_gradient_x(heightmap, water, x, y, grid_spacing_m);
-        let grad_y = self.compute_surface_gradient_y(heightmap, water, x, y, grid_spacing_m);
-
-        // Manning's equation for friction
-        let current_velocity = water.velocity.get(x, y);
-        let velocity_magnitude = (current_velocity.0 * current_velocity.0
-            + current_velocity.1 * current_velocity.1)
-            .sqrt();
-
-        let manning_coefficient = self.parameters.roughness;
-        let hydraulic_radius = depth; // Approximation for wide shallow flow
-        let friction_factor =
-            (manning_coefficient * velocity_magnitude) / (hydraulic_radius.powf(2.0 / 3.0));
-
-        // Conservation momentum equation: ∂v/∂t = -g∇h - friction_terms
-        let acceleration_x =
-            -self.parameters.gravity * grad_x - friction_factor * current_velocity.0;
-        let acceleration_y =
-            -self.parameters.gravity * grad_y - friction_factor * current_velocity.1;
-
-        // Explicit time integration
-        let new_velocity_x = current_velocity.0 + acceleration_x * self.parameters.dt;
-        let new_velocity_y = current_velocity.1 + acceleration_y * self.parameters.dt;
-
-        Vec2::new(new_velocity_x, new_velocity_y)
-    }
-
-    /// Compute drainage-enhanced velocity with flow concentration
-    fn compute_drainage_enhanced_velocity(
-        &self,
+/// Double-precision working state for the shallow-water solver
+/// Each cell holds the conserved variables [h, hu, hv]
+struct ShallowWaterGrid {
+    width: usize,
+    height: usize,
+    gravity: f64,
+    min_depth: f64,
+    elevation: Vec<f64>,
+    state: Vec<[f64; 3]>,
+}
+
+impl ShallowWaterGrid {
+    fn from_layers(
         heightmap: &HeightMap,
         water: &WaterLayer,
-        x: usize,
-        y: usize,
-        grid_spacing_m: f32,
-        flow_accumulation: f32,
-    ) -> Vec2 {
-        // Start with base gradient velocity
-        let base_velocity = self.compute_gradient_velocity(heightmap, water, x, y, grid_spacing_m);
+        velocity_field: &VelocityField,
+        parameters: &FlowParameters,
+    ) -> Self {
+        let width = heightmap.width();
+        let height = heightmap.height();
+        let mut elevation = Vec::with_capacity(width * height);
+        let mut state = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                let depth = water.get_water_depth(x, y).max(0.0) as f64;
+                let velocity = velocity_field.get_velocity(x, y);
+                elevation.push(heightmap.get(x, y) as f64);
+                state.push([depth, depth * velocity.x as f64, depth * velocity.y as f64]);
+            }
+        }
 
-        // Apply drainage concentration factor (from Phase 1 solution)
-        let pixel_area = (grid_spacing_m * grid_spacing_m) as f64;
-        let concentration = 1.0
-            + (flow_accumulation as f64 / pixel_area).sqrt()
-                * self.parameters.concentration_factor as f64;
+        Self {
+            width,
+            height,
+            gravity: parameters.gravity as f64,
+            min_depth: parameters.min_depth as f64,
+            elevation,
+            state,
+        }
+    }
 
-        base_velocity * concentration as f32
+    fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
     }
 
-    /// Helper: compute surface gradient in X direction
-    fn compute_surface_gradient_x(
-        &self,
-        heightmap: &HeightMap,
-        water: &WaterLayer,
-        x: usize,
-        y: usize,
-        grid_spacing_m: f32,
-    ) -> f32 {
-        let x_left = if x > 0 { x - 1 } else { x };
-        let x_right = if x < heightmap.width() - 1 { x + 1 } else { x };
-
-        let left_surface = heightmap.get(x_left, y) + water.get_water_depth(x_left, y);
-        let right_surface = heightmap.get(x_right, y) + water.get_water_depth(x_right, y);
-
-        let distance = if x_left != x_right {
-            2.0 * grid_spacing_m
+    /// Cell velocity, treating films thinner than `min_depth` as still
+    fn velocity(&self, cell: usize) -> (f64, f64) {
+        let [h, hu, hv] = self.state[cell];
+        if h > self.min_depth {
+            (hu / h, hv / h)
         } else {
-            grid_spacing_m
-        };
+            (0.0, 0.0)
+        }
+    }
+
+    /// Largest |u| + |v| + 2√(gh); bounding dt by it keeps the unsplit update positive
+    fn max_wave_speed(&self) -> f64 {
+        (0..self.state.len())
+            .map(|cell| {
+                let (u, v) = self.velocity(cell);
+                u.abs() + v.abs() + 2.0 * (self.gravity * self.state[cell][0]).sqrt()
+            })
+            .fold(0.0, f64::max)
+    }
 
-        (right_surface - left_surface) / distance
+    /// Bed elevation and [h, normal velocity, tangential velocity] seen from a face
+    fn face_values(&self, cell: usize, normal: usize) -> (f64, [f64; 3]) {
+        let (u, v) = self.velocity(cell);
+        let (un, ut) = if normal == 1 { (u, v) } else { (v, u) };
+        (self.elevation[cell], [self.state[cell][0], un, ut])
     }
 
-    /// Helper: compute surface gradient in Y direction  
-    fn compute_surface_gradient_y(
+    /// Fluxes [mass, normal momentum, tangential momentum] leaving the left cell and
+    /// entering the right cell; a missing side is a reflective wall
+    fn face_flux(
         &self,
-        heightmap: &HeightMap,
-        water: &WaterLayer,
-        x: usize,
-        y: usize,
-        grid_spacing_m: f32,
-    ) -> f32 {
-        let y_bottom = if y > 0 { y - 1 } else { y };
-        let y_top = if y < heightmap.height() - 1 { y + 1 } else { y };
-
-        let bottom_surface = heightmap.get(x, y_bottom) + water.get_water_depth(x, y_bottom);
-        let top_surface = heightmap.get(x, y_top) + water.get_water_depth(x, y_top);
-
-        let distance = if y_bottom != y_top {
-            2.0 * grid_spacing_m
-        } else {
-            grid_spacing_m
+        left: Option<usize>,
+        right: Option<usize>,
+        normal: usize,
+    ) -> ([f64; 3], [f64; 3]) {
+        let mirror = |(z, [h, un, ut]): (f64, [f64; 3])| (z, [h, -un, ut]);
+        let ((z_l, [h_l, un_l, ut_l]), (z_r, [h_r, un_r, ut_r])) = match (left, right) {
+            (Some(l), Some(r)) => (self.face_values(l, normal), self.face_values(r, normal)),
+            (Some(l), None) => {
+                let side = self.face_values(l, normal);
+                (side, mirror(side))
+            }
+            (None, Some(r)) => {
+                let side = self.face_values(r, normal);
+                (mirror(side), side)
+            }
+            (None, None) => return ([0.0; 3], [0.0; 3]),
         };
 
-        (top_surface - bottom_surface) / distance
+        // Hydrostatic reconstruction keeps a lake at rest exactly still over uneven beds
+        let z_face = z_l.max(z_r);
+        let h_l_star = (h_l + z_l - z_face).max(0.0);
+        let h_r_star = (h_r + z_r - z_face).max(0.0);
+        let flux = hll_flux([h_l_star, un_l, ut_l], [h_r_star, un_r, ut_r], self.gravity);
+
+        let half_g = 0.5 * self.gravity;
+        let mut flux_left = flux;
+        flux_left[1] += half_g * (h_l * h_l - h_l_star * h_l_star);
+        let mut flux_right = flux;
+        flux_right[1] += half_g * (h_r * h_r - h_r_star * h_r_star);
+        (flux_left, flux_right)
     }
 
-    /// Check if cell should be updated (for spatial optimization)
-    fn should_update_cell(&self, water: &WaterLayer, x: usize, y: usize) -> bool {
-        // Simple heuristic: update if water depth is significant
-        water.get_water_depth(x, y) > self.parameters.min_depth * 10.0
+    /// One explicit finite-volume update over both axes
+    fn step(&mut self, dt: f64, dx: f64, dy: f64) {
+        let (ratio_x, ratio_y) = (dt / dx, dt / dy);
+        let mut next = self.state.clone();
+
+        for y in 0..self.height {
+            for x in 0..=self.width {
+                let left = (x > 0).then(|| self.index(x - 1, y));
+                let right = (x < self.width).then(|| self.index(x, y));
+                let (flux_left, flux_right) = self.face_flux(left, right, 1);
+                if let Some(cell) = left {
+                    apply_face_flux(&mut next[cell], &flux_left, 1, -ratio_x);
+                }
+                if let Some(cell) = right {
+                    apply_face_flux(&mut next[cell], &flux_right, 1, ratio_x);
+                }
+            }
+        }
+
+        for x in 0..self.width {
+            for y in 0..=self.height {
+                let left = (y > 0).then(|| self.index(x, y - 1));
+                let right = (y < self.height).then(|| self.index(x, y));
+                let (flux_left, flux_right) = self.face_flux(left, right, 2);
+                if let Some(cell) = left {
+                    apply_face_flux(&mut next[cell], &flux_left, 2, -ratio_y);
+                }
+                if let Some(cell) = right {
+                    apply_face_flux(&mut next[cell], &flux_right, 2, ratio_y);
+                }
+            }
+        }
+
+        self.state = next;
     }
 
-    /// Update WaterLayer velocity field from unified representation
-    fn update_water_layer_velocities(&self, water: &mut WaterLayer) {
-        for x in 0..self.velocity_field.width {
-            for y in 0..self.velocity_field.height {
-                let velocity = self.velocity_field.get_velocity(x, y);
-                water.velocity.set(x, y, (velocity.x, velocity.y));
+    /// Semi-implicit Manning friction: momentum /= 1 + dt·g·n²|v| / h^(4/3)
+    fn apply_friction(&mut self, dt: f64, roughness: f64) {
+        for cell in &mut self.state {
+            let h = cell[0];
+            if h <= self.min_depth {
+                cell[1] = 0.0;
+                cell[2] = 0.0;
+                continue;
+            }
+
+            let speed = cell[1].hypot(cell[2]) / h;
+            let drag = self.gravity * roughness * roughness * speed / h.powf(4.0 / 3.0);
+            let factor = 1.0 / (1.0 + dt * drag);
+            cell[1] *= factor;
+            cell[2] *= factor;
+        }
+    }
+}
+
+/// HLL flux for face states [h, normal velocity, tangential velocity]
+/// Uses two-rarefaction wave speed estimates with exact dry-front speeds (Toro), and
+/// upwinds the tangential momentum with the mass flux
+fn hll_flux(left: [f64; 3], right: [f64; 3], gravity: f64) -> [f64; 3] {
+    let [h_l, u_l, ut_l] = left;
+    let [h_r, u_r, ut_r] = right;
+    if h_l <= 0.0 && h_r <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let (c_l, c_r) = ((gravity * h_l).sqrt(), (gravity * h_r).sqrt());
+    let u_star = 0.5 * (u_l + u_r) + c_l - c_r;
+    let c_star = 0.5 * (c_l + c_r) + 0.25 * (u_l - u_r);
+    let s_l = if h_l <= 0.0 {
+        u_r - 2.0 * c_r
+    } else {
+        (u_l - c_l).min(u_star - c_star)
+    };
+    let s_r = if h_r <= 0.0 {
+        u_l + 2.0 * c_l
+    } else {
+        (u_r + c_r).max(u_star + c_star)
+    };
+
+    let physical_flux = |h: f64, u: f64| [h * u, h * u * u + 0.5 * gravity * h * h];
+    let (flux_l, flux_r) = (physical_flux(h_l, u_l), physical_flux(h_r, u_r));
+    let [mass, momentum] = if s_l >= 0.0 {
+        flux_l
+    } else if s_r <= 0.0 {
+        flux_r
+    } else {
+        let (cons_l, cons_r) = ([h_l, h_l * u_l], [h_r, h_r * u_r]);
+        [0, 1].map(|i| {
+            (s_r * flux_l[i] - s_l * flux_r[i] + s_l * s_r * (cons_r[i] - cons_l[i])) / (s_r - s_l)
+        })
+    };
+
+    let tangential = if mass >= 0.0 { ut_l } else { ut_r };
+    [mass, momentum, mass * tangential]
+}
+
+/// Add a scaled face flux to a cell's conserved state along the given normal axis
+fn apply_face_flux(cell: &mut [f64; 3], flux: &[f64; 3], normal: usize, ratio: f64) {
+    let tangential = 3 - normal;
+    cell[0] += ratio * flux[0];
+    cell[normal] += ratio * flux[1];
+    cell[tangential] += ratio * flux[2];
+}
+
+/// East-west and north-south cell spacing (m)
+fn cell_spacing(scale: &WorldScale) -> (f32, f32) {
+    (
+        scale.meters_per_pixel_x() as f32,
+        scale.meters_per_pixel_y() as f32,
+    )
+}
+
+/// Compute gradient-based velocity for a single cell
+/// Slopes use per-axis distances, so diagonals on rectangular cells span √(dx² + dy²).
+/// The velocity points along the physical offset to the steepest neighbor, scaled so its
+/// larger component carries the full flow speed as on square cells.
+fn compute_gradient_velocity(
+    parameters: &FlowParameters,
+    heightmap: &HeightMap,
+    water: &WaterLayer,
+    x: usize,
+    y: usize,
+    (spacing_x, spacing_y): (f32, f32),
+) -> Vec2 {
+    let water_surface_elevation = heightmap.get(x, y) + water.get_water_depth(x, y);
+
+    // Calculate gradients to 8 neighbors
+    let mut best_velocity = Vec2::zero();
+    let mut steepest_gradient = 0.0f32;
+
+    for dx in -1i32..=1 {
+        for dy in -1i32..=1 {
+            if dx == 0 && dy == 0 {
+                continue;
+            }
+
+            let nx = (x as i32 + dx) as usize;
+            let ny = (y as i32 + dy) as usize;
+
+            if nx < heightmap.width() && ny < heightmap.height() {
+                let neighbor_elevation = heightmap.get(nx, ny) + water.get_water_depth(nx, ny);
+                let elevation_diff = water_surface_elevation - neighbor_elevation;
+
+                if elevation_diff > 0.0 {
+                    let offset_x = dx as f32 * spacing_x;
+                    let offset_y = dy as f32 * spacing_y;
+                    let distance = offset_x.hypot(offset_y);
+
+                    let gradient = elevation_diff / distance;
+                    if gradient > steepest_gradient {
+                        steepest_gradient = gradient;
+                        let flow_speed = (parameters.gravity * gradient).sqrt();
+                        let dominant = offset_x.abs().max(offset_y.abs());
+                        best_velocity = Vec2::new(offset_x, offset_y) * (flow_speed / dominant);
+                    }
+                }
             }
         }
     }
+
+    best_velocity
+}
+
+/// Compute conservation-based velocity using shallow water equations  
+fn compute_conservation_velocity(
+    parameters: &FlowParameters,
+    heightmap: &HeightMap,
+    water: &WaterLayer,
+    x: usize,
+    y: usize,
+    dx: f32,
+    dy: f32,
+) -> Vec2 {
+    let depth = water.get_water_depth(x, y).max(parameters.min_depth);
+    let _elevation = heightmap.get(x, y); // Available for future conservation equations
+
+    // Pressure gradient force: -g * ∇h
+    let grad_x = compute_surface_gradient_x(heightmap, water, x, y, dx);
+    let grad_y = compute_surface_gradient_y(heightmap, water, x, y, dy);
+
+    // Manning's equation for friction
+    let current_velocity = water.velocity.get(x, y);
+    let velocity_magnitude =
+        (current_velocity.0 * current_velocity.0 + current_velocity.1 * current_velocity.1).sqrt();
+
+    let manning_coefficient = parameters.roughness;
+    let hydraulic_radius = depth; // Approximation for wide shallow flow
+    let friction_factor =
+        (manning_coefficient * velocity_magnitude) / (hydraulic_radius.powf(2.0 / 3.0));
+
+    // Conservation momentum equation: ∂v/∂t = -g∇h - friction_terms
+    let acceleration_x = -parameters.gravity * grad_x - friction_factor * current_velocity.0;
+    let acceleration_y = -parameters.gravity * grad_y - friction_factor * current_velocity.1;
+
+    // Explicit time integration
+    let new_velocity_x = current_velocity.0 + acceleration_x * parameters.dt;
+    let new_velocity_y = current_velocity.1 + acceleration_y * parameters.dt;
+
+    Vec2::new(new_velocity_x, new_velocity_y)
+}
+
+/// Compute drainage-enhanced velocity with flow concentration
+fn compute_drainage_enhanced_velocity(
+    parameters: &FlowParameters,
+    heightmap: &HeightMap,
+    water: &WaterLayer,
+    x: usize,
+    y: usize,
+    spacing: (f32, f32),
+    flow_accumulation: f32,
+) -> Vec2 {
+    // Start with base gradient velocity
+    let base_velocity = compute_gradient_velocity(parameters, heightmap, water, x, y, spacing);
+
+    // Apply drainage concentration factor (from Phase 1 solution)
+    let pixel_area = (spacing.0 * spacing.1) as f64;
+    let concentration = 1.0
+        + (flow_accumulation as f64 / pixel_area).sqrt() * parameters.concentration_factor as f64;
+
+    base_velocity * concentration as f32
+}
+
+/// Helper: compute surface gradient in X direction
+fn compute_surface_gradient_x(
+    heightmap: &HeightMap,
+    water: &WaterLayer,
+    x: usize,
+    y: usize,
+    grid_spacing_m: f32,
+) -> f32 {
+    let x_left = if x > 0 { x - 1 } else { x };
+    let x_right = if x < heightmap.width() - 1 { x + 1 } else { x };
+
+    let left_surface = heightmap.get(x_left, y) + water.get_water_depth(x_left, y);
+    let right_surface = heightmap.get(x_right, y) + water.get_water_depth(x_right, y);
+
+    let distance = if x_left != x_right {
+        2.0 * grid_spacing_m
+    } else {
+        grid_spacing_m
+    };
+
+    (right_surface - left_surface) / distance
+}
+
+/// Helper: compute surface gradient in Y direction  
+fn compute_surface_gradient_y(
+    heightmap: &HeightMap,
+    water: &WaterLayer,
+    x: usize,
+    y: usize,
+    grid_spacing_m: f32,
+) -> f32 {
+    let y_bottom = if y > 0 { y - 1 } else { y };
+    let y_top = if y < heightmap.height() - 1 { y + 1 } else { y };
+
+    let bottom_surface = heightmap.get(x, y_bottom) + water.get_water_depth(x, y_bottom);
+    let top_surface = heightmap.get(x, y_top) + water.get_water_depth(x, y_top);
+
+    let distance = if y_bottom != y_top {
+        2.0 * grid_spacing_m
+    } else {
+        grid_spacing_m
+    };
+
+    (top_surface - bottom_surface) / distance
+}
+
+/// Check if cell should be updated (for spatial optimization)
+fn should_update_cell(parameters: &FlowParameters, water: &WaterLayer, x: usize, y: usize) -> bool {
+    // Simple heuristic: update if water depth is significant
+    water.get_water_depth(x, y) > parameters.min_depth * 10.0
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::engine::core::scale::DetailLevel;
+    use crate::engine::core::scale::{DetailLevel, WorldScaleBuilder};
 
     fn create_test_scale() -> WorldScale {
         WorldScale::new(1000.0, (10, 10), DetailLevel::Standard)
@@ -689,4 +1220,339 @@ mod tests {
         // Large scale should have higher concentration factor
         assert!(large_scale.concentration_factor > interactive.concentration_factor);
     }
+
+    #[test]
+    fn test_streamline_in_uniform_rightward_field() {
+        let scale = create_test_scale();
+        let mut field = VelocityField::new(10, 10, &scale);
+        for x in 0..10 {
+            for y in 0..10 {
+                field.set_velocity(x, y, Vec2::new(2.0, 0.0));
+            }
+        }
+
+        let path = field.trace_streamline(Vec2::new(1.0, 4.5), 8, 0.5);
+        assert_eq!(path.len(), 9);
+        for point in &path {
+            assert!(
+                (point.y - 4.5).abs() < 1e-6,
+                "Streamline should stay horizontal"
+            );
+        }
+        let end = path.last().unwrap();
+        assert!((end.x - 5.0).abs() < 1e-5);
+
+        // Paths stop at the domain edge instead of running off the grid
+        let long_path = field.trace_streamline(Vec2::new(1.0, 4.5), 100, 1.0);
+        assert_eq!(long_path.len(), 9);
+        assert!((long_path.last().unwrap().x - 9.0).abs() < 1e-5);
+
+        // Stagnant flow terminates immediately
+        let still = VelocityField::new(10, 10, &scale);
+        let still_path = still.trace_streamline(Vec2::new(3.0, 3.0), 10, 0.5);
+        assert_eq!(still_path.len(), 1);
+    }
+
+    #[test]
+    fn test_adaptive_dt_shrinks_for_fast_flow() {
+        let scale = create_test_scale();
+        let heightmap = HeightMap::from_nested(vec![vec![0.5; 10]; 10]);
+        let mut water = WaterLayer::new(10, 10);
+        let set_speed = |water: &mut WaterLayer, speed: f32| {
+            for x in 0..10 {
+                for y in 0..10 {
+                    water.depth.set(x, y, 0.1);
+                    water.velocity.set(x, y, (speed, 0.0));
+                }
+            }
+        };
+
+        let mut engine = FlowEngine::new(FlowAlgorithm::Gradient, 10, 10, &scale);
+        engine.parameters.dt = 100.0;
+        engine.parameters.cfl_safety = 0.5;
+        engine.parameters.adaptive_dt = true;
+
+        // Calm flow keeps the nominal timestep
+        set_speed(&mut water, 0.01);
+        engine.calculate_flow(&heightmap, &mut water, None, &scale);
+        let calm_dt = engine.current_dt();
+        assert_eq!(calm_dt, 100.0);
+        assert_eq!(engine.current_substeps(), 1);
+
+        // A fast field at the nominal dt would have Courant number 5
+        let fast_speed = 5.0 * scale.meters_per_pixel() as f32 / 100.0;
+        set_speed(&mut water, fast_speed);
+        assert!((engine.max_courant_number(&water, &scale) - 5.0).abs() < 1e-4);
+        engine.calculate_flow(&heightmap, &mut water, None, &scale);
+        let fast_dt = engine.current_dt();
+        assert!(fast_dt < calm_dt);
+        set_speed(&mut water, fast_speed);
+        assert!(engine.max_courant_number(&water, &scale) <= 0.5 + 1e-5);
+
+        // The substeps still cover the whole nominal interval
+        let fast_substeps = engine.current_substeps();
+        assert!(fast_substeps >= 10);
+        assert!((fast_dt * fast_substeps as f32 - calm_dt).abs() < 1e-3);
+
+        // Once flow calms dt grows back to nominal, at most doubling per step
+        set_speed(&mut water, 0.0);
+        engine.calculate_flow(&heightmap, &mut water, None, &scale);
+        let recovering_dt = engine.current_dt();
+        assert!(recovering_dt > fast_dt && recovering_dt <= fast_dt * 2.0);
+        for _ in 0..5 {
+            engine.calculate_flow(&heightmap, &mut water, None, &scale);
+        }
+        assert_eq!(engine.current_dt(), calm_dt);
+        assert_eq!(engine.current_substeps(), 1);
+    }
+
+    #[derive(Debug)]
+    struct StillWaterSolver;
+
+    impl FlowSolver for StillWaterSolver {
+        fn calculate_flow(
+            &self,
+            heightmap: &HeightMap,
+            _water: &mut WaterLayer,
+            _drainage: Option<&DrainageNetwork>,
+            _scale: &WorldScale,
+            _parameters: &FlowParameters,
+            velocity_field: &mut VelocityField,
+        ) {
+            for x in 0..heightmap.width() {
+                for y in 0..heightmap.height() {
+                    velocity_field.set_velocity(x, y, Vec2::zero());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_solver_overrides_algorithm() {
+        let scale = create_test_scale();
+        let mut data = vec![vec![0.0; 10]; 10];
+        for (y, row) in data.iter_mut().enumerate() {
+            for (x, value) in row.iter_mut().enumerate() {
+                *value = 1.0 - (x + y) as f32 * 0.05; // Steady downhill slope
+            }
+        }
+        let heightmap = HeightMap::from_nested(data);
+        let mut water = WaterLayer::new(10, 10);
+        for x in 0..10 {
+            for y in 0..10 {
+                water.depth.set(x, y, 0.1);
+            }
+        }
+
+        // The built-in gradient solver moves water down the slope
+        let mut builtin = FlowEngine::new(FlowAlgorithm::Gradient, 10, 10, &scale);
+        builtin.calculate_flow(&heightmap, &mut water, None, &scale);
+        assert!(builtin.velocity_field.get_velocity(3, 3).magnitude() > 0.0);
+
+        // A registered custom solver replaces it entirely
+        let mut custom = FlowEngine::new(FlowAlgorithm::Gradient, 10, 10, &scale)
+            .with_solver(Box::new(StillWaterSolver));
+        assert!(custom.has_custom_solver());
+        custom.calculate_flow(&heightmap, &mut water, None, &scale);
+        for x in 0..10 {
+            for y in 0..10 {
+                assert_eq!(custom.velocity_field.get_velocity(x, y), Vec2::zero());
+                assert_eq!(water.velocity.get(x, y), (0.0, 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_shallow_water_dam_break_matches_ritter_front() {
+        // 200 m channel at 1 m resolution with a 1 m dam of water released onto a dry bed
+        let (width, height) = (200, 4);
+        let scale = WorldScale::new(0.2, (width as u32, height as u32), DetailLevel::Standard);
+        let heightmap = HeightMap::new(width, height, 0.0);
+        let mut water = WaterLayer::new(width, height);
+        let dam_x = 100;
+        let initial_depth = 1.0;
+        for x in 0..dam_x {
+            for y in 0..height {
+                water.depth.set(x, y, StateFloat::from_f32(initial_depth));
+            }
+        }
+        let initial_mass: f64 = water.depth.iter().map(|d| d as f64).sum();
+
+        let mut engine = FlowEngine::new(FlowAlgorithm::ShallowWater, width, height, &scale);
+        engine.parameters.roughness = 0.0; // Ritter's solution is frictionless
+        engine.parameters.dt = 1.0;
+        let elapsed_seconds = 8.0;
+        for _ in 0..8 {
+            engine.calculate_flow(&heightmap, &mut water, None, &scale);
+        }
+
+        // Ritter: downstream of the dam √h falls linearly to zero at the front,
+        // x_front = x_dam + 2√(g·h₀)·t, so extrapolate the resolved part of the profile
+        let celerity = (engine.parameters.gravity * initial_depth).sqrt();
+        let analytic_front = dam_x as f32 + 2.0 * celerity * elapsed_seconds;
+        let samples: Vec<(f32, f32)> = (dam_x..width)
+            .map(|x| (x as f32 + 0.5, water.get_water_depth(x, 1)))
+            .filter(|&(_, depth)| depth > 0.02 && depth < 0.3)
+            .map(|(x, depth)| (x, depth.sqrt()))
+            .collect();
+        assert!(samples.len() > 5);
+        let n = samples.len() as f32;
+        let mean_x = samples.iter().map(|s| s.0).sum::<f32>() / n;
+        let mean_root = samples.iter().map(|s| s.1).sum::<f32>() / n;
+        let slope = samples
+            .iter()
+            .map(|s| (s.0 - mean_x) * (s.1 - mean_root))
+            .sum::<f32>()
+            / samples.iter().map(|s| (s.0 - mean_x).powi(2)).sum::<f32>();
+        let numeric_front = mean_x - mean_root / slope;
+
+        let analytic_travel = analytic_front - dam_x as f32;
+        let relative_error = (numeric_front - analytic_front).abs() / analytic_travel;
+        assert!(
+            relative_error < 0.05,
+            "front reached {:.1} m, Ritter predicts {:.1} m",
+            numeric_front,
+            analytic_front
+        );
+
+        // Nothing outruns the analytic front
+        for x in (analytic_front as usize + 2)..width {
+            assert!(water.get_water_depth(x, 1) < 1e-6);
+        }
+
+        // Depth at the dam site stays at the Ritter value 4h₀/9
+        let dam_depth = water.get_water_depth(dam_x, 1);
+        assert!((dam_depth - 4.0 * initial_depth / 9.0).abs() < 0.03);
+
+        let final_mass: f64 = water.depth.iter().map(|d| d as f64).sum();
+        assert!((final_mass - initial_mass).abs() / initial_mass < 1e-6);
+    }
+
+    #[test]
+    fn test_shallow_water_conserves_mass_in_closed_basin() {
+        let size = 24;
+        let scale = WorldScale::new(2.4, (size as u32, size as u32), DetailLevel::Standard);
+        let mut heightmap = HeightMap::new(size, size, 0.0);
+        let mut water = WaterLayer::new(size, size);
+        for x in 0..size {
+            for y in 0..size {
+                let bump = ((x as f32 * 0.4).sin() * (y as f32 * 0.3).cos()).max(0.0);
+                heightmap.set(x, y, 0.5 * bump);
+                if x < size / 3 {
+                    water.depth.set(x, y, 2.0); // Sloshing column against the west wall
+                }
+            }
+        }
+        let initial_mass: f64 = water.depth.iter().map(|d| d as f64).sum();
+
+        let mut engine = FlowEngine::new(FlowAlgorithm::ShallowWater, size, size, &scale);
+        engine.parameters.dt = 30.0;
+        for _ in 0..10 {
+            engine.calculate_flow(&heightmap, &mut water, None, &scale);
+        }
+
+        let final_mass: f64 = water.depth.iter().map(|d| d as f64).sum();
+        let relative_error = (final_mass - initial_mass).abs() / initial_mass;
+        assert!(
+            relative_error < 1e-6,
+            "mass drifted by {:.2e}",
+            relative_error
+        );
+        assert!(water.depth.iter().all(|d| d >= 0.0));
+        assert!(
+            water.get_water_depth(size / 2, size / 2) > 0.1,
+            "the column should collapse across the basin"
+        );
+    }
+
+    #[test]
+    fn semi_lagrangian_moves_pulse_several_cells_and_conserves_water() {
+        // Uniform eastward slope, 1 km cells; a narrow pulse starts at x = 5
+        let (width, height) = (40, 5);
+        let scale = WorldScale::new(40.0, (width as u32, height as u32), DetailLevel::Standard);
+        let heightmap = HeightMap::from_nested(
+            (0..height)
+                .map(|_| (0..width).map(|x| 1.0 - 0.02 * x as f32).collect())
+                .collect(),
+        );
+        let mut water = WaterLayer::new(width, height);
+        for y in 0..height {
+            water.depth.set(5, y, 0.001);
+        }
+        let initial_total = water.get_total_water();
+
+        let mut engine = FlowEngine::new(FlowAlgorithm::SemiLagrangian, width, height, &scale);
+        // Flow speed √(g·0.02/1000) ≈ 0.014 m/s; move the pulse about four cells
+        engine.parameters.dt = 4.0 * 1000.0 / (9.81f32 * 0.02 / 1000.0).sqrt();
+        engine.calculate_flow(&heightmap, &mut water, None, &scale);
+
+        let column = |x: usize| water.get_water_depth(x, 2);
+        let peak = (0..width)
+            .max_by(|&a, &b| column(a).total_cmp(&column(b)))
+            .unwrap();
+        assert!((8..=10).contains(&peak), "pulse peak at x = {peak}");
+        assert!(column(5) < 0.1 * column(peak));
+        assert!(engine.max_courant_number(&water, &scale) > 1.0);
+
+        let final_total = water.get_total_water();
+        assert!((final_total - initial_total).abs() / initial_total < 1e-5);
+    }
+
+    #[test]
+    fn semi_lagrangian_lets_water_drain_off_the_edge() {
+        // Same eastward slope; the pulse starts two cells from the outlet edge
+        let (width, height) = (40, 5);
+        let scale = WorldScale::new(40.0, (width as u32, height as u32), DetailLevel::Standard);
+        let heightmap = HeightMap::from_nested(
+            (0..height)
+                .map(|_| (0..width).map(|x| 1.0 - 0.02 * x as f32).collect())
+                .collect(),
+        );
+        let mut water = WaterLayer::new(width, height);
+        for y in 0..height {
+            water.depth.set(width - 3, y, 0.001);
+        }
+        let initial_total = water.get_total_water();
+
+        let mut engine = FlowEngine::new(FlowAlgorithm::SemiLagrangian, width, height, &scale);
+        // About four cells of travel carries the whole pulse past the edge
+        engine.parameters.dt = 4.0 * 1000.0 / (9.81f32 * 0.02 / 1000.0).sqrt();
+        engine.calculate_flow(&heightmap, &mut water, None, &scale);
+
+        // Rescaling to the old total would pile the pulse back into the last cells
+        let final_total = water.get_total_water();
+        assert!(
+            final_total < 0.1 * initial_total,
+            "{final_total} of {initial_total}"
+        );
+        assert!(water.depth.iter().all(|d| d >= 0.0));
+    }
+
+    #[test]
+    fn gradient_flow_uses_per_axis_spacing_on_rectangular_cells() {
+        // 1 km east-west by 100 m north-south cells
+        let scale = WorldScaleBuilder::new((3, 3))
+            .physical_size_km(3.0, 0.3)
+            .build();
+        // East drops 1.0 over 1 km, south only 0.2 over 100 m, so south is steeper
+        let heightmap = HeightMap::from_nested(vec![
+            vec![2.0, 2.0, 2.0],
+            vec![2.0, 2.0, 1.0],
+            vec![2.0, 1.8, 0.95],
+        ]);
+        let water = WaterLayer::new(3, 3);
+        let parameters = FlowParameters::default();
+
+        let velocity =
+            compute_gradient_velocity(&parameters, &heightmap, &water, 1, 1, cell_spacing(&scale));
+        assert_eq!(velocity.x, 0.0);
+        let expected_speed = (parameters.gravity * 0.2 / 100.0).sqrt();
+        assert!((velocity.y - expected_speed).abs() < 1e-6);
+
+        // Square cells keep the east neighbor and the unit cardinal direction
+        let velocity =
+            compute_gradient_velocity(&parameters, &heightmap, &water, 1, 1, (1000.0, 1000.0));
+        assert_eq!(velocity.y, 0.0);
+        assert!((velocity.x - (parameters.gravity * 1.0 / 1000.0).sqrt()).abs() < 1e-6);
+    }
 }
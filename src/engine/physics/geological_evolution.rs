@@ -5,6 +5,7 @@
 // ABOUTME: Runs accelerated water flow and erosion over geological timescales before real-time simulation
 
 use super::super::core::heightmap::HeightMap;
+use super::super::core::physics_grid::Float;
 use super::super::core::scale::{DetailLevel, WorldScale};
 use super::climate::{ClimateSystem, TemperatureLayer};
 use super::flow_engine::{FlowEngine, FlowParameters};
@@ -249,7 +250,7 @@ impl GeologicalEvolution {
 
         for y in 0..heightmap.len() {
             for x in 0..heightmap[0].len() {
-                let water_amount = water_layer.depth[y][x];
+                let water_amount = water_layer.depth[y][x].to_f32();
                 let sediment_amount = water_layer.sediment[y][x];
 
                 // Additional erosion where water is flowing (CORRECTION #4: Lower threshold for geological testing)
@@ -288,7 +289,7 @@ impl GeologicalEvolution {
 
         for y in 0..water_layer.height() {
             for x in 0..water_layer.width() {
-                if water_layer.depth[y][x] > river_threshold {
+                if water_layer.depth[y][x].to_f32() > river_threshold {
                     // Count connected water cells (simple approximation)
                     let mut connections = 0;
 
@@ -307,7 +308,9 @@ impl GeologicalEvolution {
                                 && nx >= 0
                                 && nx < water_layer.width() as i32
                             {
-                                if water_layer.depth[ny as usize][nx as usize] > river_threshold {
+                                if water_layer.depth[ny as usize][nx as usize].to_f32()
+                                    > river_threshold
+                                {
                                     connections += 1;
                                 }
                             }
@@ -215,6 +215,47 @@ impl WaterAvailability {
     }
 }
 
+// Depths and rates at which each availability component reaches half its maximum
+const SURFACE_WATER_HALF_DEPTH_M: f32 = 0.01;
+const SOIL_MOISTURE_HALF_DEPTH_M: f32 = 0.025;
+const PRECIPITATION_HALF_MM: f32 = 500.0;
+
+/// Water availability at a single cell combining surface water, soil moisture,
+/// recent rainfall and upstream supply into one score for downstream systems
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CellWaterAvailability {
+    /// Ponded or flowing surface water depth (m)
+    pub surface_water_m: f32,
+    /// Water held in the soil (m)
+    pub soil_moisture_m: f32,
+    /// Recent rainfall expressed as an annual rate (mm/year)
+    pub annual_precipitation_mm: f32,
+    /// Upstream watershed area draining through the cell (km²)
+    pub upstream_watershed_km2: f32,
+}
+
+impl CellWaterAvailability {
+    /// Availability score (0.0 = parched, 1.0 = abundant water)
+    pub fn availability_index(&self) -> f32 {
+        let saturating = |value: f32, half: f32| {
+            let value = value.max(0.0);
+            value / (value + half)
+        };
+        let surface = saturating(self.surface_water_m, SURFACE_WATER_HALF_DEPTH_M);
+        let soil = saturating(self.soil_moisture_m, SOIL_MOISTURE_HALF_DEPTH_M);
+        let rainfall = saturating(self.annual_precipitation_mm, PRECIPITATION_HALF_MM);
+        // Same square-root watershed scaling as WaterAvailability: √(100 km²) = 1.0
+        let upstream = (self.upstream_watershed_km2.max(0.0).sqrt() / 10.0).min(1.0);
+
+        (surface * 0.35 + soil * 0.20 + rainfall * 0.20 + upstream * 0.25).clamp(0.0, 1.0)
+    }
+
+    /// Normalized aridity (0.0 = abundant water, 1.0 = parched)
+    pub fn aridity_index(&self) -> f32 {
+        1.0 - self.availability_index()
+    }
+}
+
 /// Extended biome classifier that incorporates water availability from flow dynamics
 ///
 /// **Scientific Foundation**: This implements the ecological principle that vegetation
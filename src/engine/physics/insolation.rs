@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Top-of-atmosphere solar flux from latitude and season for radiative temperature forcing
+// ABOUTME: Uses a linear declination approximation and daily-mean insolation with polar night
+
+use crate::engine::core::scale::WorldScale;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+/// Stefan-Boltzmann constant (W/(m²·K⁴))
+const STEFAN_BOLTZMANN: f32 = 5.670_374e-8;
+
+/// Meridional distance spanned by one degree of latitude (km)
+pub const KM_PER_DEGREE_LATITUDE: f64 = 111.32;
+
+/// Latitude in degrees for a map row of a domain `extent_km` tall from north to south
+/// The equator sits at map center and row 0 is the northern edge, matching the temperature
+/// generators' layout; domains larger than the globe are capped at the poles
+pub fn row_latitude_degrees(y: usize, height: usize, extent_km: f64) -> f32 {
+    if height == 0 {
+        return 0.0;
+    }
+    let half_extent_degrees = (extent_km / KM_PER_DEGREE_LATITUDE * 0.5).min(90.0);
+    let north_south_position = y as f64 / height as f64;
+    ((0.5 - north_south_position) * 2.0 * half_extent_degrees) as f32
+}
+
+/// Solar forcing component for seasonal and latitudinal temperature structure
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Insolation {
+    /// Solar flux at the top of the atmosphere (W/m²)
+    pub solar_constant: f32,
+    /// Axial tilt controlling the declination swing (degrees)
+    pub max_declination_degrees: f32,
+    /// Fraction of incoming radiation reflected back to space
+    pub albedo: f32,
+    /// Effective longwave emissivity including greenhouse trapping
+    pub emissivity: f32,
+    /// North-south extent of the domain the map rows span (km)
+    pub domain_extent_km: f64,
+}
+
+impl Default for Insolation {
+    fn default() -> Self {
+        Self {
+            solar_constant: 1361.0,         // Present-day solar constant
+            max_declination_degrees: 23.44, // Earth's obliquity
+            albedo: 0.3,                    // Planetary mean albedo
+            emissivity: 0.61,               // Greenhouse-adjusted effective emissivity
+            // Pole to pole, for forcing not tied to a domain
+            domain_extent_km: 180.0 * KM_PER_DEGREE_LATITUDE,
+        }
+    }
+}
+
+impl Insolation {
+    /// Default solar forcing for a domain of the given world scale
+    pub fn for_scale(scale: &WorldScale) -> Self {
+        Self {
+            domain_extent_km: scale.physical_size_km,
+            ..Self::default()
+        }
+    }
+
+    /// Solar declination in radians for a seasonal position
+    /// Season follows the climate convention: 0.0 = winter, 0.5 = equinox, 1.0 = summer
+    /// (northern hemisphere)
+    pub fn declination(&self, season: f32) -> f32 {
+        let season = season.clamp(0.0, 1.0);
+        self.max_declination_degrees.to_radians() * (season - 0.5) * 2.0
+    }
+
+    /// Daily-mean top-of-atmosphere flux (W/m²) at a latitude and season
+    pub fn daily_mean_flux(&self, latitude_degrees: f32, season: f32) -> f32 {
+        let latitude = latitude_degrees.clamp(-90.0, 90.0).to_radians();
+        let declination = self.declination(season);
+
+        // Sunset hour angle: cos(h0) = -tan(φ)tan(δ), clamped for polar day and night
+        let cos_h0 = (-latitude.tan() * declination.tan()).clamp(-1.0, 1.0);
+        let h0 = cos_h0.acos();
+
+        let flux = self.solar_constant / PI
+            * (h0 * latitude.sin() * declination.sin()
+                + latitude.cos() * declination.cos() * h0.sin());
+        flux.max(0.0)
+    }
+
+    /// Daily-mean flux (W/m²) for a map row
+    pub fn flux_for_row(&self, y: usize, height: usize, season: f32) -> f32 {
+        self.daily_mean_flux(
+            row_latitude_degrees(y, height, self.domain_extent_km),
+            season,
+        )
+    }
+
+    /// Radiative equilibrium surface temperature (°C) for an incoming flux
+    /// Zero flux (polar night) yields absolute zero; callers clamp to climate limits
+    pub fn equilibrium_temperature_c(&self, flux_w_m2: f32) -> f32 {
+        self.surface_equilibrium_temperature_c(flux_w_m2, self.albedo)
+    }
+
+    /// Radiative equilibrium temperature (°C) using a land surface's albedo in place of
+    /// the planetary mean, so bright deserts and ice run cooler than dark forest
+    pub fn surface_equilibrium_temperature_c(&self, flux_w_m2: f32, albedo: f32) -> f32 {
+        let absorbed = flux_w_m2.max(0.0) * (1.0 - albedo.clamp(0.0, 1.0));
+        (absorbed / (self.emissivity * STEFAN_BOLTZMANN)).powf(0.25) - 273.15
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::core::scale::DetailLevel;
+
+    #[test]
+    fn winter_polar_flux_approaches_zero() {
+        let insolation = Insolation::default();
+        let winter = 0.0;
+
+        let north_pole_winter = insolation.daily_mean_flux(89.0, winter);
+        let equator_winter = insolation.daily_mean_flux(0.0, winter);
+        let north_pole_summer = insolation.daily_mean_flux(89.0, 1.0);
+
+        assert!(
+            north_pole_winter < 1.0,
+            "Polar night should receive no sunlight, got {:.2} W/m²",
+            north_pole_winter
+        );
+        assert!(equator_winter > 350.0);
+        assert!(
+            north_pole_summer > equator_winter,
+            "Polar day at solstice should exceed equatorial flux"
+        );
+
+        // The top map row sits in polar night during winter
+        let top_row = insolation.flux_for_row(0, 100, winter);
+        assert!(top_row < 1.0);
+    }
+
+    #[test]
+    fn equinox_flux_is_symmetric_and_decreases_poleward() {
+        let insolation = Insolation::default();
+        let north = insolation.daily_mean_flux(60.0, 0.5);
+        let south = insolation.daily_mean_flux(-60.0, 0.5);
+        let equator = insolation.daily_mean_flux(0.0, 0.5);
+
+        assert!((north - south).abs() < 1e-3);
+        assert!(equator > north);
+        assert!((equator - 1361.0 / PI).abs() < 1.0);
+    }
+
+    #[test]
+    fn row_latitude_follows_domain_extent() {
+        // A 1000 km regional domain spans about ±4.5° around the equator
+        let scale = WorldScale::new(1000.0, (50, 50), DetailLevel::Standard);
+        let insolation = Insolation::for_scale(&scale);
+
+        let top = row_latitude_degrees(0, 50, scale.physical_size_km);
+        assert!((top - 4.49).abs() < 0.01, "got {:.2}°", top);
+        assert_eq!(row_latitude_degrees(25, 50, scale.physical_size_km), 0.0);
+        assert_eq!(
+            insolation.flux_for_row(0, 50, 0.5),
+            insolation.daily_mean_flux(top, 0.5)
+        );
+
+        // Continental extents are capped at the poles
+        assert_eq!(row_latitude_degrees(0, 50, 40_000.0), 90.0);
+    }
+
+    #[test]
+    fn equilibrium_temperature_is_earthlike() {
+        let insolation = Insolation::default();
+        let tropical = insolation.equilibrium_temperature_c(1361.0 / PI);
+        assert!(tropical > 15.0 && tropical < 45.0, "got {:.1}°C", tropical);
+        assert!(insolation.equilibrium_temperature_c(0.0) < -200.0);
+    }
+}
@@ -6,7 +6,17 @@
 
 use super::climate::TemperatureLayer;
 use super::flow_engine::FlowEngine;
-use crate::engine::core::{heightmap::HeightMap, math::Vec2, scale::WorldScale};
+use crate::engine::core::{PhysicsGrid, heightmap::HeightMap, math::Vec2, scale::WorldScale};
+
+/// Elevation below which a cell is treated as open water for maritime effects
+const SEA_LEVEL_THRESHOLD: f32 = 0.01;
+/// Seawater density (kg/m³)
+const SEAWATER_DENSITY: f32 = 1025.0;
+/// Seawater specific heat (J/(kg·K))
+const SEAWATER_SPECIFIC_HEAT: f32 = 3990.0;
+/// Heat capacity of the atmospheric boundary layer per unit area (J/(m²·K))
+/// ρ_air × c_p × ~1km mixing height
+const BOUNDARY_LAYER_HEAT_CAPACITY: f32 = 1.225 * 1004.0 * 1000.0;
 
 /// Coastal thermal effects on atmospheric circulation
 ///
@@ -198,12 +208,148 @@ impl CoastalThermalEffects {
     }
 }
 
+/// Parameters for the ocean mixed-layer heat reservoir
+#[derive(Debug, Clone)]
+pub struct OceanHeatParameters {
+    /// Depth of the ocean mixed layer exchanging heat with the air (m)
+    pub mixed_layer_depth_m: f32,
+    /// Bulk air-sea heat exchange coefficient (W/(m²·K))
+    pub exchange_coefficient: f32,
+    /// Climatological mean ocean temperature the reservoir relaxes toward (°C)
+    pub climatological_mean_c: f32,
+    /// Relaxation timescale toward the climatological mean (days)
+    pub relaxation_time_days: f32,
+}
+
+impl Default for OceanHeatParameters {
+    fn default() -> Self {
+        Self {
+            mixed_layer_depth_m: 75.0,   // Mid-latitude winter mixed layer
+            exchange_coefficient: 15.0,  // Combined sensible + latent bulk exchange
+            climatological_mean_c: 15.0, // Matches the default sea-level base temperature
+            relaxation_time_days: 730.0, // Slow deep-ocean restoring
+        }
+    }
+}
+
+/// Ocean heat reservoir: per-cell mixed-layer temperature for ocean cells
+///
+/// **Physical Process**: The mixed layer stores heat with capacity ρ c_p h and exchanges
+/// it with surface air through a bulk flux F = k (T_ocean - T_air), while relaxing
+/// slowly toward a climatological mean. Its large heat capacity makes ocean surface
+/// temperature lag the air temperature cycle and keeps coastal winters mild.
+#[derive(Debug, Clone)]
+pub struct OceanHeatReservoir {
+    /// Reservoir parameters
+    pub parameters: OceanHeatParameters,
+    /// Mixed-layer temperature (°C); meaningful only where `is_ocean` is set
+    pub ocean_temperature: PhysicsGrid<f32>,
+    /// Ocean cell mask derived from the heightmap
+    pub is_ocean: PhysicsGrid<bool>,
+}
+
+impl OceanHeatReservoir {
+    /// Create a reservoir over the heightmap's ocean cells, starting at the current air temperature
+    pub fn from_terrain(
+        heightmap: &HeightMap,
+        temperature_layer: &TemperatureLayer,
+        parameters: OceanHeatParameters,
+    ) -> Self {
+        let width = heightmap.width();
+        let height = heightmap.height();
+        let mut ocean_temperature = PhysicsGrid::new(width, height, 0.0);
+        let mut is_ocean = PhysicsGrid::new(width, height, false);
+
+        for y in 0..height {
+            for x in 0..width {
+                if heightmap.get(x, y) < SEA_LEVEL_THRESHOLD {
+                    is_ocean.set(x, y, true);
+                    ocean_temperature.set(x, y, temperature_layer.get_temperature(x, y));
+                }
+            }
+        }
+
+        Self {
+            parameters,
+            ocean_temperature,
+            is_ocean,
+        }
+    }
+
+    /// Mixed-layer heat capacity per unit area (J/(m²·K))
+    pub fn heat_capacity(&self) -> f32 {
+        SEAWATER_DENSITY * SEAWATER_SPECIFIC_HEAT * self.parameters.mixed_layer_depth_m
+    }
+
+    /// Get ocean temperature at a cell (None for land)
+    pub fn get_ocean_temperature(&self, x: usize, y: usize) -> Option<f32> {
+        if x < self.is_ocean.width() && y < self.is_ocean.height() && *self.is_ocean.get(x, y) {
+            Some(*self.ocean_temperature.get(x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Exchange heat between ocean cells and the overlying air for one time step
+    /// Air gains exactly what the ocean loses, limited so neither side overshoots the other;
+    /// separately, the reservoir relaxes toward climatology through the deep ocean
+    pub fn exchange_heat(
+        &mut self,
+        temperature_layer: &mut TemperatureLayer,
+        season: f32,
+        dt_seconds: f32,
+    ) {
+        let ocean_capacity = self.heat_capacity();
+        let relaxation_seconds = self.parameters.relaxation_time_days * 86400.0;
+        let width = self.is_ocean.width().min(temperature_layer.width());
+        let height = self.is_ocean.height().min(temperature_layer.height());
+
+        for y in 0..height {
+            for x in 0..width {
+                if !*self.is_ocean.get(x, y) {
+                    continue;
+                }
+
+                let ocean_temp = *self.ocean_temperature.get(x, y);
+                let air_temp = temperature_layer.get_current_temperature(x, y, season);
+
+                // Bulk air-sea flux (W/m², positive = ocean warms air)
+                let heat_flux = self.parameters.exchange_coefficient * (ocean_temp - air_temp);
+
+                // Energy moved this step (J/m²), capped at what brings both sides level
+                let equalizing_energy = (ocean_temp - air_temp)
+                    / (1.0 / BOUNDARY_LAYER_HEAT_CAPACITY + 1.0 / ocean_capacity);
+                let exchanged = (heat_flux * dt_seconds)
+                    .clamp(-equalizing_energy.abs(), equalizing_energy.abs());
+
+                let relaxation = if relaxation_seconds > 0.0 {
+                    (self.parameters.climatological_mean_c - ocean_temp) / relaxation_seconds
+                } else {
+                    0.0
+                };
+                let new_ocean_temp =
+                    ocean_temp + relaxation * dt_seconds - exchanged / ocean_capacity;
+                self.ocean_temperature.set(x, y, new_ocean_temp);
+
+                temperature_layer.set_current_temperature(
+                    x,
+                    y,
+                    season,
+                    air_temp + exchanged / BOUNDARY_LAYER_HEAT_CAPACITY,
+                );
+            }
+        }
+    }
+}
+
 /// Extended atmosphere system that incorporates maritime thermal effects
 #[derive(Debug)]
 pub struct MaritimAwareAtmosphereSystem {
     /// Maritime influence strength (0.0-1.0)
     /// 0.0 = ignore coastal effects, 1.0 = fully influenced by thermal contrasts
     pub maritime_influence: f32,
+    /// Ocean heat reservoir buffering coastal air temperature (None until initialized)
+    pub ocean_reservoir: Option<OceanHeatReservoir>,
 }
 
 impl MaritimAwareAtmosphereSystem {
@@ -211,6 +357,33 @@ impl MaritimAwareAtmosphereSystem {
     pub fn new_for_scale(_scale: &WorldScale, maritime_influence: f32) -> Self {
         Self {
             maritime_influence: maritime_influence.clamp(0.0, 1.0),
+            ocean_reservoir: None,
+        }
+    }
+
+    /// Initialize the ocean heat reservoir from terrain and the current temperature field
+    pub fn initialize_ocean_reservoir(
+        &mut self,
+        heightmap: &HeightMap,
+        temperature_layer: &TemperatureLayer,
+        parameters: OceanHeatParameters,
+    ) {
+        self.ocean_reservoir = Some(OceanHeatReservoir::from_terrain(
+            heightmap,
+            temperature_layer,
+            parameters,
+        ));
+    }
+
+    /// Advance air-sea heat exchange; no-op until the reservoir is initialized
+    pub fn update_ocean_heat(
+        &mut self,
+        temperature_layer: &mut TemperatureLayer,
+        season: f32,
+        dt_seconds: f32,
+    ) {
+        if let Some(reservoir) = self.ocean_reservoir.as_mut() {
+            reservoir.exchange_heat(temperature_layer, season, dt_seconds);
         }
     }
 
@@ -409,4 +582,97 @@ mod tests {
             full_maritime_velocity.x, full_maritime_velocity.y
         );
     }
+
+    #[test]
+    fn ocean_air_exchange_conserves_heat_and_keeps_seasonal_cycle() {
+        let heightmap = HeightMap::from_nested(vec![vec![-0.5; 3]; 3]);
+        let mut temperature_layer = TemperatureLayer::new(3, 3);
+        temperature_layer.temperature.fill(5.0);
+        temperature_layer.seasonal_variation.fill(10.0);
+        let mut reservoir = OceanHeatReservoir::from_terrain(
+            &heightmap,
+            &temperature_layer,
+            OceanHeatParameters {
+                relaxation_time_days: 0.0,
+                ..Default::default()
+            },
+        );
+        reservoir.ocean_temperature.fill(25.0);
+
+        // Summer air at 15 °C under a 25 °C ocean; a long step would overshoot without the cap
+        let summer = 1.0;
+        let air_before = temperature_layer.get_current_temperature(1, 1, summer);
+        reservoir.exchange_heat(&mut temperature_layer, summer, 1.0e9);
+        let air_after = temperature_layer.get_current_temperature(1, 1, summer);
+        let ocean_after = reservoir.get_ocean_temperature(1, 1).unwrap();
+
+        assert!((air_before - 15.0).abs() < 1e-4);
+        assert!(
+            (air_after - ocean_after).abs() < 1e-3,
+            "{air_after} vs {ocean_after}"
+        );
+        let air_gain = (air_after - air_before) * BOUNDARY_LAYER_HEAT_CAPACITY;
+        let ocean_loss = (25.0 - ocean_after) * reservoir.heat_capacity();
+        assert!((air_gain - ocean_loss).abs() < 1e-3 * ocean_loss);
+
+        // The seasonal swing around the new mean is untouched
+        assert_eq!(*temperature_layer.seasonal_variation.get(1, 1), 10.0);
+        let winter = temperature_layer.get_current_temperature(1, 1, 0.0);
+        assert!((air_after - winter - 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn ocean_reservoir_lags_air_temperature_by_a_season() {
+        // Single ocean column forced by a prescribed annual air temperature cycle
+        let heightmap = HeightMap::from_nested(vec![vec![-0.5; 3]; 3]);
+        let scale = WorldScale::new(30.0, (3, 3), DetailLevel::Standard);
+        let mut temperature_layer = TemperatureLayer::new(3, 3);
+        temperature_layer.temperature.fill(15.0);
+
+        let mut maritime = MaritimAwareAtmosphereSystem::new_for_scale(&scale, 1.0);
+        maritime.initialize_ocean_reservoir(
+            &heightmap,
+            &temperature_layer,
+            OceanHeatParameters::default(),
+        );
+
+        let day_seconds = 86400.0;
+        let mut air_series = Vec::new();
+        let mut ocean_series = Vec::new();
+        for day in 0..730 {
+            let phase = 2.0 * std::f32::consts::PI * day as f32 / 365.0;
+            let air_temp = 15.0 + 10.0 * phase.sin();
+            temperature_layer.temperature.fill(air_temp);
+
+            maritime.update_ocean_heat(&mut temperature_layer, 0.5, day_seconds);
+
+            let reservoir = maritime.ocean_reservoir.as_ref().unwrap();
+            air_series.push(air_temp);
+            ocean_series.push(reservoir.get_ocean_temperature(1, 1).unwrap());
+        }
+
+        // Compare annual peaks in the second year, after the spin-up transient
+        let peak_day = |series: &[f32]| {
+            (365..730)
+                .max_by(|&a, &b| series[a].partial_cmp(&series[b]).unwrap())
+                .unwrap()
+        };
+        let lag_days = peak_day(&ocean_series) as i32 - peak_day(&air_series) as i32;
+        assert!(
+            (50..=110).contains(&lag_days),
+            "Ocean temperature should lag air by roughly a season, got {} days",
+            lag_days
+        );
+
+        // Large heat capacity damps the cycle: ocean winters stay warmer than air
+        let ocean_min = ocean_series[365..]
+            .iter()
+            .cloned()
+            .fold(f32::INFINITY, f32::min);
+        let air_min = air_series[365..]
+            .iter()
+            .cloned()
+            .fold(f32::INFINITY, f32::min);
+        assert!(ocean_min > air_min + 3.0);
+    }
 }
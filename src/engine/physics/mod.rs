@@ -7,6 +7,7 @@
 pub mod atmosphere;
 pub mod atmospheric_moisture;
 pub mod atmospheric_pressure_coupling;
+pub mod boundary_forcing;
 pub mod climate;
 pub mod convergence;
 pub mod convergence_detection;
@@ -16,34 +17,50 @@ pub mod ecosystem_feedback;
 pub mod flow_engine;
 pub mod geological_evolution;
 pub mod hydro_biome_coupling;
+pub mod insolation;
 pub mod maritime_climate_coupling;
 pub mod optimized_geological_evolution;
 pub mod orographic_precipitation;
+pub mod precipitation_history;
+pub mod rainfall_schedule;
+pub mod runoff;
+pub mod snow;
 pub mod spatial_partitioning;
 pub mod tectonics;
 pub mod temperature;
 pub mod thermal_circulation;
 pub mod water;
 pub mod wind_erosion_coupling;
+pub mod wind_statistics;
 pub mod worldgen;
 
 // Re-export key terrain generation types
 pub use worldgen::{
-    DiamondSquareConfig, DiamondSquareGenerator, TectonicConfig, TectonicGenerator,
-    TerrainGenerator,
+    ARCHETYPE_OCEAN_DEPTH, Archetype, ArchetypeGenerator, DiamondSquareConfig,
+    DiamondSquareGenerator, TectonicConfig, TectonicGenerator, TerrainGenerator,
 };
 
 // Re-export geological evolution
 pub use geological_evolution::GeologicalEvolutionConfig;
 
 // Re-export unified flow engine
-pub use flow_engine::{FlowAlgorithm, FlowEngine, FlowParameters, VelocityField};
+pub use flow_engine::{FlowAlgorithm, FlowEngine, FlowParameters, FlowSolver, VelocityField};
 
 // Re-export hydrology-biome coupling
-pub use hydro_biome_coupling::{HydrologyAwareBiomeClassifier, WaterAvailability};
+pub use hydro_biome_coupling::{
+    CellWaterAvailability, HydrologyAwareBiomeClassifier, WaterAvailability,
+};
+
+// Re-export solar forcing
+pub use insolation::Insolation;
+
+// Re-export snowpack storage
+pub use snow::{SnowLayer, SnowParameters};
 
 // Re-export maritime-climate coupling
-pub use maritime_climate_coupling::{CoastalThermalEffects, MaritimAwareAtmosphereSystem};
+pub use maritime_climate_coupling::{
+    CoastalThermalEffects, MaritimAwareAtmosphereSystem, OceanHeatParameters, OceanHeatReservoir,
+};
 
 // Re-export atmospheric-pressure coupling
 pub use atmospheric_pressure_coupling::{AtmosphericPressureEffects, PressureAwareWaterFlowSystem};
@@ -56,6 +73,18 @@ pub use orographic_precipitation::{
     OrographicEffects, OrographicParameters, OrographicPrecipitationSystem,
 };
 
+// Re-export precipitation accumulation
+pub use precipitation_history::PrecipitationHistory;
+
+// Re-export nested-domain boundary forcing
+pub use boundary_forcing::{BoundaryForcing, BoundaryFrame, FrameSizeMismatch};
+
+// Re-export scripted rainfall
+pub use rainfall_schedule::{RainfallPhase, RainfallSchedule};
+
+// Re-export wind climatology
+pub use wind_statistics::WindStatistics;
+
 // Re-export thermal-circulation coupling
 pub use thermal_circulation::{
     ThermalCirculationEffects, ThermalCirculationParameters, ThermalCirculationSystem,
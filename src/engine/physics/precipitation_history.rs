@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Per-cell precipitation accumulator converting simulated rainfall into annual totals
+// ABOUTME: Supplies mean annual precipitation (mm/year) to climate-driven biome classification
+
+use crate::engine::core::heightmap::HeightMap;
+use serde::{Deserialize, Serialize};
+
+/// Seconds in a 365-day year
+pub const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 3600.0;
+
+/// Running total of rainfall depth per cell and the simulated time it covers
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PrecipitationHistory {
+    /// Accumulated rainfall depth per cell (m)
+    total_depth: HeightMap,
+    /// Simulated time covered by the accumulation (s)
+    elapsed_seconds: f64,
+}
+
+impl PrecipitationHistory {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            total_depth: HeightMap::new(width, height, 0.0),
+            elapsed_seconds: 0.0,
+        }
+    }
+
+    /// Add the same rainfall depth (m) to every cell
+    pub fn add_uniform(&mut self, depth_m: f32) {
+        for depth in self.total_depth.data_mut() {
+            *depth += depth_m;
+        }
+    }
+
+    /// Add rainfall depth (m) at a single cell
+    pub fn add_at(&mut self, x: usize, y: usize, depth_m: f32) {
+        if x < self.total_depth.width() && y < self.total_depth.height() {
+            let current = self.total_depth.get(x, y);
+            self.total_depth.set(x, y, current + depth_m);
+        }
+    }
+
+    /// Advance the simulated time covered by the record
+    pub fn advance(&mut self, seconds: f64) {
+        self.elapsed_seconds += seconds.max(0.0);
+    }
+
+    /// Simulated time covered so far (s)
+    pub fn elapsed_seconds(&self) -> f64 {
+        self.elapsed_seconds
+    }
+
+    /// Accumulated rainfall depth at a cell (m)
+    pub fn total_depth_m(&self, x: usize, y: usize) -> f32 {
+        if x < self.total_depth.width() && y < self.total_depth.height() {
+            self.total_depth.get(x, y)
+        } else {
+            0.0
+        }
+    }
+
+    /// Mean annual precipitation (mm/year) extrapolated from the record, None before any time passes
+    pub fn annual_precipitation_mm(&self, x: usize, y: usize) -> Option<f32> {
+        if self.elapsed_seconds <= 0.0 {
+            return None;
+        }
+        let years = self.elapsed_seconds / SECONDS_PER_YEAR;
+        Some((self.total_depth_m(x, y) as f64 * 1000.0 / years) as f32)
+    }
+
+    /// Clear the record
+    pub fn reset(&mut self) {
+        for depth in self.total_depth.data_mut() {
+            *depth = 0.0;
+        }
+        self.elapsed_seconds = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn annual_total_extrapolates_from_elapsed_time() {
+        let mut history = PrecipitationHistory::new(4, 3);
+        assert_eq!(history.annual_precipitation_mm(0, 0), None);
+
+        // 0.5 m of rain over half a year is 1000 mm/year
+        history.add_uniform(0.25);
+        history.add_at(2, 1, 0.25);
+        history.advance(SECONDS_PER_YEAR / 4.0);
+        history.add_uniform(0.25);
+        history.advance(SECONDS_PER_YEAR / 4.0);
+
+        let dry = history.annual_precipitation_mm(0, 0).unwrap();
+        let wet = history.annual_precipitation_mm(2, 1).unwrap();
+        assert!((dry - 1000.0).abs() < 1e-2);
+        assert!((wet - 1500.0).abs() < 1e-2);
+
+        history.reset();
+        assert_eq!(history.annual_precipitation_mm(0, 0), None);
+        assert_eq!(history.total_depth_m(2, 1), 0.0);
+    }
+}
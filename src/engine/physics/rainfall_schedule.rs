@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Time-varying rainfall schedules for scripted storms and diurnal precipitation cycles
+// ABOUTME: Maps elapsed simulation time to a multiplier on the water system's effective rainfall rate
+
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+
+/// One phase of a piecewise-constant schedule, active from its start until the next phase begins
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RainfallPhase {
+    /// Simulation time at which the phase begins (hours)
+    pub start_hours: f32,
+    /// Multiplier applied to the effective rainfall rate during the phase
+    pub multiplier: f32,
+}
+
+/// Rainfall multiplier as a function of simulation time
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RainfallSchedule {
+    /// Constant multipliers over consecutive phases (e.g. a design storm followed by dry weather)
+    /// Before the first phase the multiplier is 1.0
+    PiecewiseConstant { phases: Vec<RainfallPhase> },
+
+    /// Daily sinusoidal cycle peaking at peak_hour, clamped at zero
+    Diurnal {
+        mean: f32,
+        amplitude: f32,
+        /// Hour of day (0-24) with the heaviest rainfall
+        peak_hour: f32,
+    },
+}
+
+impl RainfallSchedule {
+    /// Piecewise-constant schedule from (start hour, multiplier) pairs
+    pub fn piecewise(phases: &[(f32, f32)]) -> Self {
+        let mut phases: Vec<RainfallPhase> = phases
+            .iter()
+            .map(|&(start_hours, multiplier)| RainfallPhase {
+                start_hours,
+                multiplier,
+            })
+            .collect();
+        phases.sort_by(|a, b| a.start_hours.total_cmp(&b.start_hours));
+        Self::PiecewiseConstant { phases }
+    }
+
+    /// Rainfall multiplier at the given simulation time (hours), never negative
+    pub fn multiplier_at(&self, hours: f32) -> f32 {
+        match self {
+            Self::PiecewiseConstant { phases } => phases
+                .iter()
+                .take_while(|phase| phase.start_hours <= hours)
+                .last()
+                .map_or(1.0, |phase| phase.multiplier)
+                .max(0.0),
+            Self::Diurnal {
+                mean,
+                amplitude,
+                peak_hour,
+            } => {
+                let phase = 2.0 * PI * (hours - peak_hour) / 24.0;
+                (mean + amplitude * phase.cos()).max(0.0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedules_map_time_to_multiplier() {
+        let storm = RainfallSchedule::piecewise(&[(3.0, 0.0), (0.0, 4.0)]);
+        assert_eq!(storm.multiplier_at(0.0), 4.0);
+        assert_eq!(storm.multiplier_at(2.9), 4.0);
+        assert_eq!(storm.multiplier_at(3.0), 0.0);
+        assert_eq!(storm.multiplier_at(100.0), 0.0);
+
+        let diurnal = RainfallSchedule::Diurnal {
+            mean: 1.0,
+            amplitude: 1.5,
+            peak_hour: 15.0,
+        };
+        assert!((diurnal.multiplier_at(15.0) - 2.5).abs() < 1e-5);
+        assert!((diurnal.multiplier_at(39.0) - 2.5).abs() < 1e-4);
+        assert_eq!(diurnal.multiplier_at(3.0), 0.0);
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Per-cell water balance accumulator - liquid water input against lateral outflow and losses
+// ABOUTME: Reports the runoff coefficient, the fraction of input that flowed off each cell
+
+use crate::engine::core::PhysicsGrid;
+use serde::{Deserialize, Serialize};
+
+/// Accumulated per-cell water input, lateral outflow and vertical losses since the last reset
+///
+/// Input is rain plus snowmelt reaching the surface; lateral outflow is surface water leaving
+/// the cell less water flowing in from neighbors; losses are water soaking into the soil and
+/// ponded water evaporating. All amounts are water depth in heightmap units.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunoffTracker {
+    rainfall: PhysicsGrid<f32>,
+    lateral_outflow: PhysicsGrid<f32>,
+    infiltration: PhysicsGrid<f32>,
+    evaporation: PhysicsGrid<f32>,
+}
+
+impl RunoffTracker {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            rainfall: PhysicsGrid::new(width, height, 0.0),
+            lateral_outflow: PhysicsGrid::new(width, height, 0.0),
+            infiltration: PhysicsGrid::new(width, height, 0.0),
+            evaporation: PhysicsGrid::new(width, height, 0.0),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rainfall.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rainfall.height()
+    }
+
+    fn accumulate(grid: &mut PhysicsGrid<f32>, x: usize, y: usize, amount: f32) {
+        if x < grid.width() && y < grid.height() {
+            *grid.get_mut(x, y) += amount;
+        }
+    }
+
+    /// Add rain or snowmelt reaching the surface at a cell
+    pub fn record_rainfall(&mut self, x: usize, y: usize, amount: f32) {
+        Self::accumulate(&mut self.rainfall, x, y, amount);
+    }
+
+    /// Add surface water leaving a cell for a neighbor or the map edge
+    pub fn record_outflow(&mut self, x: usize, y: usize, amount: f32) {
+        Self::accumulate(&mut self.lateral_outflow, x, y, amount);
+    }
+
+    /// Add surface water arriving at a cell from a neighbor
+    pub fn record_inflow(&mut self, x: usize, y: usize, amount: f32) {
+        Self::accumulate(&mut self.lateral_outflow, x, y, -amount);
+    }
+
+    /// Add ponded water that soaked into the soil at a cell
+    pub fn record_infiltration(&mut self, x: usize, y: usize, amount: f32) {
+        Self::accumulate(&mut self.infiltration, x, y, amount);
+    }
+
+    /// Add ponded water that evaporated at a cell
+    pub fn record_evaporation(&mut self, x: usize, y: usize, amount: f32) {
+        Self::accumulate(&mut self.evaporation, x, y, amount);
+    }
+
+    /// Total input recorded at a cell
+    pub fn rainfall(&self, x: usize, y: usize) -> f32 {
+        *self.rainfall.get(x, y)
+    }
+
+    /// Net lateral outflow recorded at a cell; negative where more flowed in than out
+    pub fn lateral_outflow(&self, x: usize, y: usize) -> f32 {
+        *self.lateral_outflow.get(x, y)
+    }
+
+    /// Total infiltration recorded at a cell
+    pub fn infiltration(&self, x: usize, y: usize) -> f32 {
+        *self.infiltration.get(x, y)
+    }
+
+    /// Total evaporation recorded at a cell
+    pub fn evaporation(&self, x: usize, y: usize) -> f32 {
+        *self.evaporation.get(x, y)
+    }
+
+    /// Fraction of a cell's input that left it as net lateral outflow (0-1)
+    ///
+    /// Water that ponds, soaks in or evaporates in place is not runoff. Cells gaining
+    /// more run-on than they shed clamp to 0; cells with no input report 0.
+    pub fn runoff_coefficient(&self, x: usize, y: usize) -> f32 {
+        let rainfall = self.rainfall(x, y);
+        if rainfall <= 0.0 {
+            return 0.0;
+        }
+        (self.lateral_outflow(x, y) / rainfall).clamp(0.0, 1.0)
+    }
+
+    /// Runoff coefficient of every cell
+    pub fn runoff_coefficient_field(&self) -> PhysicsGrid<f32> {
+        let mut field = PhysicsGrid::new(self.width(), self.height(), 0.0);
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                field.set(x, y, self.runoff_coefficient(x, y));
+            }
+        }
+        field
+    }
+
+    /// Start a new accumulation window
+    pub fn reset(&mut self) {
+        self.rainfall.fill(0.0);
+        self.lateral_outflow.fill(0.0);
+        self.infiltration.fill(0.0);
+        self.evaporation.fill(0.0);
+    }
+}
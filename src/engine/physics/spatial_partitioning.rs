@@ -4,6 +4,7 @@
 // ABOUTME: Spatial partitioning system for selective cell updates in geological simulation
 // ABOUTME: Implements multi-tier update regions for water/evaporation coupling and convergence tracking
 
+use super::super::core::math::Vec2;
 use super::super::core::optimized_heightmap::FlatHeightmap;
 use super::super::core::scale::WorldScale;
 use super::{FlowEngine, FlowParameters}; // Use the re-export from mod.rs
@@ -248,6 +249,7 @@ impl OptimizedWaterFlowSystem {
             concentration_factor: 5000.0, // From legacy flow_rate conversion
             cfl_safety: params.cfl_safety_factor,
             dt: 1.0 / params.max_expected_velocity_ms, // Derived from CFL condition
+            adaptive_dt: false,
         };
 
         let mut update_tracker = SpatialUpdateTracker::new(width, height);
@@ -482,10 +484,185 @@ impl PerformanceStats {
     }
 }
 
+/// Uniform bucket grid over a rectangular domain for radius and nearest-neighbor queries
+///
+/// Positions are in meters from the top-left corner. Points outside the domain are kept in
+/// the nearest edge bucket, so queries stay correct but slow down if many points lie outside.
+#[derive(Debug, Clone)]
+pub struct SpatialIndex<T> {
+    bucket_size: f32,
+    columns: usize,
+    rows: usize,
+    buckets: Vec<Vec<(T, Vec2)>>,
+    len: usize,
+}
+
+impl<T: Copy + PartialEq> SpatialIndex<T> {
+    /// Index covering `width` x `height` meters with square buckets of `bucket_size` meters
+    pub fn new(width: f32, height: f32, bucket_size: f32) -> Self {
+        let bucket_size = bucket_size.max(f32::EPSILON);
+        let columns = ((width / bucket_size).ceil() as usize).max(1);
+        let rows = ((height / bucket_size).ceil() as usize).max(1);
+        Self {
+            bucket_size,
+            columns,
+            rows,
+            buckets: vec![Vec::new(); columns * rows],
+            len: 0,
+        }
+    }
+
+    /// Index covering the physical extent of a world scale
+    pub fn for_world(scale: &WorldScale, bucket_size_m: f32) -> Self {
+        let width = scale.resolution.0 as f64 * scale.meters_per_pixel_x();
+        let height = scale.resolution.1 as f64 * scale.meters_per_pixel_y();
+        Self::new(width as f32, height as f32, bucket_size_m)
+    }
+
+    /// Bucket column and row containing a position, clamped to the grid
+    fn bucket_of(&self, position: Vec2) -> (usize, usize) {
+        let column = (position.x / self.bucket_size).floor().max(0.0) as usize;
+        let row = (position.y / self.bucket_size).floor().max(0.0) as usize;
+        (column.min(self.columns - 1), row.min(self.rows - 1))
+    }
+
+    pub fn insert(&mut self, id: T, position: Vec2) {
+        let (column, row) = self.bucket_of(position);
+        self.buckets[row * self.columns + column].push((id, position));
+        self.len += 1;
+    }
+
+    /// Remove every entry with this id, returning whether any was found
+    pub fn remove(&mut self, id: T) -> bool {
+        let before = self.len;
+        for bucket in &mut self.buckets {
+            bucket.retain(|(entry, _)| *entry != id);
+        }
+        self.len = self.buckets.iter().map(Vec::len).sum();
+        self.len < before
+    }
+
+    pub fn clear(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+        self.len = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Ids of all entries within `radius` meters of `position` (inclusive)
+    pub fn query_radius(&self, position: Vec2, radius: f32) -> Vec<T> {
+        let radius = radius.max(0.0);
+        let (min_column, min_row) =
+            self.bucket_of(Vec2::new(position.x - radius, position.y - radius));
+        let (max_column, max_row) =
+            self.bucket_of(Vec2::new(position.x + radius, position.y + radius));
+
+        let mut found = Vec::new();
+        for row in min_row..=max_row {
+            for column in min_column..=max_column {
+                for &(id, point) in &self.buckets[row * self.columns + column] {
+                    if (point - position).magnitude_squared() <= radius * radius {
+                        found.push(id);
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Id of the entry closest to `position`, searching outward ring by ring
+    pub fn nearest(&self, position: Vec2) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let (center_column, center_row) = self.bucket_of(position);
+        let mut best: Option<(T, f32)> = None;
+
+        for ring in 0..self.columns.max(self.rows) {
+            let min_column = center_column.saturating_sub(ring);
+            let max_column = (center_column + ring).min(self.columns - 1);
+            let min_row = center_row.saturating_sub(ring);
+            let max_row = (center_row + ring).min(self.rows - 1);
+            for row in min_row..=max_row {
+                for column in min_column..=max_column {
+                    let on_ring =
+                        column.abs_diff(center_column) == ring || row.abs_diff(center_row) == ring;
+                    if !on_ring {
+                        continue;
+                    }
+                    for &(id, point) in &self.buckets[row * self.columns + column] {
+                        let distance = (point - position).magnitude_squared();
+                        if best.is_none_or(|(_, best_distance)| distance < best_distance) {
+                            best = Some((id, distance));
+                        }
+                    }
+                }
+            }
+
+            // Anything beyond this ring is at least `ring` whole buckets away
+            let reach = ring as f32 * self.bucket_size;
+            if let Some((_, best_distance)) = best
+                && best_distance <= reach * reach
+            {
+                break;
+            }
+        }
+        best.map(|(id, _)| id)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn spatial_index_radius_and_nearest_queries() {
+        let scale = WorldScale::new(
+            1.0,
+            (100, 100),
+            crate::engine::core::scale::DetailLevel::Standard,
+        );
+        let mut index = SpatialIndex::for_world(&scale, 50.0);
+
+        // Tight cluster around (200, 200) m and a few distant points
+        let cluster = [(1, 200.0, 200.0), (2, 210.0, 195.0), (3, 190.0, 215.0)];
+        for &(id, x, y) in &cluster {
+            index.insert(id, Vec2::new(x, y));
+        }
+        index.insert(10, Vec2::new(900.0, 900.0));
+        index.insert(11, Vec2::new(260.0, 200.0));
+        index.insert(12, Vec2::new(5.0, 990.0));
+        assert_eq!(index.len(), 6);
+
+        let mut in_range = index.query_radius(Vec2::new(200.0, 200.0), 25.0);
+        in_range.sort();
+        assert_eq!(in_range, vec![1, 2, 3]);
+
+        let mut wider = index.query_radius(Vec2::new(200.0, 200.0), 60.0);
+        wider.sort();
+        assert_eq!(wider, vec![1, 2, 3, 11]);
+
+        assert_eq!(index.nearest(Vec2::new(880.0, 870.0)), Some(10));
+        assert_eq!(index.nearest(Vec2::new(0.0, 1000.0)), Some(12));
+        assert_eq!(index.nearest(Vec2::new(205.0, 197.0)), Some(2));
+
+        assert!(index.remove(10));
+        assert_eq!(index.nearest(Vec2::new(700.0, 300.0)), Some(11));
+        assert!(
+            index
+                .query_radius(Vec2::new(900.0, 900.0), 100.0)
+                .is_empty()
+        );
+    }
+
     #[test]
     fn spatial_tracker_basic_operations() {
         let mut tracker = SpatialUpdateTracker::new(10, 10);
@@ -4,7 +4,11 @@
 // ABOUTME: Thermal circulation coupling - temperature-driven atmospheric flow patterns
 // ABOUTME: Creates buoyancy effects and pressure gradients from temperature differences
 
-use super::super::core::{math::Vec2 as MathVec2, scale::WorldScale};
+use super::super::core::{
+    math::Vec2 as MathVec2,
+    physics_grid::{Float, StateFloat},
+    scale::WorldScale,
+};
 use super::{
     climate::{AtmosphericPressureLayer, ClimateSystem, TemperatureLayer},
     flow_engine::FlowEngine,
@@ -206,10 +210,12 @@ impl ThermalCirculationSystem {
                 effects.thermal_pressure[x][y] = pressure_adjustment;
 
                 // Apply pressure adjustment to atmospheric pressure layer
-                let current_pressure = atmospheric_pressure.pressure.get(x, y);
-                atmospheric_pressure
-                    .pressure
-                    .set(x, y, current_pressure + pressure_adjustment);
+                let current_pressure = atmospheric_pressure.pressure.get(x, y).to_f32();
+                atmospheric_pressure.pressure.set(
+                    x,
+                    y,
+                    StateFloat::from_f32(current_pressure + pressure_adjustment),
+                );
 
                 // Calculate thermal circulation velocity
                 if gradient_magnitude > 0.001 {
@@ -281,10 +287,10 @@ impl ThermalCirculationSystem {
         // Calculate pressure gradients after thermal adjustments
         for x in 1..width - 1 {
             for y in 1..height - 1 {
-                let pressure_east = atmospheric_pressure.pressure.get(x + 1, y);
-                let pressure_west = atmospheric_pressure.pressure.get(x - 1, y);
-                let pressure_north = atmospheric_pressure.pressure.get(x, y - 1);
-                let pressure_south = atmospheric_pressure.pressure.get(x, y + 1);
+                let pressure_east = atmospheric_pressure.pressure.get(x + 1, y).to_f32();
+                let pressure_west = atmospheric_pressure.pressure.get(x - 1, y).to_f32();
+                let pressure_north = atmospheric_pressure.pressure.get(x, y - 1).to_f32();
+                let pressure_south = atmospheric_pressure.pressure.get(x, y + 1).to_f32();
 
                 let dp_dx = (pressure_east - pressure_west) / (2.0 * cell_size_m);
                 let dp_dy = (pressure_south - pressure_north) / (2.0 * cell_size_m);
@@ -5,8 +5,14 @@
 // ABOUTME: Provides high-performance storage for water depth, velocity, and sediment data
 
 use super::super::core::heightmap::{HeightMap, Vec2Map};
+use super::super::core::physics_grid::{Float, StateFloat};
+use super::snow::SnowLayer;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq)]
+/// Fresh water density (kg/m³)
+const WATER_DENSITY: f32 = 1000.0;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -26,12 +32,15 @@ impl Vec2 {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WaterLayer {
-    pub depth: HeightMap,    // Water depth at each cell (primary buffer)
-    depth_buffer: HeightMap, // Secondary buffer for double-buffering optimization
-    pub velocity: Vec2Map,   // Flow direction and speed
-    pub sediment: HeightMap, // Carried sediment for erosion
+    pub depth: HeightMap<StateFloat>, // Water depth at each cell (primary buffer)
+    depth_buffer: HeightMap<StateFloat>, // Secondary buffer for double-buffering optimization
+    pub velocity: Vec2Map,            // Flow direction and speed
+    pub sediment: HeightMap,          // Carried sediment for erosion
+    pub soil_moisture: HeightMap,     // Infiltrated water held in the soil (m depth)
+    pub snow: SnowLayer,              // Snowpack water equivalent awaiting melt
+    pub ice_fraction: HeightMap,      // Frozen fraction of surface water (0 = open, 1 = frozen)
     width: usize,
     height: usize,
 }
@@ -39,35 +48,88 @@ pub struct WaterLayer {
 impl WaterLayer {
     pub fn new(width: usize, height: usize) -> Self {
         Self {
-            depth: HeightMap::new(width, height, 0.0),
-            depth_buffer: HeightMap::new(width, height, 0.0),
+            depth: HeightMap::filled(width, height, 0.0),
+            depth_buffer: HeightMap::filled(width, height, 0.0),
             velocity: Vec2Map::new(width, height),
             sediment: HeightMap::new(width, height, 0.0),
+            soil_moisture: HeightMap::new(width, height, 0.0),
+            snow: SnowLayer::new(width, height),
+            ice_fraction: HeightMap::new(width, height, 0.0),
             width,
             height,
         }
     }
 
     pub fn get_total_water(&self) -> f32 {
-        self.depth.iter().sum()
+        self.depth.iter().sum::<StateFloat>().to_f32()
+    }
+
+    /// Depth-weighted momentum summed over all cells, Σ depth·v (m²/s per unit area)
+    pub fn total_momentum(&self) -> Vec2 {
+        let mut total = Vec2::zero();
+        for (x, y, depth) in self.depth.iter_coords() {
+            let depth = depth.to_f32();
+            let (vx, vy) = self.velocity.get(x, y);
+            total.x += depth * vx;
+            total.y += depth * vy;
+        }
+        total
+    }
+
+    /// Kinetic energy of surface water, Σ ½ρ·depth·|v|²·area (J)
+    pub fn total_kinetic_energy(&self, cell_area_m2: f32) -> f32 {
+        let mut energy = 0.0;
+        for (x, y, depth) in self.depth.iter_coords() {
+            let depth = depth.to_f32();
+            let (vx, vy) = self.velocity.get(x, y);
+            energy += 0.5 * WATER_DENSITY * depth * (vx * vx + vy * vy) * cell_area_m2;
+        }
+        energy
     }
 
     pub fn add_water(&mut self, x: usize, y: usize, amount: f32) {
         if x < self.width && y < self.height {
-            let current = self.depth.get(x, y);
-            self.depth.set(x, y, current + amount);
+            *self.depth.get_mut(x, y) += StateFloat::from_f32(amount);
         }
     }
 
     /// Get water depth at specific coordinates
     pub fn get_water_depth(&self, x: usize, y: usize) -> f32 {
         if x < self.width && y < self.height {
-            self.depth.get(x, y)
+            self.depth.get(x, y).to_f32()
         } else {
             0.0
         }
     }
 
+    /// Get soil moisture (m depth) at specific coordinates
+    pub fn get_soil_moisture(&self, x: usize, y: usize) -> f32 {
+        if x < self.width && y < self.height {
+            self.soil_moisture.get(x, y)
+        } else {
+            0.0
+        }
+    }
+
+    /// Total water held in the soil across all cells (m depth summed)
+    pub fn get_total_soil_moisture(&self) -> f32 {
+        self.soil_moisture.iter().sum()
+    }
+
+    /// Liquid (unfrozen) fraction of surface water at specific coordinates
+    pub fn liquid_fraction(&self, x: usize, y: usize) -> f32 {
+        if x < self.width && y < self.height {
+            1.0 - self.ice_fraction.get(x, y)
+        } else {
+            1.0
+        }
+    }
+
+    /// Whether surface water at specific coordinates is fully frozen
+    pub fn is_frozen(&self, x: usize, y: usize) -> bool {
+        self.liquid_fraction(x, y) <= 0.0
+    }
+
     /// Get width of water layer
     pub fn width(&self) -> usize {
         self.width
@@ -79,7 +141,7 @@ impl WaterLayer {
     }
 
     /// Get mutable reference to the depth buffer for double-buffering optimization
-    pub fn get_depth_buffer_mut(&mut self) -> &mut HeightMap {
+    pub fn get_depth_buffer_mut(&mut self) -> &mut HeightMap<StateFloat> {
         &mut self.depth_buffer
     }
 
@@ -98,3 +160,24 @@ impl WaterLayer {
         self.depth_buffer.copy_from(&self.depth);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_moving_cell_momentum_and_energy() {
+        let mut water = WaterLayer::new(4, 4);
+        water.depth.set(2, 1, 0.5);
+        water.velocity.set(2, 1, (3.0, -4.0));
+        // Velocity without water carries no momentum
+        water.velocity.set(0, 0, (10.0, 10.0));
+
+        let momentum = water.total_momentum();
+        assert_eq!(momentum, Vec2::new(1.5, -2.0));
+
+        // ½ · 1000 kg/m³ · 0.5 m · 25 m²/s² · 100 m² = 625 kJ
+        let energy = water.total_kinetic_energy(100.0);
+        assert!((energy - 625_000.0).abs() < 1e-2);
+    }
+}
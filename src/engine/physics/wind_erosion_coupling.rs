@@ -5,9 +5,18 @@
 // ABOUTME: Models wind velocity effects on sediment transport and terrain modification through cross-system physics
 
 use super::flow_engine::FlowEngine;
-use crate::engine::core::{heightmap::HeightMap, math::Vec2, scale::WorldScale};
+use crate::engine::core::heightmap::{HeightMap, METERS_PER_ELEVATION_UNIT};
+use crate::engine::core::{math::Vec2, scale::WorldScale};
 use crate::engine::physics::atmosphere::AtmosphericSystem;
 use crate::engine::physics::climate::TemperatureLayer;
+use crate::engine::physics::wind_statistics::WindStatistics;
+use serde::{Deserialize, Serialize};
+
+/// Wind speed below which saltation stops and carried sand settles out (m/s)
+pub const SALTATION_THRESHOLD_SPEED: f32 = 3.0;
+
+/// Bagnold-style flux coefficient: q = C × u² × (u - u_t) in m²/s per unit width
+pub const SALTATION_FLUX_COEFFICIENT: f32 = 1.0e-5;
 
 /// Wind erosion effects on geological processes
 ///
@@ -285,6 +294,110 @@ impl WindErosionEffects {
         speed_deposition + shelter_deposition
     }
 
+    /// Saltation transport capacity per unit width (m²/s) at a wind speed
+    pub fn saltation_capacity(wind_speed: f32) -> f32 {
+        if wind_speed > SALTATION_THRESHOLD_SPEED {
+            SALTATION_FLUX_COEFFICIENT
+                * wind_speed
+                * wind_speed
+                * (wind_speed - SALTATION_THRESHOLD_SPEED)
+        } else {
+            0.0
+        }
+    }
+
+    /// Move loose sand downwind along the prevailing wind for `dt` seconds
+    ///
+    /// **Aeolian transport**: Sand is carried cell to cell along the prevailing direction.
+    /// Where the local wind (mean speed scaled by the terrain wind factor) can carry more than
+    /// arrives, loose sand is entrained up to what the cell holds; where the wind slows, the
+    /// excess settles out. Stoss slopes therefore deflate and lee slopes build up, so sand
+    /// patches migrate downwind as dunes and fines accumulate as loess in sheltered ground.
+    ///
+    /// `loose_sediment` holds the erodible layer depth (m); bare cells cannot be entrained.
+    /// Both it and the heightmap are updated. Returns the sand volume carried off the map (m³).
+    pub fn transport_sediment(
+        heightmap: &mut HeightMap,
+        loose_sediment: &mut HeightMap,
+        wind_statistics: &WindStatistics,
+        scale: &WorldScale,
+        dt: f32,
+    ) -> f32 {
+        let Some(direction) = wind_statistics.prevailing_direction() else {
+            return 0.0;
+        };
+        let mean_speed = wind_statistics.mean_speed();
+        let width = heightmap.width();
+        let height = heightmap.height();
+        if mean_speed <= 0.0 || dt <= 0.0 || width == 0 || height == 0 {
+            return 0.0;
+        }
+
+        // Route along the nearest of the eight neighbor directions
+        let step_x = direction.cos().round() as i32;
+        let step_y = direction.sin().round() as i32;
+        let cell_x = scale.meters_per_pixel_x() as f32;
+        let cell_y = scale.meters_per_pixel_y() as f32;
+        let path_length =
+            ((step_x as f32 * cell_x).powi(2) + (step_y as f32 * cell_y).powi(2)).sqrt();
+        let cross_width = cell_x * cell_y / path_length;
+
+        // Carrying capacity from the terrain before any of it changes this step (m² per unit width)
+        let mut capacity = vec![0.0; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let terrain_factor = Self::calculate_terrain_wind_factor(heightmap, x, y, scale);
+                capacity[y * width + x] =
+                    Self::saltation_capacity(mean_speed * terrain_factor) * dt;
+            }
+        }
+
+        // Visit cells upwind first so each receives its full incoming load
+        let xs: Vec<usize> = if step_x < 0 {
+            (0..width).rev().collect()
+        } else {
+            (0..width).collect()
+        };
+        let ys: Vec<usize> = if step_y < 0 {
+            (0..height).rev().collect()
+        } else {
+            (0..height).collect()
+        };
+
+        let mut load = vec![0.0; width * height];
+        let mut exported = 0.0;
+        for &y in &ys {
+            for &x in &xs {
+                let idx = y * width + x;
+                let incoming = load[idx];
+                let sand = loose_sediment.get(x, y);
+                let depth_change = if incoming < capacity[idx] {
+                    -((capacity[idx] - incoming) / path_length).min(sand.max(0.0))
+                } else {
+                    (incoming - capacity[idx]) / path_length
+                };
+                if depth_change != 0.0 {
+                    loose_sediment.set(x, y, sand + depth_change);
+                    heightmap.set(
+                        x,
+                        y,
+                        heightmap.get(x, y) + depth_change / METERS_PER_ELEVATION_UNIT,
+                    );
+                }
+                let outgoing = incoming - depth_change * path_length;
+
+                let nx = x as i32 + step_x;
+                let ny = y as i32 + step_y;
+                if nx >= 0 && ny >= 0 && (nx as usize) < width && (ny as usize) < height {
+                    load[ny as usize * width + nx as usize] += outgoing;
+                } else {
+                    exported += outgoing * cross_width;
+                }
+            }
+        }
+        exported
+    }
+
     /// Get wind shear stress at specified coordinates
     pub fn get_shear_stress(&self, x: usize, y: usize) -> f32 {
         if x < self.width && y < self.height {
@@ -332,11 +445,13 @@ impl WindErosionEffects {
 }
 
 /// Extended geological evolution system that incorporates wind erosion effects
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WindAwareGeologicalSystem {
     /// Wind erosion influence strength (0.0-1.0)
     /// 0.0 = ignore wind effects, 1.0 = fully influenced by atmospheric flow
     pub wind_influence: f32,
+    /// Loose sand depth per cell (m) available to prevailing winds; None = bare rock only
+    pub loose_sediment: Option<HeightMap>,
 }
 
 impl WindAwareGeologicalSystem {
@@ -344,6 +459,35 @@ impl WindAwareGeologicalSystem {
     pub fn new_for_scale(_scale: &WorldScale, wind_influence: f32) -> Self {
         Self {
             wind_influence: wind_influence.clamp(0.0, 1.0),
+            loose_sediment: None,
+        }
+    }
+
+    /// Give the prevailing wind a loose sand layer (depth in m per cell) to carry
+    pub fn with_loose_sediment(mut self, loose_sediment: HeightMap) -> Self {
+        self.loose_sediment = Some(loose_sediment);
+        self
+    }
+
+    /// Carry loose sand along the prevailing wind for `dt` seconds, scaled by wind influence
+    ///
+    /// See `WindErosionEffects::transport_sediment`. Returns the sand volume blown off the map (m³).
+    pub fn transport_loose_sediment(
+        &mut self,
+        heightmap: &mut HeightMap,
+        wind_statistics: &WindStatistics,
+        scale: &WorldScale,
+        dt: f32,
+    ) -> f32 {
+        match &mut self.loose_sediment {
+            Some(sand) if self.wind_influence > 0.0 => WindErosionEffects::transport_sediment(
+                heightmap,
+                sand,
+                wind_statistics,
+                scale,
+                dt * self.wind_influence,
+            ),
+            _ => 0.0,
         }
     }
 
@@ -359,11 +503,14 @@ impl WindAwareGeologicalSystem {
     /// 3. Transport sediment according to wind capacity
     /// 4. Deposit sediment where transport capacity is reduced
     /// 5. Modify terrain heightmap based on net erosion/deposition
+    /// 6. Carry loose sand along the prevailing wind in `wind_statistics`
+    #[allow(clippy::too_many_arguments)]
     pub fn evolve_terrain_with_wind_erosion(
-        &self,
+        &mut self,
         heightmap: &mut HeightMap,
         atmospheric_system: &AtmosphericSystem,
         temperature_layer: &TemperatureLayer,
+        wind_statistics: &WindStatistics,
         flow_engine: &mut FlowEngine, // Modified to include wind effects
         scale: &WorldScale,
         time_of_day: f32,
@@ -383,6 +530,9 @@ impl WindAwareGeologicalSystem {
             self.apply_wind_erosion_coupling(&wind_effects, heightmap, dt);
         }
 
+        // 3. Move loose sand downwind into dunes and loess
+        self.transport_loose_sediment(heightmap, wind_statistics, scale, dt);
+
         wind_effects
     }
 
@@ -515,6 +665,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn steady_wind_moves_sand_patch_downwind() {
+        use crate::engine::physics::{atmosphere::WindLayer, water};
+
+        // 1 km cells; a 20 m sand mound on flat ground under a steady 10 m/s east wind
+        let (width, height) = (40, 5);
+        let scale = WorldScale::new(40.0, (width as u32, height as u32), DetailLevel::Standard);
+        let mut heightmap = HeightMap::new(width, height, 0.3);
+        let mut sand = HeightMap::new(width, height, 0.0);
+        for y in 0..height {
+            for x in 0..width {
+                let offset = (x as f32 - 15.0) / 3.0;
+                let depth = 20.0 * (-offset * offset).exp();
+                sand.set(x, y, depth);
+                heightmap.set(x, y, 0.3 + depth / METERS_PER_ELEVATION_UNIT);
+            }
+        }
+        let initial = heightmap.clone();
+        let initial_sand: f32 = sand.iter().sum();
+
+        let mut wind = WindLayer::new(width, height);
+        for velocity in wind.velocity.iter_mut() {
+            *velocity = water::Vec2::new(10.0, 0.0);
+        }
+        let mut statistics = WindStatistics::new(16, 10);
+        for _ in 0..10 {
+            statistics.record(&wind);
+        }
+
+        let mut exported = 0.0;
+        for _ in 0..10 {
+            exported += WindErosionEffects::transport_sediment(
+                &mut heightmap,
+                &mut sand,
+                &statistics,
+                &scale,
+                3600.0,
+            );
+        }
+
+        let change = |x: usize| heightmap.get(x, 2) - initial.get(x, 2);
+        // Windward flank deflates while the lee builds a ridge
+        assert!(change(12) < 0.0, "windward change {}", change(12));
+        let ridge = (16..24)
+            .max_by(|&a, &b| change(a).total_cmp(&change(b)))
+            .unwrap();
+        assert!(change(ridge) > 0.0, "no downwind deposition");
+        assert!(sand.iter().all(|depth| depth >= 0.0));
+
+        // Sand is conserved: what left the map plus what remains equals the original patch
+        let remaining: f32 = sand.iter().sum();
+        let cell_area = 1000.0 * 1000.0;
+        assert!(
+            ((initial_sand - remaining) * cell_area - exported).abs()
+                < 1e-3 * initial_sand * cell_area,
+            "sand volume not conserved"
+        );
+    }
+
     #[test]
     fn test_wind_aware_geological_coupling() {
         use crate::engine::physics::flow_engine::{FlowAlgorithm, FlowEngine};
@@ -536,8 +745,9 @@ mod tests {
         let mut flow_engine = FlowEngine::new(FlowAlgorithm::Conservation, 5, 3, &scale);
 
         // Test different wind influence levels
-        let no_wind = WindAwareGeologicalSystem::new_for_scale(&scale, 0.0);
-        let full_wind = WindAwareGeologicalSystem::new_for_scale(&scale, 1.0);
+        let mut no_wind = WindAwareGeologicalSystem::new_for_scale(&scale, 0.0);
+        let mut full_wind = WindAwareGeologicalSystem::new_for_scale(&scale, 1.0);
+        let wind_statistics = WindStatistics::default();
 
         // Store initial terrain state for comparison
         let initial_heightmap = heightmap.clone();
@@ -551,6 +761,7 @@ mod tests {
             &mut heightmap_no_wind,
             &atmospheric_system,
             &temperature_layer,
+            &wind_statistics,
             &mut flow_engine,
             &scale,
             0.5,    // Noon
@@ -563,6 +774,7 @@ mod tests {
             &mut heightmap_full_wind,
             &atmospheric_system,
             &temperature_layer,
+            &wind_statistics,
             &mut flow_engine,
             &scale,
             0.5,    // Noon
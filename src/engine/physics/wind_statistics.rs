@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Sliding-window wind climatology - speed-weighted direction sectors and mean wind speed
+// ABOUTME: Reports the prevailing wind direction for siting, erosion and dune analysis
+
+use super::atmosphere::WindLayer;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::f32::consts::TAU;
+
+/// Default number of direction sectors (22.5° each)
+pub const DEFAULT_WIND_SECTORS: usize = 16;
+
+/// Default number of ticks kept in the sliding window
+pub const DEFAULT_WIND_WINDOW_TICKS: usize = 240;
+
+/// One tick's wind summary over the whole map
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct WindSample {
+    /// Summed wind speed per direction sector (m/s)
+    sector_speed: Vec<f32>,
+    /// Mean wind speed over all cells (m/s)
+    mean_speed: f32,
+}
+
+/// Speed-weighted wind direction histogram over the most recent ticks
+///
+/// Directions follow the wind layer convention: radians counter-clockwise from east,
+/// giving the direction the wind blows toward.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WindStatistics {
+    sectors: usize,
+    window: usize,
+    samples: VecDeque<WindSample>,
+}
+
+impl Default for WindStatistics {
+    fn default() -> Self {
+        Self::new(DEFAULT_WIND_SECTORS, DEFAULT_WIND_WINDOW_TICKS)
+    }
+}
+
+impl WindStatistics {
+    pub fn new(sectors: usize, window: usize) -> Self {
+        Self {
+            sectors: sectors.max(1),
+            window: window.max(1),
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Angular width of one sector (radians)
+    pub fn sector_width(&self) -> f32 {
+        TAU / self.sectors as f32
+    }
+
+    /// Sector containing a direction; sectors are centered on multiples of the sector width
+    fn sector_of(&self, direction: f32) -> usize {
+        let width = self.sector_width();
+        let shifted = (direction + 0.5 * width).rem_euclid(TAU);
+        ((shifted / width) as usize).min(self.sectors - 1)
+    }
+
+    /// Add the current wind field, dropping the oldest tick once the window is full
+    pub fn record(&mut self, wind: &WindLayer) {
+        let mut sector_speed = vec![0.0; self.sectors];
+        let mut total_speed = 0.0;
+        let mut cells = 0usize;
+        for velocity in wind.velocity.iter() {
+            let speed = velocity.magnitude();
+            if speed.is_finite() {
+                if speed > 0.0 {
+                    sector_speed[self.sector_of(velocity.y.atan2(velocity.x))] += speed;
+                }
+                total_speed += speed;
+                cells += 1;
+            }
+        }
+
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(WindSample {
+            sector_speed,
+            mean_speed: if cells > 0 {
+                total_speed / cells as f32
+            } else {
+                0.0
+            },
+        });
+    }
+
+    /// Ticks currently held in the window
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Summed wind speed per sector over the window, sector 0 centered on east
+    pub fn sector_weights(&self) -> Vec<f32> {
+        let mut weights = vec![0.0; self.sectors];
+        for sample in &self.samples {
+            for (weight, speed) in weights.iter_mut().zip(&sample.sector_speed) {
+                *weight += speed;
+            }
+        }
+        weights
+    }
+
+    /// Center of the most heavily weighted sector (radians from east), None while calm or empty
+    pub fn prevailing_direction(&self) -> Option<f32> {
+        let (sector, weight) = self
+            .sector_weights()
+            .into_iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+        (weight > 0.0).then(|| sector as f32 * self.sector_width())
+    }
+
+    /// Mean wind speed over the window (m/s)
+    pub fn mean_speed(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().map(|s| s.mean_speed).sum::<f32>() / self.samples.len() as f32
+    }
+
+    /// Forget all recorded ticks
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+}
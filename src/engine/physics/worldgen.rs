@@ -3,7 +3,7 @@
 
 // kosmarium/src/engine/physics/worldgen.rs
 
-use super::super::core::heightmap::HeightMap;
+use super::super::core::heightmap::{HeightMap, SEA_LEVEL_ELEVATION};
 use super::super::core::scale::{ScaleAware, WorldScale};
 use super::geological_evolution::{GeologicalEvolution, GeologicalEvolutionConfig};
 use super::tectonics::TectonicSystem;
@@ -708,9 +708,192 @@ impl TectonicGenerator {
     }
 }
 
+/// Depth of the deepest archetype sea floor below `SEA_LEVEL_ELEVATION`
+pub const ARCHETYPE_OCEAN_DEPTH: f32 = 0.3;
+
+/// Recognizable terrain morphologies with tuned generator settings
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Archetype {
+    /// Scattered rugged islands in open ocean
+    Archipelago,
+    /// Plate-driven continents with mountain belts
+    #[default]
+    ContinentalMountains,
+    /// Parallel north-south ranges separating dry interior basins
+    BasinAndRange,
+    /// Low-relief land rising gently inland from a western coast
+    CoastalPlain,
+}
+
+impl Archetype {
+    pub const ALL: [Archetype; 4] = [
+        Archetype::Archipelago,
+        Archetype::ContinentalMountains,
+        Archetype::BasinAndRange,
+        Archetype::CoastalPlain,
+    ];
+
+    /// Fraction of cells at or above `SEA_LEVEL_ELEVATION`
+    pub fn land_fraction(self) -> f32 {
+        match self {
+            Archetype::Archipelago => 0.2,
+            Archetype::ContinentalMountains => 0.6,
+            Archetype::BasinAndRange => 0.9,
+            Archetype::CoastalPlain => 0.55,
+        }
+    }
+
+    /// Exponent applied to land heights; above 1 flattens lowlands, below 1 raises uplands
+    fn relief_exponent(self) -> f32 {
+        match self {
+            Archetype::Archipelago => 1.0,
+            Archetype::ContinentalMountains => 1.5,
+            Archetype::BasinAndRange => 0.8,
+            Archetype::CoastalPlain => 2.5,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Archetype::Archipelago => "Archipelago",
+            Archetype::ContinentalMountains => "Continental Mountains",
+            Archetype::BasinAndRange => "Basin and Range",
+            Archetype::CoastalPlain => "Coastal Plain",
+        }
+    }
+}
+
+/// Terrain generator that picks Diamond-Square or tectonic settings for an archetype
+/// Sea floor spans `-ARCHETYPE_OCEAN_DEPTH` up to `SEA_LEVEL_ELEVATION` and land rises to 1.0,
+/// so the simulation's ocean mask sees the archetype's sea.
+pub struct ArchetypeGenerator {
+    seed: u64,
+    scale: Option<WorldScale>,
+}
+
+impl ArchetypeGenerator {
+    pub fn new(seed: u64) -> Self {
+        Self { seed, scale: None }
+    }
+
+    /// Derive tectonic settings such as plate count from the world scale
+    pub fn with_scale(mut self, scale: &WorldScale) -> Self {
+        self.scale = Some(scale.clone());
+        self
+    }
+
+    /// Generate an archetype at the scale's resolution
+    /// Lives here rather than on `TerrainGenerator`, whose `Config` is specific to each generator.
+    pub fn from_archetype(archetype: Archetype, seed: u64, scale: &WorldScale) -> HeightMap {
+        Self::new(seed).with_scale(scale).generate(
+            scale.resolution.0 as usize,
+            scale.resolution.1 as usize,
+            &archetype,
+        )
+    }
+
+    fn base_terrain(&self, archetype: Archetype, width: usize, height: usize) -> HeightMap {
+        let fractal = |roughness: f32, persistence: f32| {
+            DiamondSquareGenerator::new(self.seed).generate(
+                width,
+                height,
+                &DiamondSquareConfig {
+                    roughness,
+                    persistence,
+                    ..DiamondSquareConfig::default()
+                },
+            )
+        };
+
+        match archetype {
+            Archetype::Archipelago => fractal(0.8, 0.6),
+            Archetype::ContinentalMountains => {
+                let defaults = TectonicConfig {
+                    mountain_scale: 1.5,
+                    // Evolution reshapes the whole map; presets keep the tectonic relief crisp
+                    enable_geological_evolution: false,
+                    geological_evolution_config: None,
+                    ..TectonicConfig::default()
+                };
+                let config = match &self.scale {
+                    Some(scale) => defaults.derive_parameters(scale),
+                    None => defaults,
+                };
+                TectonicGenerator::new(self.seed).generate(width, height, &config)
+            }
+            Archetype::BasinAndRange => {
+                // Ridged north-south ranges, roughly one per 16 cells, over fractal detail
+                let mut map = fractal(0.5, 0.5);
+                let ranges = (width as f32 / 16.0).max(2.0);
+                for y in 0..height {
+                    for x in 0..width {
+                        let phase = std::f32::consts::PI * ranges * x as f32 / width as f32;
+                        let ridge = phase.sin().abs().powi(3);
+                        map.set(x, y, 0.4 * map.get(x, y) + 0.6 * ridge);
+                    }
+                }
+                map
+            }
+            Archetype::CoastalPlain => {
+                let mut map = fractal(0.3, 0.4);
+                for y in 0..height {
+                    for x in 0..width {
+                        let inland = x as f32 / width.saturating_sub(1).max(1) as f32;
+                        map.set(x, y, 0.3 * map.get(x, y) + 0.7 * inland);
+                    }
+                }
+                map
+            }
+        }
+    }
+}
+
+impl TerrainGenerator for ArchetypeGenerator {
+    type Config = Archetype;
+
+    fn generate(&self, width: usize, height: usize, config: &Self::Config) -> HeightMap {
+        let mut map = self.base_terrain(*config, width, height);
+        shape_hypsometry(&mut map, config.land_fraction(), config.relief_exponent());
+        map
+    }
+
+    fn name(&self) -> &'static str {
+        "Archetype"
+    }
+
+    fn supports_arbitrary_dimensions(&self) -> bool {
+        true
+    }
+}
+
+/// Remap elevations so `land_fraction` of cells lie at or above `SEA_LEVEL_ELEVATION`
+/// Sea floor keeps its relative depths; land heights are raised to `relief_exponent`.
+fn shape_hypsometry(map: &mut HeightMap, land_fraction: f32, relief_exponent: f32) {
+    if map.is_empty() {
+        return;
+    }
+    let mut sorted: Vec<f32> = map.iter().collect();
+    sorted.sort_by(f32::total_cmp);
+    let (min, max) = (sorted[0], sorted[sorted.len() - 1]);
+    let sea_cells = ((1.0 - land_fraction.clamp(0.0, 1.0)) * sorted.len() as f32) as usize;
+    let coast = sorted[sea_cells.min(sorted.len() - 1)];
+
+    for value in map.iter_mut() {
+        *value = if *value < coast {
+            let depth = (coast - *value) / (coast - min).max(f32::EPSILON);
+            SEA_LEVEL_ELEVATION - ARCHETYPE_OCEAN_DEPTH * depth
+        } else {
+            let land = (*value - coast) / (max - coast).max(f32::EPSILON);
+            SEA_LEVEL_ELEVATION + (1.0 - SEA_LEVEL_ELEVATION) * land.powf(relief_exponent)
+        };
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::engine::core::scale::DetailLevel;
+    use crate::engine::sim::Simulation;
 
     #[test]
     fn test_zero_coastal_blending_no_nan() {
@@ -842,4 +1025,64 @@ mod tests {
         assert_eq!(tectonic_generator.name(), "Layered Tectonic");
         assert!(tectonic_generator.supports_arbitrary_dimensions());
     }
+
+    #[test]
+    fn archetype_terrain_has_the_shape_its_name_promises() {
+        let scale = WorldScale::new(512.0, (64, 64), DetailLevel::Standard);
+        let generate = |archetype| ArchetypeGenerator::from_archetype(archetype, 42, &scale);
+
+        // The simulation's ocean mask sees the archetype sea
+        let archipelago = generate(Archetype::Archipelago);
+        assert_eq!((archipelago.width(), archipelago.height()), (64, 64));
+        let ocean = Simulation::new(archipelago.clone()).ocean_mask();
+        let ocean_cells = ocean.iter().filter(|&&is_ocean| is_ocean).count();
+        assert!(ocean_cells as f32 > 0.5 * ocean.len() as f32);
+
+        // Archipelago land is broken into several separate islands
+        let mut seen = vec![false; archipelago.len()];
+        let mut islands = 0;
+        for start in 0..archipelago.len() {
+            let (x, y) = (start % 64, start / 64);
+            if seen[start] || archipelago.get(x, y) < SEA_LEVEL_ELEVATION {
+                continue;
+            }
+            islands += 1;
+            let mut stack = vec![(x, y)];
+            seen[start] = true;
+            while let Some((cx, cy)) = stack.pop() {
+                let neighbors = [
+                    (cx.wrapping_sub(1), cy),
+                    (cx + 1, cy),
+                    (cx, cy.wrapping_sub(1)),
+                    (cx, cy + 1),
+                ];
+                for (nx, ny) in neighbors {
+                    if nx < 64
+                        && ny < 64
+                        && !seen[ny * 64 + nx]
+                        && archipelago.get(nx, ny) >= SEA_LEVEL_ELEVATION
+                    {
+                        seen[ny * 64 + nx] = true;
+                        stack.push((nx, ny));
+                    }
+                }
+            }
+        }
+        assert!(islands >= 3, "expected scattered islands, found {islands}");
+
+        // Coastal plain sea lies to the west
+        let coastal = generate(Archetype::CoastalPlain);
+        let sea_in = |columns: std::ops::Range<usize>| {
+            columns
+                .flat_map(|x| (0..64).map(move |y| (x, y)))
+                .filter(|&(x, y)| coastal.get(x, y) < SEA_LEVEL_ELEVATION)
+                .count()
+        };
+        assert!(sea_in(0..16) > 4 * sea_in(48..64).max(1));
+
+        assert_eq!(
+            generate(Archetype::Archipelago).to_nested(),
+            archipelago.to_nested()
+        );
+    }
 }
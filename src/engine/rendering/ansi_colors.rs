@@ -46,6 +46,9 @@ impl AnsiColor {
 /// ANSI color reset sequence
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// ANSI bold + inverse attributes used to flag changed cells
+pub const ANSI_HIGHLIGHT: &str = "\x1b[1;7m";
+
 /// Color mapping for elevation data (matching graphics frontend)
 pub fn elevation_to_ansi_color(elevation: f32) -> AnsiColor {
     match elevation {
@@ -86,6 +89,36 @@ pub fn temperature_to_ansi_color(temperature: f32, min_temp: f32, max_temp: f32)
     }
 }
 
+/// Thermal color mapping with a hard hue break at 0°C
+/// Blues and cyans below freezing, yellows and reds above, scaled to min/max on each side
+pub fn thermal_to_ansi_color(temperature: f32, min_temp: f32, max_temp: f32) -> AnsiColor {
+    if temperature < 0.0 {
+        let cold = if min_temp < 0.0 {
+            (temperature / min_temp).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        match cold {
+            c if c < 0.25 => AnsiColor::BrightCyan, // Just below freezing
+            c if c < 0.5 => AnsiColor::Cyan,        // Frost
+            c if c < 0.75 => AnsiColor::BrightBlue, // Hard freeze
+            _ => AnsiColor::Blue,                   // Deep cold
+        }
+    } else {
+        let warm = if max_temp > 0.0 {
+            (temperature / max_temp).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        match warm {
+            w if w < 0.25 => AnsiColor::BrightYellow, // Just above freezing
+            w if w < 0.5 => AnsiColor::Yellow,        // Mild
+            w if w < 0.75 => AnsiColor::Red,          // Warm
+            _ => AnsiColor::BrightRed,                // Hot
+        }
+    }
+}
+
 /// Color mapping for wind speed (blue = calm, red = strong)
 pub fn wind_speed_to_ansi_color(speed: f32) -> AnsiColor {
     // Use logarithmic scaling like graphics frontend
@@ -230,6 +263,11 @@ pub fn colorize_char_bg(ch: char, color: AnsiColor) -> String {
     format!("{}{}{}", color.bg(), ch, ANSI_RESET)
 }
 
+/// Format a character with color plus bold/inverse highlighting for changed cells
+pub fn colorize_char_highlighted(ch: char, color: AnsiColor) -> String {
+    format!("{}{}{}{}", ANSI_HIGHLIGHT, color.fg(), ch, ANSI_RESET)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,6 +297,26 @@ mod tests {
         assert_eq!(pressure_to_ansi_color(1018.0, min_p, max_p), AnsiColor::Red);
     }
 
+    #[test]
+    fn test_thermal_colors_break_at_freezing() {
+        let cold_family = [
+            AnsiColor::Blue,
+            AnsiColor::BrightBlue,
+            AnsiColor::Cyan,
+            AnsiColor::BrightCyan,
+        ];
+        let just_below = thermal_to_ansi_color(-0.1, -30.0, 30.0);
+        let just_above = thermal_to_ansi_color(0.1, -30.0, 30.0);
+        assert!(cold_family.contains(&just_below));
+        assert!(!cold_family.contains(&just_above));
+
+        assert_eq!(thermal_to_ansi_color(-30.0, -30.0, 30.0), AnsiColor::Blue);
+        assert_eq!(
+            thermal_to_ansi_color(30.0, -30.0, 30.0),
+            AnsiColor::BrightRed
+        );
+    }
+
     #[test]
     fn test_biome_colors() {
         assert_eq!(biome_to_ansi_color(BiomeType::Ocean), AnsiColor::Blue);
@@ -280,5 +338,10 @@ mod tests {
 
         let bg_colored = colorize_char_bg('B', AnsiColor::Blue);
         assert!(bg_colored.contains("\x1b[44m")); // Blue background
+
+        let highlighted = colorize_char_highlighted('C', AnsiColor::Green);
+        assert!(highlighted.starts_with(ANSI_HIGHLIGHT)); // Bold + inverse
+        assert!(highlighted.contains("\x1b[32m")); // Green foreground
+        assert!(highlighted.ends_with(ANSI_RESET));
     }
 }
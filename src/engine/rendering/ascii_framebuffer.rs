@@ -5,56 +5,31 @@
 // ABOUTME: Provides real-time monitoring with configurable layers, change detection, and frame buffering
 
 use super::super::agents::biome::BiomeType;
+use super::super::config::{FramebufferLayout, LayerSettings};
+use super::super::core::physics_grid::Float;
+pub use super::super::core::visualization_layer::VisualizationLayer;
 use super::super::sim::Simulation;
 use super::ansi_colors::{
-    AnsiColor, colorize_char, elevation_to_ansi_color, pressure_to_ansi_color,
-    temperature_to_ansi_color, wind_to_ansi_color,
+    AnsiColor, colorize_char, colorize_char_highlighted, elevation_to_ansi_color,
+    pressure_to_ansi_color, temperature_to_ansi_color, thermal_to_ansi_color, wind_to_ansi_color,
 };
-use std::collections::VecDeque;
-
-/// Available visualization layers for ASCII framebuffer
-#[derive(Debug, Clone, PartialEq)]
-pub enum VisualizationLayer {
-    Elevation,
-    Water,
-    Biomes,
-    Temperature,
-    Pressure,
-    Wind,
-    Flow,
-    Changes,
-    Sediment,
-}
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
 
+// Glyph ramps are rendering-specific, so they stay with the framebuffer
 impl VisualizationLayer {
-    /// Parse layer from string for CLI arguments
-    pub fn from_str(s: &str) -> Option<Self> {
-        match s.to_lowercase().as_str() {
-            "elevation" | "elev" | "height" => Some(Self::Elevation),
-            "water" | "depth" => Some(Self::Water),
-            "biomes" | "biome" => Some(Self::Biomes),
-            "temperature" | "temp" => Some(Self::Temperature),
-            "pressure" | "press" => Some(Self::Pressure),
-            "wind" => Some(Self::Wind),
-            "flow" | "velocity" => Some(Self::Flow),
-            "changes" | "diff" => Some(Self::Changes),
-            "sediment" | "sed" => Some(Self::Sediment),
-            _ => None,
-        }
-    }
-
-    /// Get display name for headers
-    pub fn display_name(&self) -> &'static str {
+    /// Default glyph ramp (low to high) for layers rendered from a scalar field
+    /// Biomes, Changes and Divides are categorical and have no ramp
+    fn default_symbols(&self) -> Option<&'static [char]> {
         match self {
-            Self::Elevation => "ELEVATION",
-            Self::Water => "WATER",
-            Self::Biomes => "BIOMES",
-            Self::Temperature => "TEMPERATURE",
-            Self::Pressure => "PRESSURE",
-            Self::Wind => "WIND",
-            Self::Flow => "FLOW",
-            Self::Changes => "CHANGES",
-            Self::Sediment => "SEDIMENT",
+            Self::Elevation => Some(&['~', '.', ',', '^', '#', '@', '%']),
+            Self::Water => Some(&['.', ':', '~', '#', '@']),
+            Self::Temperature => Some(&['■', '▓', '▒', '░', '.', '+', '#']),
+            Self::Pressure => Some(&['-', '.', '0', '+', '#']),
+            Self::Wind | Self::Flow => Some(&['.', ':', '-', '=', '#']),
+            Self::Sediment => Some(&['.', ':', '+', '#', '@']),
+            Self::Clouds => Some(&[' ', '.', ':', '%', '#']),
+            Self::Biomes | Self::Changes | Self::Divides => None,
         }
     }
 }
@@ -96,6 +71,26 @@ impl Default for FramebufferConfig {
     }
 }
 
+impl FramebufferConfig {
+    /// Framebuffer settings from a workspace layout; unknown layer names are skipped
+    /// (`WorkspaceConfig::validate` rejects them before this point)
+    pub fn from_layout(layout: &FramebufferLayout) -> Self {
+        Self {
+            layers: layout
+                .layers
+                .iter()
+                .filter_map(|name| VisualizationLayer::from_str(name))
+                .collect(),
+            buffer_size: layout.buffer_size.max(1),
+            panel_width: layout.frame_size.0,
+            panel_height: layout.frame_size.1,
+            show_timestamps: layout.show_timestamps,
+            highlight_changes: layout.highlight_changes,
+            subsample_rate: layout.subsample_rate.max(1),
+        }
+    }
+}
+
 /// Single ASCII frame containing all layer data
 #[derive(Debug, Clone)]
 pub struct AsciiFrame {
@@ -118,6 +113,9 @@ pub struct LayerFrame {
     pub chars: Vec<Vec<char>>,
     /// Color codes for each character (optional)
     pub colors: Vec<Vec<u8>>,
+    /// Cells whose character differs from the previous capture of this layer
+    /// (all false unless `highlight_changes` is enabled)
+    pub changed: Vec<Vec<bool>>,
 }
 
 /// ASCII framebuffer system with temporal buffering
@@ -130,6 +128,16 @@ pub struct AsciiFramebuffer {
     current_frame: usize,
     /// Cached previous frame for change detection
     previous_frame: Option<AsciiFrame>,
+    /// Characters from the last capture of each layer for change highlighting
+    previous_layer_chars: HashMap<VisualizationLayer, Vec<Vec<char>>>,
+    /// Per-layer glyph ramps replacing the built-in characters
+    layer_symbols: HashMap<VisualizationLayer, Vec<char>>,
+    /// Per-layer (min, max) used to normalize values instead of the data range
+    value_ranges: HashMap<VisualizationLayer, (f32, f32)>,
+    /// Per-layer magnification of the panel center (1 = whole map)
+    layer_zoom: HashMap<VisualizationLayer, usize>,
+    /// Color temperatures with the thermal ramp (hue break at 0°C)
+    thermal_temperature: bool,
 }
 
 impl AsciiFramebuffer {
@@ -143,11 +151,74 @@ impl AsciiFramebuffer {
             frame_buffer,
             current_frame: 0,
             previous_frame: None,
+            previous_layer_chars: HashMap::new(),
+            layer_symbols: HashMap::new(),
+            value_ranges: HashMap::new(),
+            layer_zoom: HashMap::new(),
+            thermal_temperature: false,
         }
     }
 
-    /// Capture current simulation state as ASCII frame
-    pub fn capture_frame(&mut self, simulation: &Simulation) -> AsciiFrame {
+    /// Color the temperature layer with the thermal ramp instead of the relative scale
+    pub fn set_thermal_temperature(&mut self, enabled: bool) {
+        self.thermal_temperature = enabled;
+    }
+
+    /// Replace a layer's built-in characters with a low-to-high glyph ramp
+    /// Ignored for categorical layers (biomes, changes); an empty ramp restores the default
+    pub fn set_layer_symbols(&mut self, layer: VisualizationLayer, symbols: Vec<char>) {
+        if symbols.is_empty() {
+            self.layer_symbols.remove(&layer);
+        } else {
+            self.layer_symbols.insert(layer, symbols);
+        }
+    }
+
+    /// Fix the (min, max) used to normalize a layer onto its glyph ramp
+    pub fn set_value_range(&mut self, layer: VisualizationLayer, range: Option<(f32, f32)>) {
+        match range {
+            Some(range) => self.value_ranges.insert(layer, range),
+            None => self.value_ranges.remove(&layer),
+        };
+    }
+
+    /// Magnify the center of a layer's panel by an integer factor (1 = whole map)
+    pub fn set_layer_zoom(&mut self, layer: VisualizationLayer, zoom: usize) {
+        self.layer_zoom.insert(layer, zoom.max(1));
+    }
+
+    /// Apply `symbols`, `value_range` and `zoom_override` from workspace layer settings
+    /// Unknown layer or zoom names are ignored
+    pub fn apply_layer_settings(&mut self, settings: &HashMap<String, LayerSettings>) {
+        for (name, layer_settings) in settings {
+            let Some(layer) = VisualizationLayer::from_str(name) else {
+                continue;
+            };
+            if let Some(symbols) = &layer_settings.symbols {
+                self.set_layer_symbols(layer.clone(), symbols.clone());
+            }
+            if let Some((min, max)) = layer_settings.value_range {
+                self.set_value_range(layer.clone(), Some((min as f32, max as f32)));
+            }
+            let zoom = match layer_settings.zoom_override.as_deref() {
+                Some("continental") => Some(1),
+                Some("regional") => Some(2),
+                Some("local") => Some(4),
+                _ => None,
+            };
+            if layer == VisualizationLayer::Temperature
+                && let Some(scheme) = layer_settings.color_scheme.as_deref()
+            {
+                self.set_thermal_temperature(scheme.eq_ignore_ascii_case("thermal"));
+            }
+            if let Some(zoom) = zoom {
+                self.set_layer_zoom(layer, zoom);
+            }
+        }
+    }
+
+    /// Panel dimensions for a simulation after subsampling and size limits
+    fn display_dimensions(&self, simulation: &Simulation) -> (usize, usize) {
         let width = simulation.get_width();
         let height = simulation.get_height();
 
@@ -167,6 +238,15 @@ impl AsciiFramebuffer {
             display_height.min(24) // Reasonable terminal height
         };
 
+        (final_width, final_height)
+    }
+
+    /// Capture current simulation state as ASCII frame
+    pub fn capture_frame(&mut self, simulation: &Simulation) -> AsciiFrame {
+        let width = simulation.get_width();
+        let height = simulation.get_height();
+        let (final_width, final_height) = self.display_dimensions(simulation);
+
         let mut layer_data = Vec::new();
 
         // Generate ASCII data for each requested layer
@@ -182,6 +262,12 @@ impl AsciiFramebuffer {
             layer_data.push(layer_frame);
         }
 
+        if self.config.highlight_changes {
+            for layer_frame in &mut layer_data {
+                self.mark_changed_cells(layer_frame);
+            }
+        }
+
         let frame = AsciiFrame {
             frame_number: self.current_frame,
             simulation_time: simulation.tick_count,
@@ -193,6 +279,64 @@ impl AsciiFramebuffer {
         frame
     }
 
+    /// Flag cells that differ from the previous capture of the same layer
+    /// The first capture of a layer (or a resized panel) marks nothing as changed
+    fn mark_changed_cells(&mut self, layer_frame: &mut LayerFrame) {
+        if let Some(previous) = self.previous_layer_chars.get(&layer_frame.layer_type) {
+            let same_shape = previous.len() == layer_frame.chars.len()
+                && previous
+                    .iter()
+                    .zip(&layer_frame.chars)
+                    .all(|(old, new)| old.len() == new.len());
+            if same_shape {
+                for ((changed_row, old_row), new_row) in layer_frame
+                    .changed
+                    .iter_mut()
+                    .zip(previous)
+                    .zip(&layer_frame.chars)
+                {
+                    for ((changed, old), new) in changed_row.iter_mut().zip(old_row).zip(new_row) {
+                        *changed = old != new;
+                    }
+                }
+            }
+        }
+
+        self.previous_layer_chars
+            .insert(layer_frame.layer_type.clone(), layer_frame.chars.clone());
+    }
+
+    /// Render a single layer of the current simulation state as plain text
+    /// Uses the same layout and timestamp header as the TUI, without ANSI colors,
+    /// and does not advance the frame counter
+    pub fn render_to_string(&self, simulation: &Simulation, layer: VisualizationLayer) -> String {
+        let (final_width, final_height) = self.display_dimensions(simulation);
+        let layer_frame = self.generate_layer_frame(
+            simulation,
+            layer,
+            final_width,
+            final_height,
+            simulation.get_width(),
+            simulation.get_height(),
+        );
+
+        let frame = AsciiFrame {
+            frame_number: self.current_frame,
+            simulation_time: simulation.tick_count,
+            layer_data: vec![layer_frame],
+            dimensions: (final_width, final_height),
+        };
+        self.format_frame(&frame)
+    }
+
+    /// Write the most recently buffered frame as plain text (no-op when empty)
+    pub fn append_to_writer(&self, writer: &mut dyn Write) -> std::io::Result<()> {
+        if let Some(frame) = self.latest_frame() {
+            writer.write_all(self.format_frame(frame).as_bytes())?;
+        }
+        Ok(())
+    }
+
     /// Add frame to buffer and maintain size limit
     pub fn add_frame(&mut self, frame: AsciiFrame) {
         // Store previous frame for change detection
@@ -209,7 +353,8 @@ impl AsciiFramebuffer {
         }
     }
 
-    /// Generate ASCII representation for a specific layer
+    /// Generate ASCII representation for a specific layer, applying its zoom,
+    /// symbol and value range settings
     fn generate_layer_frame(
         &self,
         simulation: &Simulation,
@@ -218,6 +363,48 @@ impl AsciiFramebuffer {
         display_height: usize,
         sim_width: usize,
         sim_height: usize,
+    ) -> LayerFrame {
+        // Zoom renders a larger virtual panel and keeps its central window
+        let zoom = self.layer_zoom.get(&layer_type).copied().unwrap_or(1);
+        let virtual_width = display_width * zoom;
+        let virtual_height = display_height * zoom;
+
+        let mut frame = self.generate_unzoomed_layer_frame(
+            simulation,
+            layer_type,
+            virtual_width,
+            virtual_height,
+            sim_width,
+            sim_height,
+        );
+
+        if virtual_width > display_width || virtual_height > display_height {
+            let x0 = (virtual_width - display_width) / 2;
+            let y0 = (virtual_height - display_height) / 2;
+            let rows = y0..y0 + display_height;
+            frame.chars = frame.chars[rows.clone()]
+                .iter()
+                .map(|row| row[x0..x0 + display_width].to_vec())
+                .collect();
+            frame.colors = frame.colors[rows]
+                .iter()
+                .map(|row| row[x0..x0 + display_width].to_vec())
+                .collect();
+            frame.changed = vec![vec![false; display_width]; display_height];
+        }
+
+        frame
+    }
+
+    /// Generate a layer over the whole map at the given panel size
+    fn generate_unzoomed_layer_frame(
+        &self,
+        simulation: &Simulation,
+        layer_type: VisualizationLayer,
+        display_width: usize,
+        display_height: usize,
+        sim_width: usize,
+        sim_height: usize,
     ) -> LayerFrame {
         let mut chars = vec![vec![' '; display_width]; display_height];
         let mut colors = vec![vec![0u8; display_width]; display_height];
@@ -314,12 +501,98 @@ impl AsciiFramebuffer {
                     sim_height,
                 );
             }
+            VisualizationLayer::Clouds => {
+                self.generate_clouds_layer(
+                    simulation,
+                    &mut chars,
+                    display_width,
+                    display_height,
+                    sim_width,
+                    sim_height,
+                );
+            }
+            VisualizationLayer::Divides => {
+                self.generate_divides_layer(
+                    simulation,
+                    &mut chars,
+                    display_width,
+                    display_height,
+                    sim_width,
+                    sim_height,
+                );
+            }
+        }
+
+        if self.layer_symbols.contains_key(&layer_type)
+            || self.value_ranges.contains_key(&layer_type)
+        {
+            self.apply_symbol_ramp(simulation, &layer_type, &mut chars, sim_width, sim_height);
         }
 
         LayerFrame {
             layer_type,
             chars,
             colors,
+            changed: vec![vec![false; display_width]; display_height],
+        }
+    }
+
+    /// Re-render a layer on its glyph ramp, normalizing over the configured value
+    /// range or, without one, the range of values shown in the panel
+    fn apply_symbol_ramp(
+        &self,
+        simulation: &Simulation,
+        layer_type: &VisualizationLayer,
+        chars: &mut [Vec<char>],
+        sim_width: usize,
+        sim_height: usize,
+    ) {
+        let display_height = chars.len();
+        let display_width = chars.first().map_or(0, Vec::len);
+        let symbols = match self.layer_symbols.get(layer_type) {
+            Some(symbols) => symbols.as_slice(),
+            None => match layer_type.default_symbols() {
+                Some(symbols) => symbols,
+                None => return,
+            },
+        };
+
+        let mut values = vec![vec![0.0; display_width]; display_height];
+        for (y, row) in values.iter_mut().enumerate() {
+            for (x, value) in row.iter_mut().enumerate() {
+                let sim_x = (x * sim_width) / display_width;
+                let sim_y = (y * sim_height) / display_height;
+                match simulation.layer_value(layer_type, sim_x, sim_y) {
+                    Some(v) => *value = v,
+                    None => return,
+                }
+            }
+        }
+
+        let (min, max) = self
+            .value_ranges
+            .get(layer_type)
+            .copied()
+            .unwrap_or_else(|| {
+                values
+                    .iter()
+                    .flatten()
+                    .fold((f32::INFINITY, f32::NEG_INFINITY), |(lo, hi), &v| {
+                        (lo.min(v), hi.max(v))
+                    })
+            });
+        let span = max - min;
+
+        for (char_row, value_row) in chars.iter_mut().zip(&values) {
+            for (cell, &value) in char_row.iter_mut().zip(value_row) {
+                let normalized = if span > 0.0 {
+                    ((value - min) / span).clamp(0.0, 1.0)
+                } else {
+                    0.5
+                };
+                let index = ((normalized * symbols.len() as f32) as usize).min(symbols.len() - 1);
+                *cell = symbols[index];
+            }
         }
     }
 
@@ -381,7 +654,7 @@ impl AsciiFramebuffer {
 
                 // Get water depth (need to access water layer directly)
                 let water_depth = if sim_x < sim_width && sim_y < sim_height {
-                    simulation.water.depth.get(sim_x, sim_y)
+                    simulation.water.depth.get(sim_x, sim_y).to_f32()
                 } else {
                     0.0
                 };
@@ -507,7 +780,16 @@ impl AsciiFramebuffer {
                 };
 
                 // Store ANSI color code using dynamic range for better color distribution
-                let ansi_color = temperature_to_ansi_color(temperature, min_temp, max_temp);
+                let ansi_color = if self.thermal_temperature {
+                    let (min, max) = self
+                        .value_ranges
+                        .get(&VisualizationLayer::Temperature)
+                        .copied()
+                        .unwrap_or((min_temp, max_temp));
+                    thermal_to_ansi_color(temperature, min, max)
+                } else {
+                    temperature_to_ansi_color(temperature, min_temp, max_temp)
+                };
                 colors[y][x] = ansi_color as u8;
             }
         }
@@ -527,8 +809,8 @@ impl AsciiFramebuffer {
         let pressure_layer = simulation.get_pressure_layer();
 
         // Calculate pressure range for normalization using PhysicsGrid's optimized methods
-        let min_pressure = pressure_layer.pressure.min();
-        let max_pressure = pressure_layer.pressure.max();
+        let min_pressure = pressure_layer.pressure.min().to_f32();
+        let max_pressure = pressure_layer.pressure.max().to_f32();
         let pressure_range = max_pressure - min_pressure;
 
         for y in 0..display_height {
@@ -708,6 +990,58 @@ impl AsciiFramebuffer {
         }
     }
 
+    /// Generate cloud cover layer ASCII
+    fn generate_clouds_layer(
+        &self,
+        simulation: &Simulation,
+        chars: &mut [Vec<char>],
+        display_width: usize,
+        display_height: usize,
+        sim_width: usize,
+        sim_height: usize,
+    ) {
+        for (y, row) in chars.iter_mut().enumerate().take(display_height) {
+            for (x, cell) in row.iter_mut().enumerate().take(display_width) {
+                let sim_x = (x * sim_width) / display_width;
+                let sim_y = (y * sim_height) / display_height;
+
+                *cell = match simulation.get_cloud_fraction_at(sim_x, sim_y) {
+                    c if c < 0.1 => ' ', // Clear sky
+                    c if c < 0.3 => '.', // Scattered
+                    c if c < 0.6 => ':', // Broken
+                    c if c < 0.9 => '%', // Mostly cloudy
+                    _ => '#',            // Overcast
+                };
+            }
+        }
+    }
+
+    fn generate_divides_layer(
+        &self,
+        simulation: &Simulation,
+        chars: &mut [Vec<char>],
+        display_width: usize,
+        display_height: usize,
+        sim_width: usize,
+        sim_height: usize,
+    ) {
+        let divides = simulation.drainage_network.divides();
+        for (y, row) in chars.iter_mut().enumerate().take(display_height) {
+            for (x, cell) in row.iter_mut().enumerate().take(display_width) {
+                let sim_x = (x * sim_width) / display_width;
+                let sim_y = (y * sim_height) / display_height;
+
+                *cell = if *divides.get(sim_x, sim_y) {
+                    '#' // Ridgeline between watersheds
+                } else if simulation.drainage_network.is_river(sim_x, sim_y) {
+                    '~' // Channel
+                } else {
+                    '.'
+                };
+            }
+        }
+    }
+
     /// Format frame for display with multi-layer layout
     pub fn format_frame(&self, frame: &AsciiFrame) -> String {
         let mut output = String::new();
@@ -720,8 +1054,8 @@ impl AsciiFramebuffer {
         }
 
         let (width, height) = frame.dimensions;
-        let layers_per_row = (self.config.layers.len()).min(4); // Max 4 layers per row
-        let rows_needed = (self.config.layers.len() + layers_per_row - 1) / layers_per_row;
+        let layers_per_row = frame.layer_data.len().clamp(1, 4); // Max 4 layers per row
+        let rows_needed = frame.layer_data.len().div_ceil(layers_per_row);
 
         for row in 0..rows_needed {
             // Headers
@@ -785,8 +1119,8 @@ impl AsciiFramebuffer {
         }
 
         let (width, height) = frame.dimensions;
-        let layers_per_row = (self.config.layers.len()).min(4); // Max 4 layers per row
-        let rows_needed = (self.config.layers.len() + layers_per_row - 1) / layers_per_row;
+        let layers_per_row = frame.layer_data.len().clamp(1, 4); // Max 4 layers per row
+        let rows_needed = frame.layer_data.len().div_ceil(layers_per_row);
 
         for row in 0..rows_needed {
             // Headers
@@ -831,8 +1165,12 @@ impl AsciiFramebuffer {
                                     _ => AnsiColor::White, // Default fallback
                                 };
 
-                                // Add colorized character to output
-                                output.push_str(&colorize_char(ch, ansi_color));
+                                // Add colorized character to output, flagging changed cells
+                                if layer.changed[y][x] {
+                                    output.push_str(&colorize_char_highlighted(ch, ansi_color));
+                                } else {
+                                    output.push_str(&colorize_char(ch, ansi_color));
+                                }
                             } else {
                                 output.push(' ');
                             }
@@ -848,3 +1186,126 @@ impl AsciiFramebuffer {
         output
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::core::heightmap::HeightMap;
+    use crate::engine::rendering::ansi_colors::ANSI_HIGHLIGHT;
+
+    #[test]
+    fn render_to_string_matches_golden_elevation_frame() {
+        // Coastal plain in the north half, mountains in the south half
+        let mut rows = vec![vec![0.1; 16]; 8];
+        rows.extend(vec![vec![0.7; 16]; 8]);
+        let simulation = Simulation::new(HeightMap::from_nested(rows));
+        let framebuffer = AsciiFramebuffer::new(FramebufferConfig::default());
+
+        let rendered = framebuffer.render_to_string(&simulation, VisualizationLayer::Elevation);
+
+        let mut expected = String::from("=== FRAME 000 (t=     0 ticks) ===\nELEVATION    \n");
+        for _ in 0..8 {
+            expected.push_str(",,,,,,,,,,,,,,,,  \n");
+        }
+        for _ in 0..8 {
+            expected.push_str("@@@@@@@@@@@@@@@@  \n");
+        }
+        expected.push('\n');
+        assert_eq!(rendered, expected);
+
+        // Writer export emits the buffered frame with the same layout
+        let mut framebuffer = AsciiFramebuffer::new(FramebufferConfig {
+            layers: vec![VisualizationLayer::Elevation],
+            show_timestamps: false,
+            ..Default::default()
+        });
+        let frame = framebuffer.capture_frame(&simulation);
+        framebuffer.add_frame(frame);
+        let mut log = Vec::new();
+        framebuffer.append_to_writer(&mut log).unwrap();
+        let logged = String::from_utf8(log).unwrap();
+        assert_eq!(logged, expected.split_once('\n').unwrap().1);
+    }
+
+    #[test]
+    fn highlight_changes_marks_only_toggled_cell() {
+        let mut simulation = Simulation::new(HeightMap::from_nested(vec![vec![0.1; 16]; 16]));
+        let mut framebuffer = AsciiFramebuffer::new(FramebufferConfig {
+            layers: vec![VisualizationLayer::Elevation],
+            highlight_changes: true,
+            ..Default::default()
+        });
+
+        let first = framebuffer.capture_frame(&simulation);
+        assert!(first.layer_data[0].changed.iter().flatten().all(|&c| !c));
+
+        simulation.heightmap.set(3, 5, 0.9);
+        let second = framebuffer.capture_frame(&simulation);
+        let changed: Vec<(usize, usize)> = second.layer_data[0]
+            .changed
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(_, &c)| c)
+                    .map(move |(x, _)| (x, y))
+            })
+            .collect();
+        assert_eq!(changed, vec![(3, 5)]);
+
+        let output = framebuffer.format_frame_colorized(&second);
+        assert_eq!(output.matches(ANSI_HIGHLIGHT).count(), 1);
+
+        // Unchanged follow-up frame renders normally
+        let third = framebuffer.capture_frame(&simulation);
+        let output = framebuffer.format_frame_colorized(&third);
+        assert_eq!(output.matches(ANSI_HIGHLIGHT).count(), 0);
+    }
+
+    #[test]
+    fn layer_settings_apply_custom_symbol_ramp_and_zoom() {
+        // Elevation rising west to east across the map
+        let rows = vec![(0..16).map(|x| x as f32 / 15.0).collect::<Vec<f32>>(); 16];
+        let simulation = Simulation::new(HeightMap::from_nested(rows));
+        let mut framebuffer = AsciiFramebuffer::new(FramebufferConfig {
+            show_timestamps: false,
+            ..Default::default()
+        });
+
+        let mut settings = HashMap::new();
+        settings.insert(
+            "elevation".to_string(),
+            LayerSettings {
+                zoom_override: None,
+                color_scheme: None,
+                value_range: Some((0.0, 1.0)),
+                symbols: Some(vec!['a', 'b', 'c']),
+            },
+        );
+        framebuffer.apply_layer_settings(&settings);
+
+        let rendered = framebuffer.render_to_string(&simulation, VisualizationLayer::Elevation);
+        let panel: Vec<char> = rendered
+            .lines()
+            .skip(1)
+            .flat_map(|line| line.trim_end().chars())
+            .collect();
+        assert_eq!(panel.len(), 16 * 16);
+        assert!(panel.iter().all(|c| ['a', 'b', 'c'].contains(c)));
+        for symbol in ['a', 'b', 'c'] {
+            assert!(panel.contains(&symbol));
+        }
+
+        // Local zoom shows only the middle of the ramp
+        settings.get_mut("elevation").unwrap().zoom_override = Some("local".to_string());
+        framebuffer.apply_layer_settings(&settings);
+        let zoomed = framebuffer.render_to_string(&simulation, VisualizationLayer::Elevation);
+        assert!(
+            zoomed
+                .lines()
+                .skip(1)
+                .all(|line| !line.contains('a') && !line.contains('c'))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2025 Jerry Snitselaar and contributors
+
+// ABOUTME: Frame recorder capturing a rendered layer every N ticks for producing animations
+// ABOUTME: Writes numbered PNG sequences or one raw RGBA stream and tracks frames dropped under load
+
+use super::graphics_render::{DisplayMode, GraphicsRenderer};
+use crate::engine::Simulation;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Where captured frames are written
+enum FrameSink {
+    /// One PNG per frame named `<layer>_tick_<tick>.png` inside the directory
+    PngSequence { directory: PathBuf },
+    /// Frames appended back to back as 8-bit RGBA, suitable for `ffmpeg -f rawvideo`
+    RawStream { writer: BufWriter<File> },
+}
+
+/// Captures one layer of a running simulation at a fixed tick interval
+pub struct FrameRecorder {
+    renderer: GraphicsRenderer,
+    layer: DisplayMode,
+    interval: u64,
+    /// Pixels per simulation cell along each axis
+    cell_pixels: usize,
+    sink: FrameSink,
+    /// Wall-clock time a frame may take before later frames are dropped
+    frame_budget: Option<Duration>,
+    /// Rendering time spent beyond the budget and not yet paid back by dropped frames
+    overrun: Duration,
+    frame_paths: Vec<PathBuf>,
+    frames_written: usize,
+    dropped_frames: usize,
+    frame_size: Option<(usize, usize)>,
+}
+
+impl FrameRecorder {
+    /// Record `layer` every `interval` ticks as a PNG sequence in `directory` (created if missing)
+    pub fn png_sequence(
+        directory: impl AsRef<Path>,
+        layer: DisplayMode,
+        interval: u64,
+    ) -> io::Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        std::fs::create_dir_all(&directory)?;
+        Ok(Self::with_sink(
+            FrameSink::PngSequence { directory },
+            layer,
+            interval,
+        ))
+    }
+
+    /// Record `layer` every `interval` ticks into a single raw RGBA stream at `path`
+    pub fn raw_stream(
+        path: impl AsRef<Path>,
+        layer: DisplayMode,
+        interval: u64,
+    ) -> io::Result<Self> {
+        let writer = BufWriter::new(File::create(path)?);
+        Ok(Self::with_sink(
+            FrameSink::RawStream { writer },
+            layer,
+            interval,
+        ))
+    }
+
+    fn with_sink(sink: FrameSink, layer: DisplayMode, interval: u64) -> Self {
+        Self {
+            renderer: GraphicsRenderer::new(800.0, 600.0),
+            layer,
+            interval: interval.max(1),
+            cell_pixels: 1,
+            sink,
+            frame_budget: None,
+            overrun: Duration::ZERO,
+            frame_paths: Vec::new(),
+            frames_written: 0,
+            dropped_frames: 0,
+            frame_size: None,
+        }
+    }
+
+    /// Renderer used for coloring, e.g. to set color schemes or value ranges
+    pub fn renderer_mut(&mut self) -> &mut GraphicsRenderer {
+        &mut self.renderer
+    }
+
+    /// Enlarge each cell to a square of `pixels` pixels (at least 1)
+    pub fn set_cell_pixels(&mut self, pixels: usize) {
+        self.cell_pixels = pixels.max(1);
+    }
+
+    /// Drop due frames while earlier frames have overrun this per-frame time budget
+    pub fn set_frame_budget(&mut self, budget: Option<Duration>) {
+        self.frame_budget = budget;
+        self.overrun = Duration::ZERO;
+    }
+
+    /// Capture a frame if the simulation's tick count falls on the recording interval
+    /// Returns whether a frame was written
+    pub fn after_tick(&mut self, simulation: &Simulation) -> io::Result<bool> {
+        if !simulation.tick_count.is_multiple_of(self.interval) {
+            return Ok(false);
+        }
+
+        if let Some(budget) = self.frame_budget
+            && self.overrun >= budget
+        {
+            self.overrun -= budget;
+            self.dropped_frames += 1;
+            return Ok(false);
+        }
+
+        let started = Instant::now();
+        self.capture(simulation)?;
+        if let Some(budget) = self.frame_budget {
+            self.overrun += started.elapsed().saturating_sub(budget);
+        }
+        Ok(true)
+    }
+
+    /// Render and write the current state immediately, regardless of the interval
+    pub fn capture(&mut self, simulation: &Simulation) -> io::Result<()> {
+        let width = simulation.get_width() * self.cell_pixels;
+        let height = simulation.get_height() * self.cell_pixels;
+        if let Some(size) = self.frame_size
+            && size != (width, height)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame size changed during recording",
+            ));
+        }
+        self.frame_size = Some((width, height));
+
+        let rgba = self.frame_rgba(simulation);
+        match &mut self.sink {
+            FrameSink::PngSequence { directory } => {
+                let path = directory.join(format!(
+                    "{}_tick_{:06}.png",
+                    self.layer.layer_name(),
+                    simulation.tick_count
+                ));
+                let mut encoder = png::Encoder::new(
+                    BufWriter::new(File::create(&path)?),
+                    width as u32,
+                    height as u32,
+                );
+                encoder.set_color(png::ColorType::Rgba);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder.write_header()?.write_image_data(&rgba)?;
+                self.frame_paths.push(path);
+            }
+            FrameSink::RawStream { writer } => writer.write_all(&rgba)?,
+        }
+        self.frames_written += 1;
+        Ok(())
+    }
+
+    /// 8-bit RGBA bytes of the recorded layer, scaled up by the cell size
+    fn frame_rgba(&self, simulation: &Simulation) -> Vec<u8> {
+        let cells = self.renderer.layer_pixels(simulation, self.layer);
+        let width = simulation.get_width();
+        let scale = self.cell_pixels;
+        let mut rgba = Vec::with_capacity(cells.len() * scale * scale * 4);
+        for row in cells.chunks(width.max(1)) {
+            for _ in 0..scale {
+                for color in row {
+                    let bytes: [u8; 4] = (*color).into();
+                    for _ in 0..scale {
+                        rgba.extend_from_slice(&bytes);
+                    }
+                }
+            }
+        }
+        rgba
+    }
+
+    /// Flush any buffered stream output
+    pub fn finish(&mut self) -> io::Result<()> {
+        match &mut self.sink {
+            FrameSink::RawStream { writer } => writer.flush(),
+            FrameSink::PngSequence { .. } => Ok(()),
+        }
+    }
+
+    /// Frame dimensions in pixels once the first frame has been captured
+    pub fn frame_size(&self) -> Option<(usize, usize)> {
+        self.frame_size
+    }
+
+    /// Paths of PNG frames written so far, in recording order
+    pub fn frame_paths(&self) -> &[PathBuf] {
+        &self.frame_paths
+    }
+
+    pub fn frames_written(&self) -> usize {
+        self.frames_written
+    }
+
+    /// Frames skipped because rendering could not keep up with the frame budget
+    pub fn dropped_frames(&self) -> usize {
+        self.dropped_frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::core::heightmap::HeightMap;
+
+    #[test]
+    fn records_png_frame_every_interval() {
+        let directory = std::env::temp_dir().join("kosmarium_frame_recorder_test");
+        std::fs::remove_dir_all(&directory).ok();
+        let terrain: Vec<Vec<f32>> = (0..6)
+            .map(|y| (0..8).map(|x| 0.1 * x as f32 + 0.05 * y as f32).collect())
+            .collect();
+        let mut sim = Simulation::new(HeightMap::from_nested(terrain));
+        let mut recorder =
+            FrameRecorder::png_sequence(&directory, DisplayMode::Elevation, 2).unwrap();
+        recorder.set_cell_pixels(3);
+
+        sim.run_ticks(10, |sim| recorder.after_tick(sim).map(|_| ()))
+            .unwrap();
+        recorder.finish().unwrap();
+
+        assert_eq!(recorder.frames_written(), 5);
+        assert_eq!(recorder.dropped_frames(), 0);
+        assert_eq!(recorder.frame_size(), Some((24, 18)));
+        let mut names: Vec<String> = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        let ticks: Vec<u64> = names
+            .iter()
+            .map(|name| {
+                name.trim_start_matches("elevation_tick_")
+                    .trim_end_matches(".png")
+                    .parse()
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(ticks, vec![2, 4, 6, 8, 10]);
+        std::fs::remove_dir_all(&directory).ok();
+    }
+}
@@ -4,11 +4,14 @@
 // ABOUTME: Graphics rendering system using macroquad for atmospheric visualization
 // ABOUTME: Handles wind vectors, pressure fields, and weather patterns with 2D graphics
 
-use super::super::agents::biome::BiomeType;
 use super::super::physics::atmosphere::{WeatherPattern, WeatherPatternType};
 use crate::engine::Simulation;
+use crate::engine::config::LayerSettings;
+use crate::engine::core::heightmap::HeightMap;
+use crate::engine::core::physics_grid::PhysicsGrid;
 use crate::engine::physics::climate::AtmosphericPressureLayer;
 use macroquad::prelude::*;
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 // Layout constants for bounded viewport system
@@ -17,6 +20,56 @@ const RIGHT_SIDEBAR_WIDTH: f32 = 200.0;
 const TOP_BAR_HEIGHT: f32 = 40.0;
 const BOTTOM_BAR_HEIGHT: f32 = 30.0;
 
+// Legend bar geometry within the right sidebar
+const LEGEND_BAR_HEIGHT: f32 = 14.0;
+const LEGEND_BAR_STEPS: usize = 48;
+
+// Longest scale bar drawn in the map viewport (pixels)
+const SCALE_BAR_MAX_WIDTH: f32 = 150.0;
+// Kilometers per degree of latitude for the coordinate readout
+const KM_PER_DEGREE: f64 = 111.32;
+
+// Default illumination for hillshading: classic northwest sun, 45 degrees up
+const HILLSHADE_SUN_AZIMUTH: f32 = 315.0;
+const HILLSHADE_SUN_ALTITUDE: f32 = 45.0;
+// Vertical exaggeration applied to normalized elevations before shading
+const HILLSHADE_EXAGGERATION: f32 = 8.0;
+// Brightness of fully shadowed cells when tinting by elevation
+const HILLSHADE_AMBIENT: f32 = 0.35;
+
+// Color stops (position, r, g, b) for the piecewise-linear schemes
+const VIRIDIS_STOPS: [(f32, f32, f32, f32); 5] = [
+    (0.0, 0.267, 0.005, 0.329),
+    (0.25, 0.229, 0.322, 0.546),
+    (0.5, 0.128, 0.567, 0.551),
+    (0.75, 0.369, 0.789, 0.383),
+    (1.0, 0.993, 0.906, 0.144),
+];
+const BLUE_RED_STOPS: [(f32, f32, f32, f32); 3] = [
+    (0.0, 0.0, 0.0, 1.0),
+    (0.5, 1.0, 1.0, 1.0), // Neutral midpoint
+    (1.0, 1.0, 0.0, 0.0),
+];
+// Thermal ramp halves: indigo to pale cyan up to freezing, pale yellow to dark red above
+const THERMAL_COLD_STOPS: [(f32, f32, f32, f32); 3] = [
+    (0.0, 0.19, 0.07, 0.43),
+    (0.5, 0.13, 0.4, 0.85),
+    (1.0, 0.6, 0.9, 1.0),
+];
+const THERMAL_WARM_STOPS: [(f32, f32, f32, f32); 3] = [
+    (0.0, 1.0, 0.95, 0.6),
+    (0.5, 0.98, 0.55, 0.15),
+    (1.0, 0.6, 0.0, 0.05),
+];
+const TERRAIN_STOPS: [(f32, f32, f32, f32); 6] = [
+    (0.0, 0.0, 0.1, 0.5),
+    (0.2, 0.2, 0.5, 0.9),
+    (0.35, 0.9, 0.85, 0.6),
+    (0.5, 0.2, 0.6, 0.2),
+    (0.75, 0.5, 0.4, 0.25),
+    (1.0, 1.0, 1.0, 1.0),
+];
+
 pub struct GraphicsRenderer {
     camera: Camera2D,
     viewport: Rect,
@@ -25,9 +78,16 @@ pub struct GraphicsRenderer {
     pan_offset: Vec2,
     simulation_paused: bool,
     last_sim_tick: Instant,
+    color_schemes: HashMap<DisplayMode, ColorScheme>,
+    value_ranges: HashMap<DisplayMode, (f32, f32)>,
+    show_legend_bar: bool,
+    show_scale_bar: bool,
+    show_km_grid: bool,
+    show_hillshade: bool,
+    hillshade_tint: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DisplayMode {
     Elevation,
     Water,
@@ -38,6 +98,185 @@ pub enum DisplayMode {
     Biomes,
 }
 
+impl DisplayMode {
+    /// Lowercase layer name, the inverse of `from_layer_name`
+    pub fn layer_name(&self) -> &'static str {
+        match self {
+            DisplayMode::Elevation => "elevation",
+            DisplayMode::Water => "water",
+            DisplayMode::Pressure => "pressure",
+            DisplayMode::Wind => "wind",
+            DisplayMode::Weather => "weather",
+            DisplayMode::Temperature => "temperature",
+            DisplayMode::Biomes => "biomes",
+        }
+    }
+
+    /// Match a framebuffer layer name from the config (e.g. "temperature")
+    pub fn from_layer_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "elevation" => Some(DisplayMode::Elevation),
+            "water" => Some(DisplayMode::Water),
+            "pressure" => Some(DisplayMode::Pressure),
+            "wind" => Some(DisplayMode::Wind),
+            "weather" => Some(DisplayMode::Weather),
+            "temperature" => Some(DisplayMode::Temperature),
+            "biomes" => Some(DisplayMode::Biomes),
+            _ => None,
+        }
+    }
+}
+
+/// Continuous color scales that can replace a layer's built-in coloring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// Perceptually uniform purple-to-yellow ramp
+    Viridis,
+    /// Black to white
+    Grayscale,
+    /// Diverging blue-white-red with a neutral midpoint
+    BlueRed,
+    /// Hypsometric tints from deep water to snow
+    Terrain,
+    /// Temperature ramp, cold blues to hot reds, with a hue break at 0°C
+    Thermal,
+}
+
+impl ColorScheme {
+    /// Parse a config `color_scheme` name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace(['-', '_'], "").as_str() {
+            "viridis" => Some(ColorScheme::Viridis),
+            "grayscale" | "greyscale" => Some(ColorScheme::Grayscale),
+            "bluered" => Some(ColorScheme::BlueRed),
+            "terrain" => Some(ColorScheme::Terrain),
+            "thermal" => Some(ColorScheme::Thermal),
+            _ => None,
+        }
+    }
+
+    /// Color for a value already normalized to [0, 1]; non-finite values map to the midpoint
+    /// Thermal places freezing at the midpoint here; layers use `thermal_color` with real °C
+    pub fn color_at(&self, normalized: f32) -> Color {
+        let t = if normalized.is_finite() {
+            normalized.clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        match self {
+            ColorScheme::Viridis => interpolate_stops(&VIRIDIS_STOPS, t),
+            ColorScheme::Grayscale => Color::new(t, t, t, 1.0),
+            ColorScheme::BlueRed => interpolate_stops(&BLUE_RED_STOPS, t),
+            ColorScheme::Terrain => interpolate_stops(&TERRAIN_STOPS, t),
+            ColorScheme::Thermal => thermal_color(2.0 * t - 1.0, -1.0, 1.0),
+        }
+    }
+}
+
+/// Thermal ramp color for a temperature (°C) between min_t and max_t
+/// Each side of 0°C spans its own half of the ramp, so the freezing line is always a hue break
+pub fn thermal_color(temperature: f32, min_t: f32, max_t: f32) -> Color {
+    if temperature < 0.0 {
+        let t = if min_t < 0.0 {
+            1.0 - (temperature / min_t).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        interpolate_stops(&THERMAL_COLD_STOPS, t)
+    } else {
+        let t = if max_t > 0.0 {
+            (temperature / max_t).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        interpolate_stops(&THERMAL_WARM_STOPS, t)
+    }
+}
+
+/// Longest 1-2-5 series distance (km) whose bar fits within `max_px`,
+/// returned with its length in pixels for cells drawn `cell_size_px` wide
+pub fn scale_bar_length(meters_per_cell: f64, cell_size_px: f32, max_px: f32) -> (f64, f32) {
+    if meters_per_cell <= 0.0 || cell_size_px <= 0.0 || max_px <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let km_per_px = meters_per_cell / 1000.0 / cell_size_px as f64;
+    let max_km = max_px as f64 * km_per_px;
+
+    let magnitude = 10f64.powf(max_km.log10().floor());
+    let leading = max_km / magnitude;
+    let round_km = if leading >= 5.0 {
+        5.0 * magnitude
+    } else if leading >= 2.0 {
+        2.0 * magnitude
+    } else {
+        magnitude
+    };
+
+    (round_km, (round_km / km_per_px) as f32)
+}
+
+/// Lambertian illumination (0-1) of each cell for a sun at the given compass
+/// azimuth and altitude (degrees), using Horn slope and aspect
+pub fn hillshade(
+    heightmap: &HeightMap,
+    cell_spacing: f32,
+    sun_azimuth: f32,
+    sun_altitude: f32,
+) -> PhysicsGrid<f32> {
+    let slope = heightmap.slope_field(cell_spacing);
+    let aspect = heightmap.aspect_field();
+    let zenith = (90.0 - sun_altitude).to_radians();
+    let azimuth = sun_azimuth.to_radians();
+
+    let mut shade = PhysicsGrid::new(heightmap.width(), heightmap.height(), 0.0);
+    for (x, y, &slope_deg) in slope.iter_coords() {
+        let slope_rad = slope_deg.to_radians();
+        // Flat cells have no aspect; their slope term vanishes anyway
+        let aspect_rad = aspect.get(x, y).max(0.0).to_radians();
+        let intensity = zenith.cos() * slope_rad.cos()
+            + zenith.sin() * slope_rad.sin() * (azimuth - aspect_rad).cos();
+        shade.set(x, y, intensity.clamp(0.0, 1.0));
+    }
+    shade
+}
+
+/// Distance label in km, or meters below one kilometer
+fn format_distance(km: f64) -> String {
+    if km >= 1.0 || km == 0.0 {
+        format!("{} km", km)
+    } else {
+        format!("{} m", (km * 1000.0).round())
+    }
+}
+
+/// Outline color and line thickness for a detected weather pattern
+fn weather_pattern_style(pattern_type: &WeatherPatternType) -> (Color, f32) {
+    match pattern_type {
+        WeatherPatternType::LowPressureSystem => (RED, 3.0),
+        WeatherPatternType::HighPressureSystem => (BLUE, 3.0),
+        WeatherPatternType::WindShear => (YELLOW, 2.0),
+        WeatherPatternType::Calm => (GREEN, 1.0),
+    }
+}
+
+fn interpolate_stops(stops: &[(f32, f32, f32, f32)], t: f32) -> Color {
+    for pair in stops.windows(2) {
+        let (p0, r0, g0, b0) = pair[0];
+        let (p1, r1, g1, b1) = pair[1];
+        if t <= p1 {
+            let f = if p1 > p0 { (t - p0) / (p1 - p0) } else { 0.0 };
+            return Color::new(
+                r0 + (r1 - r0) * f,
+                g0 + (g1 - g0) * f,
+                b0 + (b1 - b0) * f,
+                1.0,
+            );
+        }
+    }
+    let (_, r, g, b) = stops[stops.len() - 1];
+    Color::new(r, g, b, 1.0)
+}
+
 impl GraphicsRenderer {
     pub fn new(width: f32, height: f32) -> Self {
         // Calculate bounded viewport area (central map area minus UI sidebars)
@@ -63,6 +302,76 @@ impl GraphicsRenderer {
             pan_offset: Vec2::ZERO,
             simulation_paused: false,
             last_sim_tick: Instant::now(),
+            color_schemes: HashMap::new(),
+            value_ranges: HashMap::new(),
+            show_legend_bar: false,
+            show_scale_bar: false,
+            show_km_grid: false,
+            show_hillshade: false,
+            hillshade_tint: true,
+        }
+    }
+
+    /// Draw the elevation layer with relief shading instead of flat colors
+    pub fn set_hillshade(&mut self, visible: bool) {
+        self.show_hillshade = visible;
+    }
+
+    /// Blend hillshading with the elevation colors rather than plain grayscale
+    pub fn set_hillshade_tint(&mut self, tint: bool) {
+        self.hillshade_tint = tint;
+    }
+
+    /// Show a scale bar in the lower-left corner of the map viewport
+    pub fn set_scale_bar(&mut self, visible: bool) {
+        self.show_scale_bar = visible;
+    }
+
+    /// Overlay a kilometer grid spaced at the scale bar's round distance
+    pub fn set_km_grid(&mut self, visible: bool) {
+        self.show_km_grid = visible;
+    }
+
+    /// Replace a layer's built-in coloring with a continuous color scheme
+    pub fn set_color_scheme(&mut self, layer: DisplayMode, scheme: ColorScheme) {
+        self.color_schemes.insert(layer, scheme);
+    }
+
+    /// Restore a layer's built-in coloring
+    pub fn clear_color_scheme(&mut self, layer: DisplayMode) {
+        self.color_schemes.remove(&layer);
+    }
+
+    /// Fix the (min, max) used to normalize a layer instead of the data range
+    pub fn set_value_range(&mut self, layer: DisplayMode, range: Option<(f32, f32)>) {
+        match range {
+            Some(range) => self.value_ranges.insert(layer, range),
+            None => self.value_ranges.remove(&layer),
+        };
+    }
+
+    /// Show a gradient legend bar for the current layer in the right sidebar
+    pub fn set_legend_bar(&mut self, visible: bool) {
+        self.show_legend_bar = visible;
+    }
+
+    /// Apply `color_scheme` and `value_range` from framebuffer layer settings
+    /// Unknown layer or scheme names are ignored
+    pub fn apply_layer_settings(&mut self, settings: &HashMap<String, LayerSettings>) {
+        for (name, layer_settings) in settings {
+            let Some(layer) = DisplayMode::from_layer_name(name) else {
+                continue;
+            };
+            if let Some(scheme) = layer_settings
+                .color_scheme
+                .as_deref()
+                .and_then(ColorScheme::from_name)
+            {
+                self.set_color_scheme(layer, scheme);
+            }
+            if let Some((min, max)) = layer_settings.value_range {
+                self.set_value_range(layer, Some((min as f32, max as f32)));
+            }
         }
     }
 
@@ -73,6 +382,11 @@ impl GraphicsRenderer {
         set_default_camera();
 
         match self.display_mode {
+            DisplayMode::Elevation if self.show_hillshade => self.render_hillshade(
+                simulation.get_heightmap(),
+                HILLSHADE_SUN_AZIMUTH,
+                HILLSHADE_SUN_ALTITUDE,
+            ),
             DisplayMode::Elevation => self.render_elevation(simulation),
             DisplayMode::Water => self.render_water(simulation),
             DisplayMode::Pressure => self.render_pressure_field(simulation),
@@ -82,9 +396,246 @@ impl GraphicsRenderer {
             DisplayMode::Biomes => self.render_biomes(simulation),
         }
 
+        if self.show_km_grid {
+            self.render_km_grid(simulation);
+        }
+        if self.show_scale_bar {
+            self.render_scale_bar(simulation);
+        }
+
         self.render_ui(simulation);
     }
 
+    /// Off-screen raster of a layer with one color per cell, row-major in screen order
+    ///
+    /// Uses the same color schemes, value ranges and row orientation as the on-screen
+    /// renderer but needs no window. Wind is colored by speed; weather outlines each
+    /// detected pattern over the pressure field, in place of the on-screen wind arrows.
+    pub fn layer_pixels(&self, simulation: &Simulation, mode: DisplayMode) -> Vec<Color> {
+        let width = simulation.get_width();
+        let height = simulation.get_height();
+        let mut pixels = Vec::with_capacity(width * height);
+
+        match mode {
+            DisplayMode::Pressure => {
+                let pressure_layer = simulation.get_atmospheric_pressure_layer();
+                let range = self.normalization_range(
+                    DisplayMode::Pressure,
+                    self.find_pressure_range(pressure_layer),
+                );
+                // Pressure is drawn with the first row at the bottom of the map
+                for y in (0..height).rev() {
+                    for x in 0..width {
+                        let pressure = pressure_layer.get_pressure(x, y);
+                        pixels.push(self.layer_color(DisplayMode::Pressure, pressure, range));
+                    }
+                }
+            }
+            DisplayMode::Temperature => {
+                let temperature_layer = simulation.get_temperature_layer();
+                let range = self.normalization_range(
+                    DisplayMode::Temperature,
+                    self.find_temperature_range(temperature_layer),
+                );
+                for y in (0..height).rev() {
+                    for x in 0..width {
+                        let temperature = temperature_layer.get_temperature(x, y);
+                        pixels.push(self.layer_color(DisplayMode::Temperature, temperature, range));
+                    }
+                }
+            }
+            DisplayMode::Wind => {
+                let velocity = &simulation.get_wind_layer().velocity;
+                for y in 0..height {
+                    for x in 0..width {
+                        pixels.push(self.wind_speed_to_color(velocity.get(x, y).magnitude()));
+                    }
+                }
+            }
+            DisplayMode::Water => {
+                let water_layer = simulation.get_water_layer();
+                let render_max_depth =
+                    (simulation.get_water_system().effective_rainfall_rate * 100.0).max(0.001);
+                let depth_range =
+                    self.normalization_range(DisplayMode::Water, (0.0, render_max_depth));
+                let elevation_range = self.normalization_range(DisplayMode::Elevation, (0.0, 1.0));
+                for y in 0..height {
+                    for x in 0..width {
+                        let base = self.layer_color(
+                            DisplayMode::Elevation,
+                            simulation.get_elevation(x, y),
+                            elevation_range,
+                        );
+                        let depth = water_layer.get_water_depth(x, y);
+                        // Same opacity ramp as the on-screen water overlay
+                        let alpha = (depth / render_max_depth).clamp(0.0, 1.0) * 200.0 / 255.0;
+                        let water = self.layer_color(DisplayMode::Water, depth, depth_range);
+                        pixels.push(Color::new(
+                            base.r + (water.r - base.r) * alpha,
+                            base.g + (water.g - base.g) * alpha,
+                            base.b + (water.b - base.b) * alpha,
+                            1.0,
+                        ));
+                    }
+                }
+            }
+            DisplayMode::Weather => {
+                let pressure_layer = simulation.get_atmospheric_pressure_layer();
+                let range = self.normalization_range(
+                    DisplayMode::Pressure,
+                    self.find_pressure_range(pressure_layer),
+                );
+                let mut cells: Vec<Color> = (0..height)
+                    .flat_map(|y| (0..width).map(move |x| (x, y)))
+                    .map(|(x, y)| {
+                        let pressure = pressure_layer.get_pressure(x, y);
+                        self.layer_color(DisplayMode::Pressure, pressure, range)
+                    })
+                    .collect();
+                for pattern in &simulation.get_weather_analysis().patterns {
+                    let (color, _) = weather_pattern_style(&pattern.pattern_type);
+                    let (cx, cy) = (pattern.center.0 as f32, pattern.center.1 as f32);
+                    for y in 0..height {
+                        for x in 0..width {
+                            let distance = (x as f32 - cx).hypot(y as f32 - cy);
+                            // Pattern boundary ring plus a center marker, one cell thick
+                            if (distance - pattern.radius as f32).abs() < 0.5 || distance < 1.0 {
+                                cells[y * width + x] = color;
+                            }
+                        }
+                    }
+                }
+                // Same bottom-up row order as the pressure background
+                for y in (0..height).rev() {
+                    pixels.extend_from_slice(&cells[y * width..(y + 1) * width]);
+                }
+            }
+            DisplayMode::Biomes => {
+                let biome_map = simulation.generate_biome_map_basic();
+                for y in (0..height).rev() {
+                    for x in 0..width {
+                        let (r, g, b) = biome_map.get(x, y).display_color();
+                        pixels.push(Color::new(
+                            r as f32 / 255.0,
+                            g as f32 / 255.0,
+                            b as f32 / 255.0,
+                            1.0,
+                        ));
+                    }
+                }
+            }
+            DisplayMode::Elevation => {
+                let range = self.normalization_range(DisplayMode::Elevation, (0.0, 1.0));
+                for y in 0..height {
+                    for x in 0..width {
+                        let elevation = simulation.get_elevation(x, y);
+                        pixels.push(self.layer_color(DisplayMode::Elevation, elevation, range));
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+
+    /// Screen origin (x, y) of the map and the on-screen size of one cell
+    fn map_layout(&self, simulation: &Simulation) -> (f32, f32, f32) {
+        let cell_size = self.calculate_cell_size(simulation.get_width(), simulation.get_height());
+        let total_width = simulation.get_width() as f32 * cell_size;
+        let total_height = simulation.get_height() as f32 * cell_size;
+        let offset_x = self.viewport.x + (self.viewport.w - total_width) * 0.5 + self.pan_offset.x;
+        let offset_y = self.viewport.y + (self.viewport.h - total_height) * 0.5 + self.pan_offset.y;
+        (offset_x, offset_y, cell_size)
+    }
+
+    /// Round scale-bar distance (km) and its length in pixels at the current zoom
+    fn scale_bar_for(&self, meters_per_cell: f64, width: usize, height: usize) -> (f64, f32) {
+        scale_bar_length(
+            meters_per_cell,
+            self.calculate_cell_size(width, height),
+            SCALE_BAR_MAX_WIDTH,
+        )
+    }
+
+    fn render_scale_bar(&self, simulation: &Simulation) {
+        let meters_per_cell = simulation.get_world_scale().meters_per_pixel_x();
+        let (distance_km, length_px) = self.scale_bar_for(
+            meters_per_cell,
+            simulation.get_width(),
+            simulation.get_height(),
+        );
+        if length_px <= 0.0 {
+            return;
+        }
+
+        let bar_x = self.viewport.x + 12.0;
+        let bar_y = self.viewport.y + self.viewport.h - 16.0;
+        draw_rectangle(
+            bar_x - 6.0,
+            bar_y - 20.0,
+            length_px + 12.0,
+            28.0,
+            Color::new(0.0, 0.0, 0.0, 0.6),
+        );
+        draw_line(bar_x, bar_y, bar_x + length_px, bar_y, 2.0, WHITE);
+        draw_line(bar_x, bar_y - 5.0, bar_x, bar_y + 3.0, 2.0, WHITE);
+        draw_line(
+            bar_x + length_px,
+            bar_y - 5.0,
+            bar_x + length_px,
+            bar_y + 3.0,
+            2.0,
+            WHITE,
+        );
+        draw_text(
+            &format_distance(distance_km),
+            bar_x,
+            bar_y - 8.0,
+            14.0,
+            WHITE,
+        );
+    }
+
+    fn render_km_grid(&self, simulation: &Simulation) {
+        let scale = simulation.get_world_scale();
+        let (offset_x, offset_y, cell_size) = self.map_layout(simulation);
+        let (spacing_km, _) = self.scale_bar_for(
+            scale.meters_per_pixel_x(),
+            simulation.get_width(),
+            simulation.get_height(),
+        );
+        if spacing_km <= 0.0 {
+            return;
+        }
+
+        let width_px = simulation.get_width() as f32 * cell_size;
+        let height_px = simulation.get_height() as f32 * cell_size;
+        let px_per_km_x = cell_size as f64 * 1000.0 / scale.meters_per_pixel_x();
+        let px_per_km_y = cell_size as f64 * 1000.0 / scale.meters_per_pixel_y();
+        let grid_color = Color::new(1.0, 1.0, 1.0, 0.25);
+
+        let mut line_km = 0.0;
+        while line_km * px_per_km_x <= width_px as f64 {
+            let x = offset_x + (line_km * px_per_km_x) as f32;
+            draw_line(x, offset_y, x, offset_y + height_px, 1.0, grid_color);
+            draw_text(
+                &format_distance(line_km),
+                x + 2.0,
+                offset_y + 12.0,
+                12.0,
+                LIGHTGRAY,
+            );
+            line_km += spacing_km;
+        }
+
+        let mut line_km = 0.0;
+        while line_km * px_per_km_y <= height_px as f64 {
+            let y = offset_y + (line_km * px_per_km_y) as f32;
+            draw_line(offset_x, y, offset_x + width_px, y, 1.0, grid_color);
+            line_km += spacing_km;
+        }
+    }
+
     fn render_elevation(&self, simulation: &Simulation) {
         let cell_size = self.calculate_cell_size(simulation.get_width(), simulation.get_height());
 
@@ -94,10 +645,12 @@ impl GraphicsRenderer {
         let offset_x = self.viewport.x + (self.viewport.w - total_width) * 0.5 + self.pan_offset.x;
         let offset_y = self.viewport.y + (self.viewport.h - total_height) * 0.5 + self.pan_offset.y;
 
+        let elevation_range = self.normalization_range(DisplayMode::Elevation, (0.0, 1.0));
+
         for y in 0..simulation.get_height() {
             for x in 0..simulation.get_width() {
                 let elevation = simulation.get_elevation(x, y);
-                let color = self.elevation_to_color(elevation);
+                let color = self.layer_color(DisplayMode::Elevation, elevation, elevation_range);
 
                 let world_x = offset_x + x as f32 * cell_size;
                 let world_y = offset_y + y as f32 * cell_size;
@@ -107,6 +660,36 @@ impl GraphicsRenderer {
         }
     }
 
+    /// Draw relief-shaded terrain lit from the given sun azimuth and altitude (degrees)
+    pub fn render_hillshade(&self, heightmap: &HeightMap, sun_azimuth: f32, sun_altitude: f32) {
+        let cell_size = self.calculate_cell_size(heightmap.width(), heightmap.height());
+        let total_width = heightmap.width() as f32 * cell_size;
+        let total_height = heightmap.height() as f32 * cell_size;
+        let offset_x = self.viewport.x + (self.viewport.w - total_width) * 0.5 + self.pan_offset.x;
+        let offset_y = self.viewport.y + (self.viewport.h - total_height) * 0.5 + self.pan_offset.y;
+
+        // Normalized elevations: one unit of relief spans the map width before exaggeration
+        let cell_spacing = 1.0
+            / (heightmap.width().max(heightmap.height()).max(1) as f32 * HILLSHADE_EXAGGERATION);
+        let shade = hillshade(heightmap, cell_spacing, sun_azimuth, sun_altitude);
+        let elevation_range = self.normalization_range(DisplayMode::Elevation, (0.0, 1.0));
+
+        for (x, y, &intensity) in shade.iter_coords() {
+            let color = if self.hillshade_tint {
+                let tint =
+                    self.layer_color(DisplayMode::Elevation, heightmap.get(x, y), elevation_range);
+                let light = HILLSHADE_AMBIENT + (1.0 - HILLSHADE_AMBIENT) * intensity;
+                Color::new(tint.r * light, tint.g * light, tint.b * light, 1.0)
+            } else {
+                Color::new(intensity, intensity, intensity, 1.0)
+            };
+
+            let world_x = offset_x + x as f32 * cell_size;
+            let world_y = offset_y + y as f32 * cell_size;
+            draw_rectangle(world_x, world_y, cell_size, cell_size, color);
+        }
+    }
+
     fn render_water(&self, simulation: &Simulation) {
         // Render elevation as base
         self.render_elevation(simulation);
@@ -118,6 +701,7 @@ impl GraphicsRenderer {
 
         // Scale-aware rendering threshold (same approach as ASCII framebuffer)
         let render_max_depth = (water_system.effective_rainfall_rate * 100.0).max(0.001);
+        let depth_range = self.normalization_range(DisplayMode::Water, (0.0, render_max_depth));
 
         // Center the simulation data in the viewport (same as elevation mode)
         let total_width = water_layer.width() as f32 * cell_size;
@@ -134,7 +718,9 @@ impl GraphicsRenderer {
                     let alpha = (normalized_alpha * 200.0) as u8;
                     
                     if alpha > 0 { // Only draw if visible
-                        let water_color = Color::new(0.0, 0.4, 0.8, alpha as f32 / 255.0);
+                        let mut water_color =
+                            self.layer_color(DisplayMode::Water, water_depth, depth_range);
+                        water_color.a = alpha as f32 / 255.0;
 
                         let world_x = offset_x + x as f32 * cell_size;
                         let world_y = offset_y + (water_layer.height() - 1 - y) as f32 * cell_size;
@@ -156,14 +742,17 @@ impl GraphicsRenderer {
         let offset_y = self.viewport.y + (self.viewport.h - total_height) * 0.5 + self.pan_offset.y;
 
         // Find pressure range for color mapping
-        let (min_pressure, max_pressure) = self.find_pressure_range(pressure_layer);
+        let pressure_range = self.normalization_range(
+            DisplayMode::Pressure,
+            self.find_pressure_range(pressure_layer),
+        );
 
         // Pressure range found for color mapping
 
         for y in 0..simulation.get_height() {
             for x in 0..simulation.get_width() {
                 let pressure = pressure_layer.get_pressure(x, y);
-                let color = self.pressure_to_color(pressure, min_pressure, max_pressure);
+                let color = self.layer_color(DisplayMode::Pressure, pressure, pressure_range);
 
                 let world_x = offset_x + x as f32 * cell_size;
                 let world_y = offset_y + (simulation.get_height() - 1 - y) as f32 * cell_size;
@@ -277,12 +866,16 @@ impl GraphicsRenderer {
         let offset_y = self.viewport.y + (self.viewport.h - total_height) * 0.5 + self.pan_offset.y;
 
         // Find temperature range for color mapping
-        let (min_temp, max_temp) = self.find_temperature_range(temperature_layer);
+        let temperature_range = self.normalization_range(
+            DisplayMode::Temperature,
+            self.find_temperature_range(temperature_layer),
+        );
 
         for y in 0..simulation.get_height() {
             for x in 0..simulation.get_width() {
                 let temperature = temperature_layer.get_temperature(x, y);
-                let color = self.temperature_to_color(temperature, min_temp, max_temp);
+                let color =
+                    self.layer_color(DisplayMode::Temperature, temperature, temperature_range);
 
                 let world_x = offset_x + x as f32 * cell_size;
                 let world_y = offset_y + (simulation.get_height() - 1 - y) as f32 * cell_size;
@@ -333,12 +926,7 @@ impl GraphicsRenderer {
         let center_y = offset_y + (height - 1 - pattern.center.1) as f32 * cell_size;
         let radius = pattern.radius as f32 * cell_size;
 
-        let (color, thickness) = match pattern.pattern_type {
-            WeatherPatternType::LowPressureSystem => (RED, 3.0),
-            WeatherPatternType::HighPressureSystem => (BLUE, 3.0),
-            WeatherPatternType::WindShear => (YELLOW, 2.0),
-            WeatherPatternType::Calm => (GREEN, 1.0),
-        };
+        let (color, thickness) = weather_pattern_style(&pattern.pattern_type);
 
         // Draw pattern boundary
         draw_circle_lines(center_x, center_y, radius, thickness, color);
@@ -385,7 +973,7 @@ impl GraphicsRenderer {
         self.render_left_sidebar(simulation);
 
         // Right sidebar content - Color legends
-        self.render_right_sidebar();
+        self.render_right_sidebar(simulation);
 
         // Top bar content - Mode indicator, time, simulation state
         self.render_top_bar(simulation);
@@ -453,11 +1041,122 @@ impl GraphicsRenderer {
         let meters_per_pixel = (scale_km * 1000.0) / width.max(height);
         let resolution_detail = format!("Resolution: {:.0}m/pixel", meters_per_pixel);
         draw_text(&resolution_detail, sidebar_x, y_pos, 12.0, DARKGRAY);
+        y_pos += line_height * 2.0;
+
+        self.render_coordinate_readout(simulation, sidebar_x, y_pos);
     }
 
-    fn render_right_sidebar(&self) {
+    /// Map position under the mouse in km from the top-left corner, plus lat/long if placed
+    fn render_coordinate_readout(&self, simulation: &Simulation, x: f32, mut y_pos: f32) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let (offset_x, offset_y, cell_size) = self.map_layout(simulation);
+        let cell_x = (mouse_x - offset_x) / cell_size;
+        let cell_y = (mouse_y - offset_y) / cell_size;
+        if cell_x < 0.0
+            || cell_y < 0.0
+            || cell_x >= simulation.get_width() as f32
+            || cell_y >= simulation.get_height() as f32
+        {
+            return;
+        }
+
+        let scale = simulation.get_world_scale();
+        let east_km = cell_x as f64 * scale.meters_per_pixel_x() / 1000.0;
+        let south_km = cell_y as f64 * scale.meters_per_pixel_y() / 1000.0;
+
+        draw_text("CURSOR", x, y_pos, 16.0, WHITE);
+        y_pos += 24.0;
+        draw_text(
+            &format!("{:.1} km E, {:.1} km S", east_km, south_km),
+            x,
+            y_pos,
+            12.0,
+            LIGHTGRAY,
+        );
+
+        if let Some((center_lat, center_lon)) = scale.geographic_center {
+            let width_km = simulation.get_width() as f64 * scale.meters_per_pixel_x() / 1000.0;
+            let height_km = simulation.get_height() as f64 * scale.meters_per_pixel_y() / 1000.0;
+            let latitude = center_lat - (south_km - height_km * 0.5) / KM_PER_DEGREE;
+            let longitude = center_lon
+                + (east_km - width_km * 0.5)
+                    / (KM_PER_DEGREE * latitude.to_radians().cos().max(1e-6));
+            y_pos += 18.0;
+            draw_text(
+                &format!("{:.3}°, {:.3}°", latitude, longitude),
+                x,
+                y_pos,
+                12.0,
+                LIGHTGRAY,
+            );
+        }
+    }
+
+    fn render_right_sidebar(&self, simulation: &Simulation) {
         // Color legend in right sidebar
-        self.render_color_legend();
+        self.render_color_legend(simulation);
+
+        if self.show_legend_bar {
+            self.render_legend_bar(simulation);
+        }
+    }
+
+    fn render_legend_bar(&self, simulation: &Simulation) {
+        let Some(range) = self.current_layer_range(simulation) else {
+            return;
+        };
+        let (min_value, max_value) = range;
+
+        let bar_x = screen_width() - RIGHT_SIDEBAR_WIDTH + 10.0;
+        let bar_y = screen_height() - BOTTOM_BAR_HEIGHT - 40.0;
+        let bar_width = RIGHT_SIDEBAR_WIDTH - 20.0;
+        let step_width = bar_width / LEGEND_BAR_STEPS as f32;
+
+        for step in 0..LEGEND_BAR_STEPS {
+            let t = (step as f32 + 0.5) / LEGEND_BAR_STEPS as f32;
+            let value = min_value + (max_value - min_value) * t;
+            let mut color = self.layer_color(self.display_mode, value, range);
+            color.a = 1.0;
+            draw_rectangle(
+                bar_x + step as f32 * step_width,
+                bar_y,
+                step_width + 0.5,
+                LEGEND_BAR_HEIGHT,
+                color,
+            );
+        }
+        draw_rectangle_lines(bar_x, bar_y, bar_width, LEGEND_BAR_HEIGHT, 1.0, LIGHTGRAY);
+
+        let label_y = bar_y + LEGEND_BAR_HEIGHT + 14.0;
+        draw_text(&format!("{:.1}", min_value), bar_x, label_y, 12.0, WHITE);
+        let max_label = format!("{:.1}", max_value);
+        let max_label_width = measure_text(&max_label, None, 12, 1.0).width;
+        draw_text(
+            &max_label,
+            bar_x + bar_width - max_label_width,
+            label_y,
+            12.0,
+            WHITE,
+        );
+    }
+
+    /// Normalization range of the current layer, or None for non-scalar layers
+    fn current_layer_range(&self, simulation: &Simulation) -> Option<(f32, f32)> {
+        let data_range = match self.display_mode {
+            DisplayMode::Elevation => (0.0, 1.0),
+            DisplayMode::Water => {
+                let rainfall = simulation.get_water_system().effective_rainfall_rate;
+                (0.0, (rainfall * 100.0).max(0.001))
+            }
+            DisplayMode::Pressure => {
+                self.find_pressure_range(simulation.get_atmospheric_pressure_layer())
+            }
+            DisplayMode::Temperature => {
+                self.find_temperature_range(simulation.get_temperature_layer())
+            }
+            DisplayMode::Wind | DisplayMode::Weather | DisplayMode::Biomes => return None,
+        };
+        Some(self.normalization_range(self.display_mode, data_range))
     }
 
     fn render_top_bar(&self, simulation: &Simulation) {
@@ -512,7 +1211,7 @@ impl GraphicsRenderer {
 
         // Control instructions
         draw_text(
-            "WASD: Pan, Mouse Wheel: Zoom, R: Reset, SPACE: Pause/Play, 1-7: Display Mode, ESC: Quit",
+            "WASD: Pan, Mouse Wheel: Zoom, R: Reset, L: Legend Bar, B: Scale Bar, G: Grid, H: Hillshade, SPACE: Pause/Play, 1-7: Display Mode, ESC: Quit",
             instructions_x,
             bar_y,
             14.0,
@@ -520,7 +1219,7 @@ impl GraphicsRenderer {
         );
     }
 
-    fn render_color_legend(&self) {
+    fn render_color_legend(&self, simulation: &Simulation) {
         let legend_x = screen_width() - RIGHT_SIDEBAR_WIDTH + 10.0;
         let mut legend_y = 50.0;
         let legend_spacing = 20.0;
@@ -644,21 +1343,14 @@ impl GraphicsRenderer {
                 draw_text("Biomes:", legend_x, legend_y, 14.0, LIGHTGRAY);
                 legend_y += legend_spacing;
 
-                // Show common biome types with their colors
-                let biome_entries = [
-                    (BiomeType::Ocean, "Ocean"),
-                    (BiomeType::Lake, "Lake"),
-                    (BiomeType::Grassland, "Grassland"),
-                    (BiomeType::TemperateForest, "Forest"),
-                    (BiomeType::Desert, "Desert"),
-                    (BiomeType::Tundra, "Tundra"),
-                ];
-
-                for (biome_type, label) in biome_entries.iter() {
+                // Show biomes present on the map with their share of cells
+                let biome_map = simulation.generate_biome_map_basic();
+                for (biome_type, _, percent) in biome_map.biome_percentages() {
                     let (r, g, b) = biome_type.display_color();
                     let color =
                         Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0);
-                    self.draw_legend_item(legend_x, legend_y, color, label, 12.0);
+                    let label = format!("{} {:.1}%", biome_type.display_name(), percent);
+                    self.draw_legend_item(legend_x, legend_y, color, &label, 12.0);
                     legend_y += legend_spacing;
                 }
             }
@@ -673,6 +1365,35 @@ impl GraphicsRenderer {
     }
 
     // Helper methods for color mapping
+
+    /// Configured value range for a layer, falling back to the observed data range
+    fn normalization_range(&self, layer: DisplayMode, data_range: (f32, f32)) -> (f32, f32) {
+        self.value_ranges.get(&layer).copied().unwrap_or(data_range)
+    }
+
+    /// Color for a scalar layer value, using the layer's scheme if one is set
+    fn layer_color(&self, layer: DisplayMode, value: f32, range: (f32, f32)) -> Color {
+        let (min_value, max_value) = range;
+        let normalized = if (max_value - min_value).abs() > f32::EPSILON {
+            (value - min_value) / (max_value - min_value)
+        } else {
+            0.5
+        };
+
+        match self.color_schemes.get(&layer) {
+            Some(ColorScheme::Thermal) => return thermal_color(value, min_value, max_value),
+            Some(scheme) => return scheme.color_at(normalized),
+            None => {}
+        }
+
+        match layer {
+            DisplayMode::Elevation => self.elevation_to_color(normalized),
+            DisplayMode::Pressure => self.pressure_to_color(value, min_value, max_value),
+            DisplayMode::Temperature => self.temperature_to_color(value, min_value, max_value),
+            _ => Color::new(0.0, 0.4, 0.8, 1.0), // Water blue
+        }
+    }
+
     fn elevation_to_color(&self, elevation: f32) -> Color {
         match elevation {
             e if e < 0.2 => BLUE,    // Water
@@ -833,7 +1554,20 @@ impl GraphicsRenderer {
             self.simulation_paused = !self.simulation_paused;
         }
 
-        // Reset zoom and center view
+        // Overlay toggles
+        if is_key_pressed(KeyCode::L) {
+            self.show_legend_bar = !self.show_legend_bar;
+        }
+        if is_key_pressed(KeyCode::B) {
+            self.show_scale_bar = !self.show_scale_bar;
+        }
+        if is_key_pressed(KeyCode::G) {
+            self.show_km_grid = !self.show_km_grid;
+        }
+        if is_key_pressed(KeyCode::H) {
+            self.show_hillshade = !self.show_hillshade;
+        }
+
         if is_key_pressed(KeyCode::R) {
             self.zoom_level = 1.0;
             self.pan_offset = Vec2::new(0.0, 0.0);
@@ -904,3 +1638,136 @@ impl GraphicsRenderer {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blue_red_midpoint_maps_to_neutral() {
+        let mut renderer = GraphicsRenderer::new(800.0, 600.0);
+        renderer.set_color_scheme(DisplayMode::Temperature, ColorScheme::BlueRed);
+        renderer.set_value_range(DisplayMode::Temperature, Some((-20.0, 20.0)));
+
+        let range = renderer.normalization_range(DisplayMode::Temperature, (5.0, 35.0));
+        assert_eq!(range, (-20.0, 20.0));
+
+        let neutral = renderer.layer_color(DisplayMode::Temperature, 0.0, range);
+        assert_eq!(neutral, Color::new(1.0, 1.0, 1.0, 1.0));
+        assert_eq!(
+            ColorScheme::BlueRed.color_at(0.0),
+            Color::new(0.0, 0.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            ColorScheme::BlueRed.color_at(1.0),
+            Color::new(1.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn scale_bar_snaps_to_round_km_at_zoom() {
+        // 2 km cells on a 100x100 map: 440x530 viewport gives 4.4 px per cell
+        let mut renderer = GraphicsRenderer::new(800.0, 600.0);
+        let (km, px) = renderer.scale_bar_for(2000.0, 100, 100);
+        assert_eq!(km, 50.0);
+        assert!((px - 110.0).abs() < 1e-3);
+
+        // Zooming in 4x shrinks the round distance so the bar still fits
+        renderer.zoom_level = 4.0;
+        let (km, px) = renderer.scale_bar_for(2000.0, 100, 100);
+        assert_eq!(km, 10.0);
+        assert!((px - 88.0).abs() < 1e-3);
+        assert!(px <= SCALE_BAR_MAX_WIDTH);
+
+        // Sub-kilometer cells produce fractional km distances
+        let (km, px) = scale_bar_length(10.0, 2.0, 150.0);
+        assert!((km - 0.5).abs() < 1e-12);
+        assert!((px - 100.0).abs() < 1e-3);
+        assert_eq!(format_distance(km), "500 m");
+    }
+
+    #[test]
+    fn default_coloring_is_unchanged_without_scheme() {
+        let renderer = GraphicsRenderer::new(800.0, 600.0);
+
+        let elevation_range = renderer.normalization_range(DisplayMode::Elevation, (0.0, 1.0));
+        for elevation in [0.1, 0.3, 0.5, 0.7, 0.9] {
+            assert_eq!(
+                renderer.layer_color(DisplayMode::Elevation, elevation, elevation_range),
+                renderer.elevation_to_color(elevation)
+            );
+        }
+        assert_eq!(
+            renderer.layer_color(DisplayMode::Pressure, 101000.0, (100000.0, 102000.0)),
+            renderer.pressure_to_color(101000.0, 100000.0, 102000.0)
+        );
+        assert_eq!(
+            renderer.layer_color(DisplayMode::Temperature, 10.0, (0.0, 30.0)),
+            renderer.temperature_to_color(10.0, 0.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn hillshade_lights_slopes_facing_the_sun() {
+        // Ridge along x = 4: the west flank faces west, the east flank faces east
+        let ridge = HeightMap::from_nested(
+            (0..6)
+                .map(|_| (0..9).map(|x| 4.0 - (x as f32 - 4.0).abs()).collect())
+                .collect(),
+        );
+
+        // Morning sun low in the east
+        let shade = hillshade(&ridge, 1.0, 90.0, 30.0);
+        let west_flank = *shade.get(2, 3);
+        let east_flank = *shade.get(6, 3);
+        assert!(east_flank > west_flank);
+        assert!(west_flank >= 0.0 && east_flank <= 1.0);
+
+        // Flat ground is lit by the sun's altitude alone
+        let flat = hillshade(&HeightMap::new(3, 3, 0.5), 1.0, 90.0, 30.0);
+        assert!((flat.get(1, 1) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn thermal_scheme_breaks_hue_at_freezing() {
+        let mut renderer = GraphicsRenderer::new(800.0, 600.0);
+        renderer.set_color_scheme(DisplayMode::Temperature, ColorScheme::Thermal);
+        let range = (-10.0, 40.0);
+
+        let below = renderer.layer_color(DisplayMode::Temperature, -0.1, range);
+        let above = renderer.layer_color(DisplayMode::Temperature, 0.1, range);
+        // Blue-dominated just below freezing, red-dominated just above
+        assert!(below.b > below.r);
+        assert!(above.r > above.b);
+        assert!(above.r - below.r > 0.3);
+
+        assert_eq!(
+            ColorScheme::from_name("Thermal"),
+            Some(ColorScheme::Thermal)
+        );
+        assert_eq!(
+            ColorScheme::Thermal.color_at(0.49),
+            thermal_color(-0.02, -1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn biome_frames_use_biome_colors() {
+        let terrain: Vec<Vec<f32>> = (0..5)
+            .map(|y| (0..6).map(|x| 0.1 * x as f32 + 0.1 * y as f32).collect())
+            .collect();
+        let sim = Simulation::new(HeightMap::from_nested(terrain));
+        let renderer = GraphicsRenderer::new(800.0, 600.0);
+
+        let pixels = renderer.layer_pixels(&sim, DisplayMode::Biomes);
+        let biome_map = sim.generate_biome_map_basic();
+        // Bottom-up rows: the first pixel is the last map row
+        let (r, g, b) = biome_map.get(0, 4).display_color();
+        assert_eq!(pixels.len(), 30);
+        assert_eq!(
+            pixels[0],
+            Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 1.0)
+        );
+        assert_ne!(pixels, renderer.layer_pixels(&sim, DisplayMode::Elevation));
+    }
+}
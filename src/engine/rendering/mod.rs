@@ -6,6 +6,7 @@
 
 pub mod ansi_colors;
 pub mod ascii_framebuffer;
+pub mod frame_recorder;
 pub mod graphics_render;
 pub mod multi_viewport;
 pub mod render;
@@ -13,6 +14,9 @@ pub mod tui;
 
 // Re-export rendering functions
 pub use ascii_framebuffer::{AsciiFramebuffer, FramebufferConfig, VisualizationLayer};
-pub use graphics_render::GraphicsRenderer;
-pub use render::{ascii_render, ascii_render_biomes};
+pub use frame_recorder::FrameRecorder;
+pub use graphics_render::{ColorScheme, DisplayMode, GraphicsRenderer};
+pub use render::{
+    ascii_render, ascii_render_biome_legend, ascii_render_biomes, ascii_render_wind, wind_glyph,
+};
 pub use tui::run_tui;
@@ -26,6 +26,12 @@ pub struct MultiViewportConfig {
     pub show_status: bool,
     /// Active viewport index
     pub active_viewport: usize,
+    /// Pan all viewports together to the same world coordinates
+    pub link_cursors: bool,
+    /// Apply zoom changes to all viewports together
+    pub link_zoom: bool,
+    /// Shared view followed by linked viewports
+    pub shared_view: ViewTransform,
 }
 
 impl Default for MultiViewportConfig {
@@ -40,6 +46,27 @@ impl Default for MultiViewportConfig {
             layout: LayoutType::Grid2x2,
             show_status: true,
             active_viewport: 0,
+            link_cursors: false,
+            link_zoom: false,
+            shared_view: ViewTransform::default(),
+        }
+    }
+}
+
+/// World-space view shared by linked viewports
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ViewTransform {
+    /// World coordinate at the center of the view (x, y)
+    pub center: (i32, i32),
+    /// Zoom level of the view
+    pub zoom: ZoomLevel,
+}
+
+impl Default for ViewTransform {
+    fn default() -> Self {
+        Self {
+            center: (0, 0),
+            zoom: ZoomLevel::Continental,
         }
     }
 }
@@ -101,6 +128,31 @@ impl ZoomLevel {
             ZoomLevel::Local => "LOCA",
         }
     }
+
+    /// Next finer zoom level, wrapping back to continental
+    pub fn next(&self) -> Self {
+        match self {
+            ZoomLevel::Continental => ZoomLevel::Regional,
+            ZoomLevel::Regional => ZoomLevel::Local,
+            ZoomLevel::Local => ZoomLevel::Continental,
+        }
+    }
+}
+
+impl ViewportConfig {
+    /// World coordinate at the center of this viewport
+    pub fn world_center(&self) -> (i32, i32) {
+        (
+            self.viewport.world_x + self.viewport.view_width as i32 / 2,
+            self.viewport.world_y + self.viewport.view_height as i32 / 2,
+        )
+    }
+
+    /// Move this viewport so its center sits on a world coordinate (clamped at the origin)
+    pub fn center_on(&mut self, center: (i32, i32)) {
+        self.viewport.world_x = (center.0 - self.viewport.view_width as i32 / 2).max(0);
+        self.viewport.world_y = (center.1 - self.viewport.view_height as i32 / 2).max(0);
+    }
 }
 
 /// Multi-viewport renderer for ASCII framebuffer system
@@ -168,8 +220,83 @@ impl MultiViewportApp {
         self.renderer.config.active_viewport
     }
 
+    /// Link or unlink panning across viewports, aligning all to the active viewport
+    pub fn link_cursors(&mut self, linked: bool) {
+        self.renderer.config.link_cursors = linked;
+        if linked {
+            self.sync_shared_view();
+        }
+    }
+
+    /// Link or unlink zoom across viewports, adopting the active viewport's zoom
+    pub fn link_zoom(&mut self, linked: bool) {
+        self.renderer.config.link_zoom = linked;
+        if linked {
+            self.sync_shared_view();
+        }
+    }
+
+    /// Set the zoom level of the active viewport (all viewports when zoom is linked)
+    pub fn set_zoom_level(&mut self, zoom: ZoomLevel) -> bool {
+        let active_idx = self.renderer.config.active_viewport;
+        match self.renderer.config.viewports.get_mut(active_idx) {
+            Some(viewport_config) => viewport_config.zoom_level = zoom,
+            None => return false,
+        }
+        self.sync_shared_view();
+        true
+    }
+
+    /// Zoom level of the active viewport
+    pub fn active_zoom_level(&self) -> ZoomLevel {
+        self.renderer
+            .config
+            .viewports
+            .get(self.renderer.config.active_viewport)
+            .map_or(ZoomLevel::Continental, |viewport_config| {
+                viewport_config.zoom_level
+            })
+    }
+
+    /// World coordinate at the center of a viewport
+    pub fn viewport_world_center(&self, viewport_index: usize) -> Option<(i32, i32)> {
+        self.renderer
+            .config
+            .viewports
+            .get(viewport_index)
+            .map(|viewport_config| viewport_config.world_center())
+    }
+
+    /// Copy the active viewport into the shared view and push it to linked viewports
+    fn sync_shared_view(&mut self) {
+        let config = &mut self.renderer.config;
+        let Some(active) = config.viewports.get(config.active_viewport) else {
+            return;
+        };
+        config.shared_view = ViewTransform {
+            center: active.world_center(),
+            zoom: active.zoom_level,
+        };
+
+        let shared_view = config.shared_view;
+        for viewport_config in config.viewports.iter_mut() {
+            if config.link_cursors {
+                viewport_config.center_on(shared_view.center);
+            }
+            if config.link_zoom {
+                viewport_config.zoom_level = shared_view.zoom;
+            }
+        }
+    }
+
     /// Handle WASD navigation for active viewport
     pub fn handle_movement(&mut self, direction: MovementDirection, fast: bool) -> bool {
+        let moved = self.move_active_viewport(direction, fast);
+        self.sync_shared_view();
+        moved
+    }
+
+    fn move_active_viewport(&mut self, direction: MovementDirection, fast: bool) -> bool {
         let active_idx = self.renderer.config.active_viewport;
         if active_idx >= self.renderer.config.viewports.len() {
             return false;
@@ -228,6 +355,7 @@ impl MultiViewportApp {
             let viewport = &mut self.renderer.config.viewports[active_idx].viewport;
             viewport.world_x = x;
             viewport.world_y = y;
+            self.sync_shared_view();
             true
         } else {
             false
@@ -349,6 +477,11 @@ impl MultiViewportRenderer {
         self.config.active_viewport
     }
 
+    /// Whether viewports pan together
+    pub fn cursors_linked(&self) -> bool {
+        self.config.link_cursors
+    }
+
     /// Generate 2x2 grid layout areas
     pub fn generate_2x2_layout(&self, area: Rect) -> Vec<Rect> {
         // Reserve space for status panel if enabled
@@ -414,6 +547,10 @@ impl MultiViewportRenderer {
             Span::raw(": Navigate • "),
             Span::styled("Shift+WASD", Style::default().fg(Color::Yellow)),
             Span::raw(": Fast • "),
+            Span::styled("L", Style::default().fg(Color::Yellow)),
+            Span::raw(": Link • "),
+            Span::styled("Z", Style::default().fg(Color::Yellow)),
+            Span::raw(": Zoom • "),
             Span::styled("Q", Style::default().fg(Color::Yellow)),
             Span::raw("/"),
             Span::styled("Esc", Style::default().fg(Color::Yellow)),
@@ -435,6 +572,16 @@ impl MultiViewportRenderer {
                 format!("{}", self.config.viewports.len()),
                 Style::default().fg(Color::Cyan),
             ),
+            Span::raw(" • Linked: "),
+            Span::styled(
+                match (self.config.link_cursors, self.config.link_zoom) {
+                    (true, true) => "pan+zoom",
+                    (true, false) => "pan",
+                    (false, true) => "zoom",
+                    (false, false) => "off",
+                },
+                Style::default().fg(Color::Cyan),
+            ),
         ]);
 
         Paragraph::new(vec![keybinding_line1, status_line]).block(
@@ -1056,4 +1203,40 @@ mod tests {
             "Status panel should not be generated when disabled"
         );
     }
+
+    #[test]
+    fn test_linked_cursor_moves_all_viewports() {
+        use crate::engine::core::scale::{DetailLevel, WorldScale};
+        use crate::engine::physics::{DiamondSquareGenerator, TerrainGenerator};
+
+        let generator = DiamondSquareGenerator::new(42);
+        let heightmap = generator.generate(50, 50, &Default::default());
+        let scale = WorldScale::new(200.0, (50, 50), DetailLevel::Standard);
+        let simulation = Simulation::_new_with_scale(heightmap, scale);
+
+        let mut app = MultiViewportApp::new(simulation);
+        app.link_cursors(true);
+        app.link_zoom(true);
+
+        app.select_viewport(2);
+        app.set_active_viewport_position(4, 6);
+        app.handle_movement(MovementDirection::East, true);
+        app.set_zoom_level(ZoomLevel::Regional);
+
+        let expected = app.viewport_world_center(2).unwrap();
+        assert_eq!(app.renderer.config.shared_view.center, expected);
+        for viewport_idx in 0..app.renderer.viewport_count() {
+            assert_eq!(app.viewport_world_center(viewport_idx), Some(expected));
+            assert_eq!(
+                app.renderer.config.viewports[viewport_idx].zoom_level,
+                ZoomLevel::Regional
+            );
+        }
+
+        // Unlinked viewports stay put when another one pans
+        app.link_cursors(false);
+        app.handle_movement(MovementDirection::South, false);
+        assert_ne!(app.viewport_world_center(2), app.viewport_world_center(0));
+        assert_eq!(app.viewport_world_center(0), Some(expected));
+    }
 }
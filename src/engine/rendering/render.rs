@@ -5,16 +5,57 @@
 
 use super::super::agents::biome::BiomeMap;
 use crate::engine::Simulation;
+use crate::engine::physics::water::Vec2;
 use crossterm::{
     execute,
     style::{Color::Rgb, PrintStyledContent, Stylize},
 };
 use std::io::{Write, stdout};
 
+/// Wind speed (m/s) below which a cell is drawn as calm
+pub const WIND_GLYPH_MIN_SPEED: f32 = 1.0;
+
 pub fn ascii_render(sim: &Simulation) {
     ascii_render_elevation(sim);
 }
 
+/// Eight-way arrow for a wind vector, or None when slower than min_speed
+///
+/// Grid y grows downward on screen, so a negative y component blows north ('↑').
+pub fn wind_glyph(velocity: &Vec2, min_speed: f32) -> Option<char> {
+    if velocity.magnitude() < min_speed {
+        return None;
+    }
+
+    // Compass-style angle with north up: flip y into screen orientation
+    let angle_deg = (-velocity.y).atan2(velocity.x).to_degrees();
+    let sector = ((angle_deg + 360.0 + 22.5) / 45.0) as usize % 8;
+    Some(['→', '↗', '↑', '↖', '←', '↙', '↓', '↘'][sector])
+}
+
+/// Wind direction rendering: arrows colored by speed, calm cells as dots
+pub fn ascii_render_wind(sim: &Simulation) {
+    let mut stdout = stdout();
+    let wind = sim.get_wind_layer();
+
+    for y in 0..sim.heightmap.height() {
+        for x in 0..sim.heightmap.width() {
+            let velocity = wind.get_velocity(x, y);
+            let symbol = match wind_glyph(&velocity, WIND_GLYPH_MIN_SPEED) {
+                None => '.'.dark_grey(),
+                Some(glyph) => match velocity.magnitude() {
+                    s if s < 5.0 => glyph.cyan(),    // Light breeze
+                    s if s < 15.0 => glyph.green(),  // Moderate wind
+                    s if s < 25.0 => glyph.yellow(), // Strong wind
+                    _ => glyph.red(),                // Gale
+                },
+            };
+            let _ = execute!(stdout, PrintStyledContent(symbol));
+        }
+        let _ = writeln!(stdout);
+    }
+}
+
 /// Original elevation-based rendering (kept for compatibility)
 pub fn ascii_render_elevation(sim: &Simulation) {
     let mut stdout = stdout();
@@ -51,3 +92,36 @@ pub fn ascii_render_biomes(biome_map: &BiomeMap) {
         let _ = writeln!(stdout);
     }
 }
+
+/// Legend of each biome present with its glyph, color and share of the map
+pub fn ascii_render_biome_legend(biome_map: &BiomeMap) {
+    let mut stdout = stdout();
+
+    for (biome, count, percent) in biome_map.biome_percentages() {
+        let (r, g, b) = biome.display_color();
+        let symbol = biome.display_char().with(Rgb { r, g, b });
+        let _ = execute!(stdout, PrintStyledContent(symbol));
+        let _ = writeln!(
+            stdout,
+            " {:<17} {:>6.2}% ({} cells)",
+            biome.display_name(),
+            percent,
+            count
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wind_glyph_follows_screen_orientation() {
+        assert_eq!(wind_glyph(&Vec2::new(10.0, 0.0), 1.0), Some('→'));
+        // Northward is toward row 0, i.e. negative y
+        assert_eq!(wind_glyph(&Vec2::new(0.0, -10.0), 1.0), Some('↑'));
+        assert_eq!(wind_glyph(&Vec2::new(0.0, 10.0), 1.0), Some('↓'));
+        assert_eq!(wind_glyph(&Vec2::new(-5.0, 5.0), 1.0), Some('↙'));
+        assert_eq!(wind_glyph(&Vec2::new(0.3, 0.2), 1.0), None);
+    }
+}
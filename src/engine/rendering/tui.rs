@@ -13,17 +13,27 @@ use ratatui::{
     Frame, Terminal,
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
 };
+use std::collections::VecDeque;
 use std::io;
 use std::time::{Duration, Instant};
 
+use super::super::agents::biome::BiomeType;
+use super::super::core::physics_grid::Float;
 use super::super::physics::atmosphere::WeatherPatternType;
 use super::super::physics::water::{Vec2, WaterLayer};
 use crate::engine::Simulation;
 
+// Simulation speed limits for the tick-rate limiter (ticks per second)
+const DEFAULT_TARGET_TPS: f32 = 10.0;
+const MIN_TARGET_TPS: f32 = 0.5;
+const MAX_TARGET_TPS: f32 = 160.0;
+// Window over which the effective tick rate is measured
+const TPS_WINDOW: Duration = Duration::from_secs(2);
+
 /// Viewport for navigating the world map
 #[derive(Debug, Clone)]
 pub struct Viewport {
@@ -118,7 +128,7 @@ impl Viewport {
 
                 // Sample from water depth (with bounds checking)
                 if world_y < world_height && world_x < world_width {
-                    row.push(water_layer.depth[world_y][world_x]);
+                    row.push(water_layer.depth[world_y][world_x].to_f32());
                 } else {
                     row.push(0.0); // Default value for out-of-bounds
                 }
@@ -178,14 +188,55 @@ pub enum DisplayMode {
     Temperature, // Temperature field
 }
 
+/// Snapshot of every layer value at one map cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellInspection {
+    pub x: usize,
+    pub y: usize,
+    pub tick: u64,
+    pub elevation: f32,
+    pub temperature: f32,
+    pub pressure: f32,
+    pub wind: (f32, f32),
+    pub wind_speed: f32,
+    pub biome: BiomeType,
+}
+
+impl CellInspection {
+    /// Single-line summary for the inspection log
+    pub fn to_log_line(&self) -> String {
+        format!(
+            "tick {} ({}, {}): elev {:.3} temp {:.1}°C pressure {:.0} Pa wind ({:.2}, {:.2}) {:.2} m/s biome {:?}",
+            self.tick,
+            self.x,
+            self.y,
+            self.elevation,
+            self.temperature,
+            self.pressure,
+            self.wind.0,
+            self.wind.1,
+            self.wind_speed,
+            self.biome
+        )
+    }
+}
+
 pub struct TuiApp {
     pub simulation: Simulation,
     pub viewport: Viewport,
     pub should_quit: bool,
-    pub zoom_level: u32,           // 1 = 1:1, 2 = 1:2, 4 = 1:4, etc.
-    pub paused: bool,              // Whether simulation is paused
-    pub show_water: bool,          // Whether to visualize water layer (legacy)
-    pub display_mode: DisplayMode, // Current display overlay mode
+    pub zoom_level: u32,                     // 1 = 1:1, 2 = 1:2, 4 = 1:4, etc.
+    pub paused: bool,                        // Whether simulation is paused
+    pub show_water: bool,                    // Whether to visualize water layer (legacy)
+    pub display_mode: DisplayMode,           // Current display overlay mode
+    pub show_rivers: bool,                   // Draw the drainage network over any layer
+    pub inspect_mode: bool,                  // Arrow keys move the cursor instead of the viewport
+    pub cursor_offset: (i32, i32),           // Cursor offset from viewport center (view cells)
+    pub target_tps: f32,                     // Simulation ticks per second while running
+    pub inspection_log: Vec<CellInspection>, // Values copied with the log key
+    last_inspection: Option<CellInspection>, // Last inspection, reused until a tick or move
+    last_tick: Instant,                      // When the limiter last advanced the simulation
+    recent_ticks: VecDeque<Instant>,         // Tick times inside the TPS measurement window
 }
 
 impl TuiApp {
@@ -201,7 +252,122 @@ impl TuiApp {
             paused: false,
             show_water: false,
             display_mode: DisplayMode::Terrain,
+            show_rivers: false,
+            inspect_mode: false,
+            cursor_offset: (0, 0),
+            target_tps: DEFAULT_TARGET_TPS,
+            inspection_log: Vec::new(),
+            last_inspection: None,
+            last_tick: Instant::now(),
+            recent_ticks: VecDeque::new(),
+        }
+    }
+
+    /// Tick the simulation if running and the target tick interval has elapsed
+    pub fn advance_simulation(&mut self, now: Instant) -> bool {
+        if self.paused || now.duration_since(self.last_tick) < self.tick_interval() {
+            return false;
         }
+        self.step_simulation(now);
+        true
+    }
+
+    /// Time until the limiter wants the next tick (None while paused)
+    pub fn time_until_next_tick(&self, now: Instant) -> Option<Duration> {
+        if self.paused {
+            return None;
+        }
+        Some(
+            self.tick_interval()
+                .saturating_sub(now.duration_since(self.last_tick)),
+        )
+    }
+
+    /// Ticks per second actually achieved over the recent measurement window
+    pub fn effective_tps(&self, now: Instant) -> f32 {
+        let recent = self
+            .recent_ticks
+            .iter()
+            .filter(|tick| now.duration_since(**tick) <= TPS_WINDOW)
+            .count();
+        recent as f32 / TPS_WINDOW.as_secs_f32()
+    }
+
+    fn tick_interval(&self) -> Duration {
+        Duration::from_secs_f32(1.0 / self.target_tps)
+    }
+
+    fn step_simulation(&mut self, now: Instant) {
+        self.simulation.tick();
+        self.last_tick = now;
+        self.recent_ticks.push_back(now);
+        while self
+            .recent_ticks
+            .front()
+            .is_some_and(|tick| now.duration_since(*tick) > TPS_WINDOW)
+        {
+            self.recent_ticks.pop_front();
+        }
+    }
+
+    /// Cursor position within the visible region (view cells)
+    pub fn cursor_view_position(&self) -> (usize, usize) {
+        let x = self.viewport.view_width as i32 / 2 + self.cursor_offset.0;
+        let y = self.viewport.view_height as i32 / 2 + self.cursor_offset.1;
+        (x.max(0) as usize, y.max(0) as usize)
+    }
+
+    /// World cell under the cursor, clamped to the map
+    pub fn cursor_world_position(&self) -> (usize, usize) {
+        let (view_x, view_y) = self.cursor_view_position();
+        let zoom = self.zoom_level.max(1) as usize;
+        let world_x = self.viewport.world_x.max(0) as usize + view_x * zoom;
+        let world_y = self.viewport.world_y.max(0) as usize + view_y * zoom;
+        (
+            world_x.min(self.simulation.get_width().saturating_sub(1)),
+            world_y.min(self.simulation.get_height().saturating_sub(1)),
+        )
+    }
+
+    /// Move the inspection cursor, keeping it inside the viewport
+    pub fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let half_width = self.viewport.view_width as i32 / 2;
+        let half_height = self.viewport.view_height as i32 / 2;
+        let max_x = self.viewport.view_width as i32 - 1 - half_width;
+        let max_y = self.viewport.view_height as i32 - 1 - half_height;
+        self.cursor_offset.0 = (self.cursor_offset.0 + dx).clamp(-half_width, max_x.max(0));
+        self.cursor_offset.1 = (self.cursor_offset.1 + dy).clamp(-half_height, max_y.max(0));
+    }
+
+    /// Read all layer values at the cursor
+    /// The biome map behind it is expensive, so values are reread only after a tick or a move.
+    pub fn inspect_cursor_cell(&mut self) -> CellInspection {
+        let (x, y) = self.cursor_world_position();
+        if let Some(cached) = &self.last_inspection
+            && (cached.x, cached.y, cached.tick) == (x, y, self.simulation.tick_count)
+        {
+            return cached.clone();
+        }
+
+        let wind = self.simulation.get_wind_at(x, y);
+        let biome = self.simulation.generate_biome_map().get(x, y);
+
+        let inspection = CellInspection {
+            x,
+            y,
+            tick: self.simulation.tick_count,
+            elevation: self.simulation.get_elevation(x, y),
+            temperature: self
+                .simulation
+                .get_temperature_layer()
+                .get_temperature(x, y),
+            pressure: self.simulation.get_pressure_at(x, y),
+            wind: (wind.x, wind.y),
+            wind_speed: self.simulation.get_wind_speed_at(x, y),
+            biome,
+        };
+        self.last_inspection = Some(inspection.clone());
+        inspection
     }
 
     /// Get terrain info at current cursor position
@@ -213,9 +379,7 @@ impl TuiApp {
             0
         };
 
-        // Calculate center of viewport (cursor position)
-        let cursor_x = (self.viewport.world_x + (self.viewport.view_width as i32 / 2)) as usize;
-        let cursor_y = (self.viewport.world_y + (self.viewport.view_height as i32 / 2)) as usize;
+        let (cursor_x, cursor_y) = self.cursor_world_position();
 
         // Get elevation at cursor (with bounds checking)
         let elevation = if cursor_y < world_height && cursor_x < world_width {
@@ -244,6 +408,19 @@ impl TuiApp {
             KeyCode::Char('q') | KeyCode::Esc => {
                 self.should_quit = true;
             }
+            // Inspection cursor (arrow keys while inspecting)
+            KeyCode::Char('i') => {
+                self.inspect_mode = !self.inspect_mode;
+                self.cursor_offset = (0, 0);
+            }
+            KeyCode::Up if self.inspect_mode => self.move_cursor(0, -1),
+            KeyCode::Down if self.inspect_mode => self.move_cursor(0, 1),
+            KeyCode::Left if self.inspect_mode => self.move_cursor(-1, 0),
+            KeyCode::Right if self.inspect_mode => self.move_cursor(1, 0),
+            KeyCode::Char('c') => {
+                let inspection = self.inspect_cursor_cell();
+                self.inspection_log.push(inspection);
+            }
             // WASD navigation
             KeyCode::Char('w') | KeyCode::Up => {
                 self.viewport.move_by(
@@ -325,6 +502,15 @@ impl TuiApp {
             KeyCode::Char(' ') => {
                 self.paused = !self.paused; // Toggle pause/resume
             }
+            KeyCode::Char('.') if self.paused => {
+                self.step_simulation(Instant::now()); // Single step while paused
+            }
+            KeyCode::Char(']') => {
+                self.target_tps = (self.target_tps * 2.0).min(MAX_TARGET_TPS);
+            }
+            KeyCode::Char('[') => {
+                self.target_tps = (self.target_tps / 2.0).max(MIN_TARGET_TPS);
+            }
             KeyCode::Char('r') => {
                 if !self.paused {
                     self.simulation.tick(); // Manual single step when running
@@ -338,6 +524,9 @@ impl TuiApp {
             KeyCode::Char('v') => {
                 self.show_water = !self.show_water; // Toggle water visualization (legacy)
             }
+            KeyCode::Char('n') => {
+                self.show_rivers = !self.show_rivers; // Toggle drainage network overlay
+            }
             // Display mode hotkeys
             KeyCode::Char('1') => {
                 self.display_mode = DisplayMode::Terrain;
@@ -359,11 +548,9 @@ impl TuiApp {
             }
             // Add water at cursor position for testing
             KeyCode::Char('f') => {
-                let cursor_x =
-                    (self.viewport.world_x + (self.viewport.view_width as i32 / 2)) as usize;
-                let cursor_y =
-                    (self.viewport.world_y + (self.viewport.view_height as i32 / 2)) as usize;
+                let (cursor_x, cursor_y) = self.cursor_world_position();
                 self.simulation.add_water_at(cursor_x, cursor_y, 0.1);
+                self.last_inspection = None;
             }
             _ => {}
         }
@@ -467,6 +654,31 @@ fn get_atmospheric_display(
     }
 }
 
+/// River glyph for the screen cell covering a zoom x zoom block of world cells
+/// Scans the whole block so rivers stay visible when the view is downsampled
+fn river_overlay(
+    simulation: &Simulation,
+    world_x: usize,
+    world_y: usize,
+    zoom: usize,
+) -> Option<(char, Style)> {
+    let mut has_river = false;
+    for y in world_y..(world_y + zoom).min(simulation.get_height()) {
+        for x in world_x..(world_x + zoom).min(simulation.get_width()) {
+            if simulation.is_major_river(x, y) {
+                return Some((
+                    '≈',
+                    Style::default()
+                        .fg(Color::LightBlue)
+                        .add_modifier(Modifier::BOLD),
+                ));
+            }
+            has_river |= simulation.is_river(x, y);
+        }
+    }
+    has_river.then(|| ('~', Style::default().fg(Color::Blue)))
+}
+
 /// Convert velocity vector to directional arrow character
 fn velocity_to_arrow(velocity: &Vec2) -> char {
     let magnitude = velocity.magnitude();
@@ -612,6 +824,17 @@ fn render_terrain_with_overlays(
                 }
             };
 
+            // Drainage overlay replaces everything except the cursor
+            let zoom = app.zoom_level.max(1) as usize;
+            let river = if app.show_rivers && !is_cursor {
+                let world_x = app.viewport.world_x.max(0) as usize + col_idx * zoom;
+                let world_y = app.viewport.world_y.max(0) as usize + row_idx * zoom;
+                river_overlay(&app.simulation, world_x, world_y, zoom)
+            } else {
+                None
+            };
+            let (symbol, style) = river.unwrap_or((symbol, style));
+
             spans.push(Span::styled(symbol.to_string(), style));
         }
         lines.push(Line::from(spans));
@@ -661,7 +884,7 @@ fn render_minimap_with_viewport(
             let water_depth = if show_water && water_layer.is_some() {
                 let water = water_layer.unwrap();
                 if world_y < water.depth.len() && world_x < water.depth[0].len() {
-                    water.depth[world_y][world_x]
+                    water.depth[world_y][world_x].to_f32()
                 } else {
                     0.0
                 }
@@ -762,6 +985,26 @@ fn render_minimap_with_viewport(
     minimap_lines
 }
 
+/// Sidebar lines describing the inspected cell
+fn inspector_lines(inspection: &CellInspection, logged: usize) -> Vec<Line<'static>> {
+    vec![
+        Line::from(format!("Cell ({}, {})", inspection.x, inspection.y)),
+        Line::from(format!("Elev  {:.3}", inspection.elevation)),
+        Line::from(format!("Temp  {:.1}°C", inspection.temperature)),
+        Line::from(format!("Press {:.0} Pa", inspection.pressure)),
+        Line::from(format!(
+            "Wind  {:.1},{:.1}",
+            inspection.wind.0, inspection.wind.1
+        )),
+        Line::from(format!("Speed {:.2} m/s", inspection.wind_speed)),
+        Line::from(format!("Biome {:?}", inspection.biome)),
+        Line::from(Span::styled(
+            format!("C=Log ({logged})"),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ]
+}
+
 /// Main UI rendering function
 pub fn ui(f: &mut Frame, app: &mut TuiApp) {
     // Update viewport size based on terminal
@@ -800,9 +1043,8 @@ pub fn ui(f: &mut Frame, app: &mut TuiApp) {
         .viewport
         .extract_visible_region(&app.simulation.heightmap.to_nested(), app.zoom_level);
 
-    // Calculate cursor position in the visible region (center of viewport)
-    let cursor_x = app.viewport.view_width / 2;
-    let cursor_y = app.viewport.view_height / 2;
+    // Cursor position in the visible region (viewport center plus inspection offset)
+    let (cursor_x, cursor_y) = app.cursor_view_position();
 
     let terrain_lines =
         render_terrain_with_overlays(app, &visible_heightmap, true, cursor_x, cursor_y);
@@ -859,9 +1101,16 @@ pub fn ui(f: &mut Frame, app: &mut TuiApp) {
         ]),
     ];
 
-    let legend_paragraph = Paragraph::new(legend_lines)
-        .block(Block::default().title("Legend").borders(Borders::ALL))
-        .style(Style::default());
+    let legend_paragraph = if app.inspect_mode {
+        Paragraph::new(inspector_lines(
+            &app.inspect_cursor_cell(),
+            app.inspection_log.len(),
+        ))
+        .block(Block::default().title("Inspector").borders(Borders::ALL))
+    } else {
+        Paragraph::new(legend_lines).block(Block::default().title("Legend").borders(Borders::ALL))
+    }
+    .style(Style::default());
 
     f.render_widget(legend_paragraph, sidebar_chunks[1]);
 
@@ -869,14 +1118,19 @@ pub fn ui(f: &mut Frame, app: &mut TuiApp) {
     let _world_width = app.simulation.heightmap[0].len();
     let _world_height = app.simulation.heightmap.len();
     let (elevation, terrain_type, symbol) = app.get_cursor_terrain_info();
+    let (cursor_world_x, cursor_world_y) = app.cursor_world_position();
     let total_water = app.simulation.water.get_total_water();
 
     let biological_time = app.simulation.get_biological_time_display();
+    let sim_time = app.simulation.get_simulation_time();
     let status_text = format!(
-        "{} | Pos: ({}, {}) | Zoom: 1:{} | {} {} ({:.3}) | Water: {:.1} | {} | WASD=Move SPC=Pause F=AddWater V=ToggleWater Q=Quit",
+        "{} | Tick {} @ {:.1}/{:.1} TPS | Pos: ({}, {}) | Zoom: 1:{} | {} {} ({:.3}) | Water: {:.1} | {} | WASD=Move SPC=Pause .=Step [/]=Speed F=AddWater V=ToggleWater N=Rivers I=Inspect Q=Quit",
         biological_time,
-        app.viewport.world_x,
-        app.viewport.world_y,
+        sim_time.tick_count,
+        app.effective_tps(Instant::now()),
+        app.target_tps,
+        cursor_world_x,
+        cursor_world_y,
         app.zoom_level,
         symbol,
         terrain_type,
@@ -910,15 +1164,11 @@ pub fn run_tui(simulation: Simulation) -> Result<(), Box<dyn std::error::Error>>
     // Event handling loop with optimized timing
     let mut needs_redraw = true;
     let mut last_redraw = Instant::now();
-    let mut last_sim_tick = Instant::now();
     let min_frame_time = Duration::from_millis(33); // ~30fps, more responsive than 60fps for terminal
-    let sim_tick_interval = Duration::from_millis(100); // ~10 simulation ticks per second
 
     loop {
-        // Run simulation tick if not paused and enough time has passed
-        if !app.paused && last_sim_tick.elapsed() >= sim_tick_interval {
-            app.simulation.tick();
-            last_sim_tick = Instant::now();
+        // Run simulation tick if not paused and the tick-rate limiter allows it
+        if app.advance_simulation(Instant::now()) {
             needs_redraw = true; // Redraw after simulation update
         }
 
@@ -929,8 +1179,16 @@ pub fn run_tui(simulation: Simulation) -> Result<(), Box<dyn std::error::Error>>
             last_redraw = std::time::Instant::now();
         }
 
-        // Handle events immediately without tokio complexity
-        if event::poll(Duration::from_millis(1))? {
+        // Sleep in the event poll until the next tick or frame is due instead of busy-looping
+        let frame_wait = if needs_redraw {
+            min_frame_time.saturating_sub(last_redraw.elapsed())
+        } else {
+            min_frame_time
+        };
+        let poll_timeout = app
+            .time_until_next_tick(Instant::now())
+            .map_or(frame_wait, |tick_wait| tick_wait.min(frame_wait));
+        if event::poll(poll_timeout)? {
             match event::read()? {
                 Event::Key(key) if key.kind == KeyEventKind::Press => {
                     app.handle_key_event(key.code);
@@ -956,3 +1214,140 @@ pub fn run_tui(simulation: Simulation) -> Result<(), Box<dyn std::error::Error>>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::core::heightmap::HeightMap;
+
+    fn test_app() -> TuiApp {
+        let heightmap = HeightMap::from_nested(vec![vec![0.5; 32]; 32]);
+        let mut app = TuiApp::new(Simulation::new(heightmap));
+        app.viewport.view_width = 10;
+        app.viewport.view_height = 8;
+        app
+    }
+
+    #[test]
+    fn arrow_keys_move_inspection_cursor() {
+        let mut app = test_app();
+        let start = app.cursor_world_position();
+        assert_eq!(start, (5, 4));
+
+        // Arrows pan the viewport until inspect mode is enabled
+        app.handle_key_event(KeyCode::Char('i'));
+        app.handle_key_event(KeyCode::Right);
+        app.handle_key_event(KeyCode::Right);
+        app.handle_key_event(KeyCode::Up);
+        assert_eq!(app.cursor_world_position(), (7, 3));
+        assert_eq!((app.viewport.world_x, app.viewport.world_y), (0, 0));
+
+        // The cursor stays inside the viewport
+        for _ in 0..20 {
+            app.handle_key_event(KeyCode::Left);
+        }
+        assert_eq!(app.cursor_world_position(), (0, 3));
+
+        app.handle_key_event(KeyCode::Char('c'));
+        assert_eq!(app.inspection_log.len(), 1);
+        let logged = &app.inspection_log[0];
+        assert_eq!((logged.x, logged.y), (0, 3));
+        assert!((logged.elevation - 0.5).abs() < 1e-6);
+        assert!(logged.to_log_line().contains("(0, 3)"));
+    }
+
+    #[test]
+    fn cursor_inspection_is_reused_until_a_tick_or_move() {
+        let mut app = test_app();
+        app.handle_key_event(KeyCode::Char('i'));
+        let first = app.inspect_cursor_cell();
+
+        // Terrain edited behind the app's back stays hidden until the cache is refreshed
+        let (x, y) = app.cursor_world_position();
+        app.simulation.heightmap.set(x, y, 0.9);
+        assert_eq!(app.inspect_cursor_cell(), first);
+
+        app.simulation.tick();
+        let after_tick = app.inspect_cursor_cell();
+        assert_eq!(after_tick.tick, first.tick + 1);
+        assert!((after_tick.elevation - first.elevation).abs() > 0.1);
+
+        app.handle_key_event(KeyCode::Right);
+        assert_eq!(app.inspect_cursor_cell().x, x + 1);
+    }
+
+    #[test]
+    fn river_overlay_survives_downsampling() {
+        // Valley draining down the middle column toward the bottom edge
+        let nested = (0..32)
+            .map(|y| {
+                (0..32)
+                    .map(|x| 0.3 + 0.02 * (x as f32 - 15.0).abs() + 0.01 * (31 - y) as f32)
+                    .collect()
+            })
+            .collect();
+        let mut app = TuiApp::new(Simulation::new(HeightMap::from_nested(nested)));
+        app.viewport.view_width = 16;
+        app.viewport.view_height = 16;
+        app.zoom_level = 2;
+        app.show_rivers = true;
+
+        // Pick a river cell on an odd coordinate that stride sampling would skip
+        let (river_x, river_y) = (0..32)
+            .flat_map(|y| (0..32).map(move |x| (x, y)))
+            .find(|&(x, y)| x % 2 == 1 && app.simulation.is_river(x, y))
+            .expect("valley should form a river");
+
+        let visible = app
+            .viewport
+            .extract_visible_region(&app.simulation.heightmap.to_nested(), app.zoom_level);
+        let lines = render_terrain_with_overlays(&app, &visible, false, 0, 0);
+
+        let span = &lines[river_y / 2].spans[river_x / 2];
+        assert!(
+            (span.content == "~" && span.style.fg == Some(Color::Blue))
+                || (span.content == "≈" && span.style.fg == Some(Color::LightBlue)),
+            "expected river glyph at ({}, {}), got {:?}",
+            river_x,
+            river_y,
+            span.content
+        );
+        if app.simulation.is_major_river(river_x, river_y) {
+            assert_eq!(span.content, "≈");
+            assert!(span.style.add_modifier.contains(Modifier::BOLD));
+        }
+    }
+
+    #[test]
+    fn pause_stops_tick_count_and_step_advances_once() {
+        let mut app = test_app();
+        let start = Instant::now();
+        let interval = Duration::from_secs_f32(1.0 / app.target_tps);
+
+        assert!(app.advance_simulation(start + interval));
+        assert_eq!(app.simulation.tick_count, 1);
+        // The limiter holds off until the next interval has elapsed
+        assert!(!app.advance_simulation(start + interval));
+        assert_eq!(app.simulation.tick_count, 1);
+
+        app.handle_key_event(KeyCode::Char(' '));
+        assert!(app.paused);
+        assert!(!app.advance_simulation(start + interval * 10));
+        assert_eq!(app.simulation.tick_count, 1);
+        assert_eq!(app.time_until_next_tick(start), None);
+
+        app.handle_key_event(KeyCode::Char('.'));
+        assert_eq!(app.simulation.tick_count, 2);
+
+        app.handle_key_event(KeyCode::Char(']'));
+        assert_eq!(app.target_tps, DEFAULT_TARGET_TPS * 2.0);
+        app.handle_key_event(KeyCode::Char('['));
+        app.handle_key_event(KeyCode::Char('['));
+        assert_eq!(app.target_tps, DEFAULT_TARGET_TPS / 2.0);
+
+        app.handle_key_event(KeyCode::Char(' '));
+        assert!(!app.paused);
+        assert!(app.advance_simulation(Instant::now() + interval * 2));
+        assert_eq!(app.simulation.tick_count, 3);
+    }
+}
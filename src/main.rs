@@ -10,7 +10,9 @@ mod debug_interval_issue;
 mod debug_water_conservation;
 mod engine;
 
-use applications::run_weather_demo;
+use applications::{
+    parse_run_args, parse_workspace_args, run_batch, run_weather_demo, run_workspace,
+};
 use debug_flow_analysis::{
     analyze_evaporation_loss, analyze_flow_calculation, analyze_flow_update_intervals,
     analyze_temperature_evaporation,
@@ -48,6 +50,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    if args.len() > 1 && args[1] == "run" {
+        let run_args = parse_run_args(&args[1..]).unwrap_or_else(|error| error.exit());
+        return run_batch(&run_args);
+    }
+
+    if args.len() > 1 && args[1] == "workspace" {
+        let workspace_args = parse_workspace_args(&args[1..]).unwrap_or_else(|error| error.exit());
+        if let Err(error) = run_workspace(&workspace_args, &mut std::io::stdout()) {
+            eprintln!("kosmarium workspace: {}", error);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // For weather system testing, run the weather demo
     // This demonstrates atmospheric dynamics and weather pattern visualization
     run_weather_demo()
@@ -1,6 +1,7 @@
 // ABOUTME: Tests for geostrophic balance validation framework detecting current physics violations
 // ABOUTME: Implements f × v ≈ -(1/ρ)∇P validation and measures pressure-wind coupling correlation
 
+use kosmarium::engine::core::physics_grid::{Float, StateFloat};
 use kosmarium::engine::core::scale::{DetailLevel, WorldScale};
 use kosmarium::engine::physics::atmosphere::{AtmosphericSystem, BoundaryType, WindLayer};
 use kosmarium::engine::physics::climate::AtmosphericPressureLayer;
@@ -686,7 +687,9 @@ mod tests {
         for y in 0..50 {
             for x in 0..50 {
                 let pressure = 101325.0 - (x as f32 * 200.0); // 200 Pa per cell decrease eastward
-                pressure_layer.pressure.set(x, y, pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(pressure));
             }
         }
         pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
@@ -780,7 +783,9 @@ mod tests {
         for y in 0..50 {
             for x in 0..50 {
                 let pressure = 101325.0 - (x as f32 * 200.0); // 200 Pa per cell decrease eastward
-                pressure_layer.pressure.set(x, y, pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(pressure));
             }
         }
         pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
@@ -868,7 +873,9 @@ mod tests {
         for y in 0..50 {
             for x in 0..50 {
                 let pressure = 101325.0 - (x as f32 * 200.0); // 200 Pa per cell decrease eastward
-                pressure_layer.pressure.set(x, y, pressure);
+                pressure_layer
+                    .pressure
+                    .set(x, y, StateFloat::from_f32(pressure));
             }
         }
         pressure_layer.calculate_pressure_gradients(scale.meters_per_pixel() as f32);
@@ -23,6 +23,7 @@ use std::time::Instant;
 
 // Test dependencies - importing from main codebase
 use kosmarium::engine::core::heightmap::HeightMap;
+use kosmarium::engine::core::physics_grid::{Float, StateFloat};
 use kosmarium::engine::core::scale::{DetailLevel, WorldScale};
 use kosmarium::engine::physics::drainage::{
     DrainageNetwork, FlowAccumulationMap, FlowDirection, FlowDirectionMap,
@@ -174,10 +175,12 @@ fn test_drainage_network_mass_conservation() {
     let mut water_layer = WaterLayer::new(3, 3);
 
     // Add uniform water distribution
-    let uniform_water = 1.0;
+    let uniform_water: f32 = 1.0;
     for y in 0..3 {
         for x in 0..3 {
-            water_layer.depth.set(x, y, uniform_water);
+            water_layer
+                .depth
+                .set(x, y, StateFloat::from_f32(uniform_water));
         }
     }
 
@@ -216,7 +219,7 @@ fn test_drainage_network_mass_conservation() {
     let mut water_depths = Vec::new();
     for y in 0..3 {
         for x in 0..3 {
-            water_depths.push(water_layer.depth.get(x, y));
+            water_depths.push(water_layer.depth.get(x, y).to_f32());
         }
     }
 
@@ -441,7 +444,9 @@ fn test_physics_grid_migration_water_conservation() {
     for y in 0..30 {
         for x in 0..50 {
             let water_depth = 0.01 + (x as f32 * y as f32) / 10000.0; // Varies from 1cm to ~2.5cm
-            water_layer.depth.set(x, y, water_depth);
+            water_layer
+                .depth
+                .set(x, y, StateFloat::from_f32(water_depth));
         }
     }
 
@@ -456,7 +461,7 @@ fn test_physics_grid_migration_water_conservation() {
     let expected_rainfall_total = rainfall_amount * 50.0 * 30.0; // cells × rate
 
     for depth in water_layer.depth.iter_mut() {
-        *depth += rainfall_amount;
+        *depth += StateFloat::from_f32(rainfall_amount);
     }
 
     let after_rainfall = water_layer.get_total_water();
@@ -487,8 +492,8 @@ fn test_physics_grid_migration_water_conservation() {
     let pre_evaporation_water = after_rainfall;
 
     for depth in water_layer.depth.iter_mut() {
-        *depth *= 1.0 - evaporation_rate;
-        if *depth < water_system.evaporation_threshold {
+        *depth *= StateFloat::from_f32(1.0 - evaporation_rate);
+        if depth.to_f32() < water_system.evaporation_threshold {
             *depth = 0.0;
         }
     }
@@ -648,7 +653,10 @@ fn test_scale_invariant_mass_balance() {
         let water_per_cell = 0.03; // 3cm
         for y in 0..height {
             for x in 0..width {
-                test_sim.water.depth.set(x, y, water_per_cell);
+                test_sim
+                    .water
+                    .depth
+                    .set(x, y, StateFloat::from_f32(water_per_cell));
             }
         }
 